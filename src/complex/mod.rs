@@ -0,0 +1,71 @@
+use std::fmt;
+use crate::fraction::Fraction;
+
+/// Models a complex number whose real & imaginary parts are fractions, e.g. `1/2 + 3/4i`.
+/// They're closed under the four operators, so arithmetic on them stays exact
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    pub real: Fraction,
+    pub imaginary: Fraction
+}
+
+impl Complex {
+
+    pub fn new(real: Fraction, imaginary: Fraction) -> Complex {
+        Complex { real, imaginary }
+    }
+
+    pub fn add(&self, other: &Complex) -> Result<Complex, &'static str> {
+        Ok(Complex::new(self.real.checked_add(&other.real)?, self.imaginary.checked_add(&other.imaginary)?))
+    }
+
+    pub fn substract(&self, other: &Complex) -> Result<Complex, &'static str> {
+        Ok(Complex::new(self.real.checked_substract(&other.real)?, self.imaginary.checked_substract(&other.imaginary)?))
+    }
+
+    /// Multiplies using `i^2 = -1`, i.e. `(a + bi)(c + di) = (ac - bd) + (ad + bc)i`
+    pub fn multiply(&self, other: &Complex) -> Result<Complex, &'static str> {
+        let real = self.real.checked_multiply(&other.real)?
+            .checked_substract(&self.imaginary.checked_multiply(&other.imaginary)?)?;
+        let imaginary = self.real.checked_multiply(&other.imaginary)?
+            .checked_add(&self.imaginary.checked_multiply(&other.real)?)?;
+
+        Ok(Complex::new(real, imaginary))
+    }
+
+    /// Divides by multiplying both numbers by the conjugate of the divisor, which makes the divisor its norm
+    pub fn divide(&self, other: &Complex) -> Result<Complex, &'static str> {
+        let numerator = self.multiply(&other.conjugate())?;
+        let norm = other.norm()?;
+
+        Ok(Complex::new(numerator.real.divide(&norm)?, numerator.imaginary.divide(&norm)?))
+    }
+
+    /// The number with its imaginary part negated, e.g. `1/2 - 3/4i` for `1/2 + 3/4i`
+    pub fn conjugate(&self) -> Complex {
+        Complex::new(self.real, -self.imaginary)
+    }
+
+    /// The square of the modulus, i.e. `a^2 + b^2` for `a + bi`, which unlike the modulus is always a fraction
+    pub fn norm(&self) -> Result<Fraction, &'static str> {
+        self.real.checked_multiply(&self.real)?.checked_add(&self.imaginary.checked_multiply(&self.imaginary)?)
+    }
+}
+
+/// Implementation to make `Complex` displayable with mixed numbers as parts, e.g. `1_1/2 - 3/4i`.
+/// Parts of `0` are left out, except when both are
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let zero = Fraction::default();
+
+        match (self.real == zero, self.imaginary.numerator < 0) {
+            (_, _) if self.imaginary == zero => write!(f, "{}", self.real),
+            (true, _) => write!(f, "{}i", self.imaginary),
+            (false, true) => write!(f, "{} - {}i", self.real, -self.imaginary),
+            (false, false) => write!(f, "{} + {}i", self.real, self.imaginary)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,56 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+fn complex(real: &str, imaginary: &str) -> Complex {
+    Complex::new(fraction(real), fraction(imaginary))
+}
+
+#[test]
+fn compute_with_complex_numbers() {
+    let z = complex("1/2", "3/4");
+    let w = complex("2", "-1");
+
+    assert_that!(&z.add(&w)).is_equal_to(Ok(complex("2_1/2", "-1/4")));
+    assert_that!(&z.substract(&w)).is_equal_to(Ok(complex("-1_1/2", "1_3/4")));
+    assert_that!(&z.multiply(&w)).is_equal_to(Ok(complex("1_3/4", "1")));
+    assert_that!(&z.divide(&w)).is_equal_to(Ok(complex("1/20", "2/5")));
+    assert_that!(&z.divide(&complex("0", "0"))).is_equal_to(Err("Division by zero!"));
+}
+
+#[test]
+fn compute_conjugate_and_norm() {
+    let z = complex("1/2", "3/4");
+
+    assert_that!(&z.conjugate()).is_equal_to(complex("1/2", "-3/4"));
+    assert_that!(&z.norm()).is_equal_to(Ok(fraction("13/16")));
+    assert_that!(&complex("0", "1").multiply(&complex("0", "1"))).is_equal_to(Ok(complex("-1", "0")));
+}
+
+#[test]
+fn compute_on_overflow() {
+    let z = complex("2147483647", "1");
+
+    assert_that!(&z.add(&complex("1", "0"))).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&complex("-2147483647", "0").substract(&z)).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&z.multiply(&complex("2", "0"))).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&z.norm()).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&complex("1", "0").divide(&z)).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn format_complex_numbers() {
+    let format_test_cases = [
+        (("1/2", "3/4"), "1/2 + 3/4i"),
+        (("3/2", "-3/4"), "1_1/2 - 3/4i"),
+        (("0", "-5/4"), "-1_1/4i"),
+        (("2", "0"), "2"),
+        (("0", "0"), "0")
+    ];
+
+    for ((real, imaginary), expected) in &format_test_cases {
+        println!("Formatting {} + {}i", real, imaginary);
+
+        assert_that!(&complex(real, imaginary).to_string()).is_equal_to(expected.to_string());
+    }
+}
@@ -0,0 +1,96 @@
+use std::convert::TryFrom;
+use regex::Regex;
+use lazy_static::*;
+use crate::fraction::Fraction;
+
+// This ensures the regexes are compiled only once
+lazy_static! {
+    static ref CONTINUED_FRACTION_RE: Regex = Regex::new(r"^\[\s*(\-?\d+)\s*(?:;\s*(\d+(?:\s*,\s*\d+)*)\s*)?\]$").unwrap();
+}
+
+/// Expands a fraction into the terms of its continued fraction, e.g. `415/93` is `[4; 2, 6, 7]`.
+/// The terms are the successive quotients of the Euclidean algorithm `math::gcd` uses, taking floors so every term
+/// but the first is positive, e.g. `-415/93` is `[-5; 1, 1, 6, 7]`
+pub fn expand(fraction: &Fraction) -> Vec<i32> {
+    let (mut numerator, mut denominator) = (fraction.numerator, fraction.denominator);
+    let mut terms = Vec::new();
+
+    while denominator != 0 {
+        terms.push(numerator.div_euclid(denominator));
+        (numerator, denominator) = (denominator, numerator.rem_euclid(denominator));
+    }
+    terms
+}
+
+/// Lists the successive rational approximations of a fraction, ending with the fraction itself,
+/// e.g. the convergents of `415/93` are `4`, `9/2`, `58/13` & `415/93`
+pub fn convergents(fraction: &Fraction) -> Vec<Fraction> {
+    // Convergents of a fraction never exceed it in size, so they can't overflow
+    convergents_of(&expand(fraction)).unwrap()
+}
+
+/// Computes the convergents of the given terms, failing if any term but the first isn't positive or
+/// the convergents don't fit in an `i32`
+pub fn convergents_of(terms: &[i32]) -> Result<Vec<Fraction>, &'static str> {
+    if terms.is_empty() || terms[1..].iter().any(|&term| term <= 0) {
+        return Err("Invalid continued fraction!");
+    }
+
+    let (mut numerators, mut denominators) = ((0, 1), (1, 0));
+    let mut convergents = Vec::new();
+
+    for &term in terms {
+        let numerator = i64::from(term) * numerators.1 + numerators.0;
+        let denominator = i64::from(term) * denominators.1 + denominators.0;
+        let (Ok(n), Ok(d)) = (i32::try_from(numerator), i32::try_from(denominator)) else {
+            return Err("Integer overflow!");
+        };
+        convergents.push(Fraction::new(n, d)?);
+        numerators = (numerators.1, numerator);
+        denominators = (denominators.1, denominator);
+    }
+    Ok(convergents)
+}
+
+/// Collapses the terms of a continued fraction back into a fraction, e.g. `[4; 2, 6, 7]` is `415/93`
+pub fn collapse(terms: &[i32]) -> Result<Fraction, &'static str> {
+    convergents_of(terms).map(|convergents| convergents[convergents.len() - 1])
+}
+
+/// Returns `true` if given expression can be parsed as a continued fraction
+pub fn is_continued_fraction(continued_fraction_exp: &str) -> bool {
+    CONTINUED_FRACTION_RE.is_match(continued_fraction_exp)
+}
+
+/// Parses the terms of a continued fraction in the form `[4; 2, 6, 7]`. A whole number is written `[4]`
+pub fn parse_terms(continued_fraction_exp: &str) -> Result<Vec<i32>, &'static str> {
+    let captures = CONTINUED_FRACTION_RE.captures(continued_fraction_exp).ok_or("Unparseable continued fraction!")?;
+    let mut terms = vec![captures.get(1).unwrap().as_str()];
+    if let Some(rest) = captures.get(2) {
+        terms.extend(rest.as_str().split(','));
+    }
+
+    terms.into_iter()
+        .map(|term| term.trim().parse().map_err(|_| "Integer overflow!"))
+        .collect()
+}
+
+/// Parses a continued fraction in the form `[4; 2, 6, 7]` into the fraction it stands for
+pub fn parse(continued_fraction_exp: &str) -> Result<Fraction, &'static str> {
+    collapse(&parse_terms(continued_fraction_exp)?)
+}
+
+/// Formats terms in the usual notation, e.g. `[4; 2, 6, 7]`
+pub fn format_terms(terms: &[i32]) -> String {
+    match terms.split_first() {
+        Some((first, [])) => format!("[{}]", first),
+        Some((first, rest)) => {
+            let rest: Vec<String> = rest.iter().map(|term| term.to_string()).collect();
+            format!("[{}; {}]", first, rest.join(", "))
+        },
+        None => "[]".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests;
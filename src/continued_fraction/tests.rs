@@ -0,0 +1,67 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+#[test]
+fn expand_fractions() {
+    let expand_test_cases = [
+        ("415/93", vec![4, 2, 6, 7]),
+        ("-415/93", vec![-5, 1, 1, 6, 7]),
+        ("3", vec![3]),
+        ("1/3", vec![0, 3])
+    ];
+
+    for (fraction_exp, expected) in &expand_test_cases {
+        println!("Expanding fraction {}", fraction_exp);
+        let fraction = fraction(fraction_exp);
+
+        assert_that!(&expand(&fraction))
+            .is_equal_to(expected);
+    }
+}
+
+#[test]
+fn convergents_of_fraction() {
+    let actual = convergents(&Fraction::new(415, 93).unwrap());
+
+    let expected: Vec<Fraction> = ["4", "9/2", "58/13", "415/93"].iter().map(|exp| fraction(exp)).collect();
+    assert_that!(&actual)
+        .is_equal_to(&expected);
+}
+
+#[test]
+fn collapse_terms() {
+    assert_that!(&collapse(&[4, 2, 6, 7])).is_equal_to(Ok(Fraction::new(415, 93).unwrap()));
+    assert_that!(&collapse(&[-5, 1, 1, 6, 7])).is_equal_to(Ok(Fraction::new(-415, 93).unwrap()));
+}
+
+#[test]
+fn collapse_invalid_terms() {
+    assert_that!(&collapse(&[4, 0, 6])).is_equal_to(Err("Invalid continued fraction!"));
+    assert_that!(&collapse(&[])).is_equal_to(Err("Invalid continued fraction!"));
+}
+
+#[test]
+fn collapse_with_overflow() {
+    assert_that!(&collapse(&[0, 1, i32::MAX, 2])).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn parse_continued_fractions() {
+    assert_that!(&parse("[4; 2, 6, 7]")).is_equal_to(Ok(Fraction::new(415, 93).unwrap()));
+    assert_that!(&parse("[ -5;1,1,6,7 ]")).is_equal_to(Ok(Fraction::new(-415, 93).unwrap()));
+    assert_that!(&parse("[4]")).is_equal_to(Ok(Fraction::from(4)));
+}
+
+#[test]
+fn parse_invalid_continued_fractions() {
+    assert_that!(&is_continued_fraction("[4; 2, -6]")).is_false();
+    assert_that!(&parse("[4; ]")).is_equal_to(Err("Unparseable continued fraction!"));
+    assert_that!(&parse("4; 2")).is_equal_to(Err("Unparseable continued fraction!"));
+}
+
+#[test]
+fn format_continued_fractions() {
+    assert_that!(format_terms(&[4, 2, 6, 7]).as_str()).is_equal_to("[4; 2, 6, 7]");
+    assert_that!(format_terms(&[3]).as_str()).is_equal_to("[3]");
+}
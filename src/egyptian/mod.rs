@@ -0,0 +1,117 @@
+use crate::fraction::Fraction;
+use crate::math;
+
+/// Decompositions with more terms than this aren't searched
+const MAX_TERMS: usize = 8;
+/// Denominators above this aren't searched
+const MAX_DENOMINATOR: i128 = 1_000_000;
+/// Stops searches that would take too long, as some fractions need a lot of candidates to be tried
+const MAX_STEPS: usize = 5_000_000;
+
+/// Decomposes a proper fraction into a sum of distinct unit fractions using the greedy (Fibonacci–Sylvester) algorithm,
+/// which takes the largest unit fraction that fits each time, e.g. `4/13 = 1/4 + 1/18 + 1/468`.
+/// Returns the denominators of the unit fractions. They grow really fast, e.g. `5/121` needs a denominator with 25 digits,
+/// so they're `i128` & the decomposition fails if they don't fit
+pub fn greedy(fraction: &Fraction) -> Result<Vec<i128>, &'static str> {
+    let (mut numerator, mut denominator) = proper_parts(fraction)?;
+    let mut denominators = Vec::new();
+
+    loop {
+        let unit = (denominator + numerator - 1) / numerator;
+        denominators.push(unit);
+
+        // numerator/denominator - 1/unit
+        let remainder = numerator.checked_mul(unit).ok_or("Integer overflow!")? - denominator;
+        if remainder == 0 {
+            return Ok(denominators);
+        }
+        let product = denominator.checked_mul(unit).ok_or("Integer overflow!")?;
+        let gcd = math::wide_gcd(remainder, product);
+        (numerator, denominator) = (remainder / gcd, product / gcd);
+    }
+}
+
+/// Decomposes a proper fraction into a sum of distinct unit fractions with as few terms as possible and, among those,
+/// the smallest largest denominator, e.g. `4/13 = 1/4 + 1/26 + 1/52`.
+/// The search is bounded, so it fails for fractions needing too many terms or too large denominators
+pub fn shortest(fraction: &Fraction) -> Result<Vec<i128>, &'static str> {
+    let (numerator, denominator) = proper_parts(fraction)?;
+    let mut search = Search { steps: 0, best: None };
+
+    for terms in 1..=MAX_TERMS {
+        search.run(numerator, denominator, terms, 2, &mut Vec::new())?;
+        if let Some(best) = search.best {
+            return Ok(best);
+        }
+    }
+    Err("No decomposition found within the search limits!")
+}
+
+/// Formats the unit fractions with the given denominators as a sum, e.g. `1/4 + 1/26 + 1/52`
+pub fn format_denominators(denominators: &[i128]) -> String {
+    let units: Vec<String> = denominators.iter().map(|denominator| format!("1/{}", denominator)).collect();
+
+    units.join(" + ")
+}
+
+/// Depth-first search keeping the best decomposition found so far
+struct Search {
+    steps: usize,
+    best: Option<Vec<i128>>
+}
+
+impl Search {
+
+    /// Tries to write `numerator/denominator` as `terms` unit fractions with denominators increasing from `lowest`
+    fn run(
+        &mut self, numerator: i128, denominator: i128, terms: usize, lowest: i128, units: &mut Vec<i128>
+    ) -> Result<(), &'static str> {
+        self.steps += 1;
+        if self.steps > MAX_STEPS {
+            return Err("Search limit reached!");
+        }
+
+        if terms == 1 {
+            let unit = denominator / numerator;
+            if denominator % numerator == 0 && unit >= lowest && unit < self.highest() {
+                units.push(unit);
+                self.best = Some(units.clone());
+                units.pop();
+            }
+            return Ok(());
+        }
+
+        // The first unit fraction is the largest one, so it must fit in the fraction but can't be too small either
+        let mut unit = lowest.max((denominator + numerator - 1) / numerator);
+        let most = (terms as i128 * denominator - 1) / numerator;
+
+        while unit <= most && unit < self.highest() {
+            // Products that don't fit can't lead to denominators within the limits either
+            if let (Some(scaled), Some(product)) = (numerator.checked_mul(unit), denominator.checked_mul(unit)) {
+                let remainder = scaled - denominator;
+                let gcd = math::wide_gcd(remainder, product);
+                units.push(unit);
+                self.run(remainder / gcd, product / gcd, terms - 1, unit + 1, units)?;
+                units.pop();
+            }
+            unit += 1;
+        }
+        Ok(())
+    }
+
+    /// Denominators must stay below the largest one of the best decomposition, as they only grow
+    fn highest(&self) -> i128 {
+        self.best.as_ref().map_or(MAX_DENOMINATOR + 1, |best| best[best.len() - 1])
+    }
+}
+
+fn proper_parts(fraction: &Fraction) -> Result<(i128, i128), &'static str> {
+    if fraction.numerator <= 0 || !fraction.is_proper() {
+        Err("Expected a positive proper fraction!")
+    } else {
+        Ok((i128::from(fraction.numerator), i128::from(fraction.denominator)))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,73 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+#[test]
+fn greedy_decomposition() {
+    let greedy_test_cases: [(&str, Vec<i128>); 4] = [
+        ("4/13", vec![4, 18, 468]),
+        ("1/7", vec![7]),
+        ("3/4", vec![2, 4]),
+        ("5/121", vec![25, 757, 763_309, 873_960_180_913, 1_527_612_795_642_093_418_846_225])
+    ];
+
+    for (fraction_exp, expected) in &greedy_test_cases {
+        println!("Decomposing fraction {}", fraction_exp);
+        let fraction = fraction(fraction_exp);
+
+        assert_that!(&greedy(&fraction))
+            .is_equal_to(Ok(expected.clone()));
+    }
+}
+
+#[test]
+fn shortest_decomposition() {
+    let shortest_test_cases: [(&str, Vec<i128>); 4] = [
+        ("4/13", vec![4, 26, 52]),
+        ("1/7", vec![7]),
+        ("3/4", vec![2, 4]),
+        ("5/121", vec![33, 121, 363])
+    ];
+
+    for (fraction_exp, expected) in &shortest_test_cases {
+        println!("Decomposing fraction {}", fraction_exp);
+        let fraction = fraction(fraction_exp);
+
+        assert_that!(&shortest(&fraction))
+            .is_equal_to(Ok(expected.clone()));
+    }
+}
+
+#[test]
+fn decomposition_sums_to_fraction() {
+    let fraction = Fraction::new(8, 11).unwrap();
+
+    let sum: Fraction = shortest(&fraction).unwrap().into_iter()
+        .map(|denominator| Fraction::new(1, denominator as i32).unwrap())
+        .sum();
+
+    assert_that!(&sum)
+        .is_equal_to(fraction);
+}
+
+#[test]
+fn decompose_improper_fraction() {
+    let fraction = Fraction::new(5, 4).unwrap();
+
+    assert_that!(&greedy(&fraction)).is_equal_to(Err("Expected a positive proper fraction!"));
+    assert_that!(&shortest(&Fraction::new(-1, 2).unwrap())).is_equal_to(Err("Expected a positive proper fraction!"));
+}
+
+#[test]
+fn greedy_decomposition_overflow() {
+    let fraction = Fraction::new(1_999_999_997, 2_147_483_647).unwrap();
+
+    assert_that!(&greedy(&fraction))
+        .is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn format_unit_fractions() {
+    assert_that!(format_denominators(&[4, 26, 52]).as_str())
+        .is_equal_to("1/4 + 1/26 + 1/52");
+}
@@ -0,0 +1,118 @@
+use std::fmt;
+
+/// Broad categories of evaluation failures, so tools don't need to match on messages
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorKind {
+    /// The expression couldn't be parsed
+    Syntax,
+    /// A fraction was written with a zero denominator
+    ZeroDenominator,
+    /// The divisor of a division is zero
+    DivisionByZero,
+    /// An operand refers to a variable that hasn't been assigned
+    UnknownVariable,
+    /// A call refers to a function that doesn't exist
+    UnknownFunction,
+    /// A conversion refers to a unit that doesn't exist
+    UnknownUnit,
+    /// A value of the wrong type was used, e.g. a boolean where a number is expected or inches where pounds are
+    Type,
+    /// A function got the wrong number of arguments or arguments it can't work with
+    InvalidArgument,
+    /// A result is too large to be represented
+    Overflow,
+    /// A system of equations has no solution or infinitely many of them
+    Unsolvable
+}
+
+impl ErrorKind {
+
+    /// Classifies an error message coming from the parsing & arithmetic modules. Every message those modules
+    /// report is listed, so there's no kind for messages that aren't
+    fn of(message: &str) -> Option<ErrorKind> {
+        let kind = match message {
+            "Unparseable operation!" | "Unparseable mixed number!" | "Unparseable fraction!" | "Unparseable continued fraction!"
+                | "Unparseable time!" | "Too deeply nested!" => ErrorKind::Syntax,
+            "Fraction with zero denominator!" => ErrorKind::ZeroDenominator,
+            "Division by zero!" | "Division by an interval containing zero!" => ErrorKind::DivisionByZero,
+            "Unknown variable!" => ErrorKind::UnknownVariable,
+            "Unknown function!" => ErrorKind::UnknownFunction,
+            "Unknown unit!" => ErrorKind::UnknownUnit,
+            "Expected a number!" | "Expected a matrix!" | "Expected a polynomial!" | "Expected a quantity!"
+                | "Expected a time!" | "Expected a ratio!" | "Expected a distribution!" | "Expected an event!" | "Expected a complex number!"
+                | "Expected an amount of money!" | "Units don't match!" => ErrorKind::Type,
+            "Wrong number of arguments!" | "Lower bound greater than upper bound!" | "Expected a whole number!"
+                | "Cannot factor zero!" | "Expected a positive proper fraction!" | "Search limit reached!"
+                | "No decomposition found within the search limits!" | "Expected at least one number!"
+                | "Expected at least two numbers!" | "Degenerate proportion!"
+                | "Expected a single unknown!" | "Expected a rectangular matrix!" | "Matrix dimensions don't match!"
+                | "Vector lengths don't match!" | "Expected a square matrix!" | "Singular matrix!"
+                | "Polynomial variables don't match!" | "Expected a non-zero polynomial!"
+                | "Expected a whole exponent up to 100!" | "Expected a positive frame rate!"
+                | "Too many frames for the frame rate!" | "Expected a positive ratio!"
+                | "Expected a time signature!" | "Expected 1 to 100 dice with 1 to 100 sides!"
+                | "Expected a non-negative tolerance!" | "Expected 1 to 1000 parts!" | "Unknown rounding!"
+                | "Invalid continued fraction!" | "Expected a ratio of two terms!" | "Expected an unknown!" => ErrorKind::InvalidArgument,
+            "Integer overflow!" => ErrorKind::Overflow,
+            "No solution!" | "Infinitely many solutions!" => ErrorKind::Unsolvable,
+            _ => return None
+        };
+        Some(kind)
+    }
+
+    /// Name of the kind as reported in machine-readable outputs
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorKind::Syntax => "syntax",
+            ErrorKind::ZeroDenominator => "zero_denominator",
+            ErrorKind::DivisionByZero => "division_by_zero",
+            ErrorKind::UnknownVariable => "unknown_variable",
+            ErrorKind::UnknownFunction => "unknown_function",
+            ErrorKind::UnknownUnit => "unknown_unit",
+            ErrorKind::Type => "type",
+            ErrorKind::InvalidArgument => "invalid_argument",
+            ErrorKind::Overflow => "overflow",
+            ErrorKind::Unsolvable => "unsolvable"
+        }
+    }
+}
+
+/// Byte offsets of the part of the expression an error refers to, end excluded
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize
+}
+
+/// Models an evaluation failure and the part of the expression that caused it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub message: &'static str,
+    pub span: Span
+}
+
+impl Error {
+
+    /// Builds an error for the given message, classifying its kind from the message.
+    /// Messages must be listed in `ErrorKind::of`, the ones that aren't are reported as syntax errors
+    pub fn new(message: &'static str, start: usize, end: usize) -> Error {
+        let kind = ErrorKind::of(message);
+        debug_assert!(kind.is_some(), "Unclassified error message: {}", message);
+
+        Error {
+            kind: kind.unwrap_or(ErrorKind::Syntax),
+            message,
+            span: Span { start, end }
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,96 @@
+use spectral::prelude::*;
+use super::*;
+use std::fs;
+use std::path::Path;
+use regex::Regex;
+
+#[test]
+fn new_classifies_error_kind() {
+    let error_test_cases = [
+        ("Unparseable operation!", ErrorKind::Syntax),
+        ("Unparseable mixed number!", ErrorKind::Syntax),
+        ("Too deeply nested!", ErrorKind::Syntax),
+        ("Fraction with zero denominator!", ErrorKind::ZeroDenominator),
+        ("Division by zero!", ErrorKind::DivisionByZero),
+        ("Unknown variable!", ErrorKind::UnknownVariable),
+        ("Unknown function!", ErrorKind::UnknownFunction),
+        ("Expected a number!", ErrorKind::Type),
+        ("Unknown unit!", ErrorKind::UnknownUnit),
+        ("Units don't match!", ErrorKind::Type),
+        ("Expected a time!", ErrorKind::Type),
+        ("Expected a time signature!", ErrorKind::InvalidArgument),
+        ("Too many frames for the frame rate!", ErrorKind::InvalidArgument),
+        ("Expected an event!", ErrorKind::Type),
+        ("Expected a complex number!", ErrorKind::Type),
+        ("Division by an interval containing zero!", ErrorKind::DivisionByZero),
+        ("Expected a non-negative tolerance!", ErrorKind::InvalidArgument),
+        ("Expected 1 to 100 dice with 1 to 100 sides!", ErrorKind::InvalidArgument),
+        ("Wrong number of arguments!", ErrorKind::InvalidArgument),
+        ("Expected a whole number!", ErrorKind::InvalidArgument),
+        ("Degenerate proportion!", ErrorKind::InvalidArgument),
+        ("Polynomial variables don't match!", ErrorKind::InvalidArgument),
+        ("Expected a polynomial!", ErrorKind::Type),
+        ("Integer overflow!", ErrorKind::Overflow),
+        ("No solution!", ErrorKind::Unsolvable),
+        ("Infinitely many solutions!", ErrorKind::Unsolvable),
+        ("Invalid continued fraction!", ErrorKind::InvalidArgument),
+        ("Expected a ratio of two terms!", ErrorKind::InvalidArgument),
+        ("Expected an unknown!", ErrorKind::InvalidArgument),
+        ("Invalid continued fraction!", ErrorKind::InvalidArgument)
+    ];
+
+    for (message, expected_kind) in &error_test_cases {
+        println!("Testing message {}", message);
+        let error = Error::new(message, 0, 1);
+
+        assert_that!(&error.kind)
+            .is_equal_to(expected_kind);
+    }
+}
+
+// Collects the messages reported by the modules found in the given directory, like `"Integer overflow!"`
+fn collect_messages(directory: &Path, messages: &mut Vec<String>) {
+    let message_re = Regex::new(r#""([A-Z][^"]*!)""#).unwrap();
+
+    for entry in fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_messages(&path, messages);
+        } else if path.file_name().is_some_and(|name| name == "mod.rs") {
+            let source = fs::read_to_string(&path).unwrap();
+            messages.extend(message_re.captures_iter(&source).map(|captures| captures[1].to_string()));
+        }
+    }
+}
+
+#[test]
+fn every_message_has_a_kind() {
+    let mut messages = Vec::new();
+    // Every module is walked, so new ones are checked too. JSON requests & command line options fail before
+    // evaluating, their messages never make an `Error`, and this module lists all the others
+    let sources = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    for entry in fs::read_dir(&sources).unwrap() {
+        let path = entry.unwrap().path();
+        let excluded = path.file_name().is_some_and(|name| ["error", "format", "json", "output", "server"].iter().any(|module| name == *module));
+        if path.is_dir() && !excluded {
+            collect_messages(&path, &mut messages);
+        }
+    }
+
+    assert_that!(&messages.len()).is_greater_than(50);
+    for message in &messages {
+        println!("Classifying message {}", message);
+
+        assert_that!(&ErrorKind::of(message)).is_some();
+    }
+}
+
+#[test]
+fn display_error_as_message() {
+    let error = Error::new("Division by zero!", 6, 9);
+
+    let actual = format!("{}", error);
+
+    assert_that!(actual.as_str())
+        .is_equal_to("Division by zero!");
+}
@@ -0,0 +1,551 @@
+use std::collections::HashMap;
+use crate::fraction::Fraction;
+use crate::error::{Error, Span};
+use crate::expression::{Event, Expression, Operator};
+use crate::value::Value;
+use crate::function::{self, Arity};
+use crate::ratio;
+use crate::linear;
+use crate::matrix::{self, Matrix};
+use crate::polynomial::Polynomial;
+use crate::quantity::{self, Quantity};
+use crate::timecode::{self, Timecode};
+use crate::probability::{self, Distribution};
+use crate::interval::Interval;
+use crate::complex::Complex;
+use crate::money;
+
+/// Evaluates an expression looking up variables in the given ones.
+/// Errors point to the part of the expression that caused them, e.g. the divisor on a division by zero.
+/// Frames of times are counted at the frame rate in the `fps` variable, if it's assigned.
+pub fn evaluate(expression: &Expression, variables: &HashMap<String, Value>) -> Result<Value, Error> {
+    match expression {
+        Expression::Number { value, .. } => Ok(Value::Number(*value)),
+        Expression::Variable { name, span } => variables.get(name).cloned()
+            .ok_or_else(|| Error::new("Unknown variable!", span.start, span.end)),
+        Expression::Call { function, arguments, span } => {
+            let values = arguments.iter()
+                .map(|argument| evaluate(argument, variables))
+                .collect::<Result<Vec<Value>, Error>>()?;
+            call(function, arguments, &values, *span)
+        },
+        Expression::List { items, span } => {
+            let values = items.iter()
+                .map(|item| evaluate(item, variables))
+                .collect::<Result<Vec<Value>, Error>>()?;
+
+            // A list of lists is a matrix whose rows are those lists
+            let rows: Option<Vec<Vec<Fraction>>> = values.iter()
+                .map(|value| match value {
+                    Value::List(row) => Some(row.clone()),
+                    _ => None
+                })
+                .collect();
+            match rows {
+                Some(rows) if !rows.is_empty() => Matrix::new(rows)
+                    .map(Value::Matrix)
+                    .map_err(|e| Error::new(e, span.start, span.end)),
+                _ => items.iter().zip(&values)
+                    .map(|(item, value)| expect_number(item, value))
+                    .collect::<Result<Vec<Fraction>, Error>>()
+                    .map(Value::List)
+            }
+        },
+        Expression::Operation { operator, left, right, .. } => {
+            let left_value = evaluate(left, variables)?;
+            let right_value = evaluate(right, variables)?;
+            apply(*operator, (left, left_value), (right, right_value))
+        },
+        Expression::Ratio { terms, span } => {
+            let values = terms.iter()
+                .map(|term| expect_number(term, &evaluate(term, variables)?))
+                .collect::<Result<Vec<Fraction>, Error>>()?;
+            ratio::simplify(&values)
+                .map(Value::Ratio)
+                .map_err(|e| Error::new(e, span.start, span.end))
+        },
+        Expression::Proportion { left, right, span } => proportion(left, right, variables, *span),
+        Expression::Solve { equations, span } => linear::solve(equations)
+            .map(Value::Solution)
+            .map_err(|e| Error::new(e, span.start, span.end)),
+        Expression::Polynomial { value, .. } => Ok(Value::Polynomial(value.clone())),
+        Expression::Quantity { value, unit, .. } => Ok(Value::Quantity(Quantity::new(*value, unit))),
+        Expression::Conversion { expression, unit, span } => match evaluate(expression, variables)? {
+            Value::Quantity(quantity) => quantity.convert(unit)
+                .map(Value::Quantity)
+                .map_err(|e| Error::new(e, span.start, span.end)),
+            Value::Time(seconds) => Quantity::new(seconds, second()).convert(unit)
+                .map(Value::Quantity)
+                .map_err(|e| Error::new(e, span.start, span.end)),
+            _ => {
+                let span = expression.span();
+                Err(Error::new("Expected a quantity!", span.start, span.end))
+            }
+        },
+        Expression::Time { value, span, .. } => time(value, variables)
+            .map(Value::Time)
+            .map_err(|e| Error::new(e, span.start, span.end)),
+        Expression::Bar { signature, durations, span } => {
+            let mut notes = Vec::new();
+            for duration in durations {
+                match evaluate(duration, variables)? {
+                    Value::List(items) => notes.extend(items),
+                    value => notes.push(expect_number(duration, &value)?)
+                }
+            }
+            signature.bar(&notes)
+                .map(Value::Bar)
+                .map_err(|e| Error::new(e, span.start, span.end))
+        },
+        Expression::Dice { count, sides, span } => Distribution::dice(*count, *sides)
+            .map(Value::Distribution)
+            .map_err(|e| Error::new(e, span.start, span.end)),
+        Expression::Probability { event, span } => probability(event, variables, *span).map(Value::Event),
+        Expression::Interval { center, tolerance, .. } => {
+            let center = expect_number(center, &evaluate(center, variables)?)?;
+            let value = expect_number(tolerance, &evaluate(tolerance, variables)?)?;
+            let span = tolerance.span();
+            Interval::with_tolerance(center, value)
+                .map(Value::Interval)
+                .map_err(|e| Error::new(e, span.start, span.end))
+        },
+        Expression::Imaginary { value, .. } => Ok(Value::from(Complex::new(Fraction::default(), *value))),
+        Expression::Percent { value, .. } => Ok(Value::Percent(*value)),
+        Expression::AsPercent { expression, span } => Value::percent(expect_number(expression, &evaluate(expression, variables)?)?)
+            .map_err(|e| Error::new(e, span.start, span.end)),
+        Expression::Money { value, .. } => Ok(Value::Money(*value)),
+        Expression::Rounded { expression: rounded, rounding, span } => match evaluate(rounded, variables)? {
+            Value::Money(amount) => money::round(&amount, *rounding)
+                .map(Value::Money)
+                .map_err(|e| Error::new(e, span.start, span.end)),
+            _ => {
+                let span = rounded.span();
+                Err(Error::new("Expected an amount of money!", span.start, span.end))
+            }
+        }
+    }
+}
+
+/// The probability of an event. Outcomes are comparisons of distributions, or anything else evaluating to
+/// a probability, while booleans are certain or impossible
+fn probability(event: &Event, variables: &HashMap<String, Value>, span: Span) -> Result<Fraction, Error> {
+    let combined = |events: &[Event], combine: fn(&Fraction, &Fraction) -> Result<Fraction, &'static str>| {
+        events.iter().try_fold(None, |combined: Option<Fraction>, event| {
+            let p = probability(event, variables, span)?;
+            match combined {
+                Some(combined) => combine(&combined, &p).map(Some).map_err(|e| Error::new(e, span.start, span.end)),
+                None => Ok(Some(p))
+            }
+        })
+    };
+
+    match event {
+        Event::Outcome(expression) => match evaluate(expression, variables)? {
+            Value::Event(p) => Ok(p),
+            Value::Boolean(outcome) => Ok(Fraction::from(i32::from(outcome))),
+            _ => {
+                let span = expression.span();
+                Err(Error::new("Expected an event!", span.start, span.end))
+            }
+        },
+        Event::Not(event) => probability::complement(&probability(event, variables, span)?)
+            .map_err(|e| Error::new(e, span.start, span.end)),
+        Event::All(events) => Ok(combined(events, probability::intersection)?.unwrap_or_default()),
+        Event::Any(events) => Ok(combined(events, probability::union)?.unwrap_or_default())
+    }
+}
+
+/// The seconds of a time literal, counting its frames at the frame rate in the `fps` variable or the default one
+fn time(timecode: &Timecode, variables: &HashMap<String, Value>) -> Result<Fraction, &'static str> {
+    let frame_rate = match variables.get("fps").map(Value::as_number) {
+        Some(Some(frame_rate)) if frame_rate.numerator > 0 => frame_rate,
+        Some(_) => return Err("Expected a positive frame rate!"),
+        None => timecode::DEFAULT_FRAME_RATE
+    };
+
+    timecode.in_seconds(&frame_rate)
+}
+
+fn second() -> &'static quantity::Unit {
+    quantity::lookup("s").expect("seconds are a unit")
+}
+
+/// Solves a proportion for its only unknown, i.e. the only variable not defined yet.
+/// When every term is known, whether the proportion holds is checked instead
+fn proportion(left: &Expression, right: &Expression, variables: &HashMap<String, Value>, span: Span) -> Result<Value, Error> {
+    let mut unknown: Option<&str> = None;
+    let mut terms = [None; 4];
+    for (index, term) in proportion_terms(left).iter().chain(proportion_terms(right)).enumerate() {
+        match term {
+            Expression::Variable { name, span } if !variables.contains_key(name) => {
+                if unknown.is_some() {
+                    return Err(Error::new("Expected a single unknown!", span.start, span.end));
+                }
+                unknown = Some(name);
+            },
+            term => terms[index] = Some(expect_number(term, &evaluate(term, variables)?)?)
+        }
+    }
+
+    match (unknown, terms) {
+        (None, [Some(a), Some(b), Some(c), Some(d)]) => ratio::holds(a, b, c, d)
+            .map(Value::Boolean)
+            .map_err(|e| Error::new(e, span.start, span.end)),
+        (Some(variable), terms) => ratio::solve(terms)
+            .map(|value| Value::Solution(vec![(variable.to_string(), value)]))
+            .map_err(|e| Error::new(e, span.start, span.end)),
+        _ => unreachable!()
+    }
+}
+
+/// The terms of a side of a proportion, which the parser checks is a ratio of two terms
+fn proportion_terms(side: &Expression) -> &[Expression] {
+    match side {
+        Expression::Ratio { terms, .. } => terms,
+        _ => unreachable!()
+    }
+}
+
+/// Applies an operator to already evaluated operands
+pub fn apply(operator: Operator, left: (&Expression, Value), right: (&Expression, Value)) -> Result<Value, Error> {
+    let (left, left_value) = left;
+    let (right, right_value) = right;
+    // Taking a part of a number is multiplying it by that part, e.g. `15% of 2_1/2` is `3/20 * 2_1/2`
+    let operator = match operator {
+        Operator::Of => Operator::Multiply,
+        operator => operator
+    };
+
+    if let Some(result) = linear_algebra(operator, &left_value, &right_value)
+        .or_else(|| polynomial_arithmetic(operator, &left_value, &right_value))
+        .or_else(|| quantity_arithmetic(operator, &left_value, &right_value))
+        .or_else(|| time_arithmetic(operator, &left_value, &right_value))
+        .or_else(|| distribution_arithmetic(operator, &left_value, &right_value))
+        .or_else(|| interval_arithmetic(operator, &left_value, &right_value))
+        .or_else(|| complex_arithmetic(operator, &left_value, &right_value))
+        .or_else(|| money_arithmetic(operator, &left_value, &right_value))
+        .or_else(|| percent_arithmetic(operator, &left_value, &right_value)) {
+        // Only divisions by zero can fail on divisions, the other operations fail on the dimensions of both operands
+        let span = match operator {
+            Operator::Divide => right.span(),
+            _ => Span { start: left.span().start, end: right.span().end }
+        };
+        return result.map_err(|e| Error::new(e, span.start, span.end));
+    }
+
+    // Numbers are compared by value, whatever they're shown like, e.g. `factor(12) == 12`
+    let equal = match (left_value.as_number(), right_value.as_number()) {
+        (Some(x), Some(y)) => x == y,
+        _ => left_value == right_value
+    };
+    match operator {
+        Operator::Equal => return Ok(Value::Boolean(equal)),
+        Operator::NotEqual => return Ok(Value::Boolean(!equal)),
+        _ => {}
+    }
+
+    let x = expect_number(left, &left_value)?;
+    let y = expect_number(right, &right_value)?;
+    let span = right.span();
+    // Overflows are blamed on the whole operation, unlike division by zero which is the divisor's fault
+    let overflow = |e| Error::new(e, left.span().start, span.end);
+
+    match operator {
+        Operator::Add => x.checked_add(&y).map(Value::Number).map_err(overflow),
+        Operator::Substract => x.checked_substract(&y).map(Value::Number).map_err(overflow),
+        Operator::Multiply => x.checked_multiply(&y).map(Value::Number).map_err(overflow),
+        Operator::Divide => x.divide(&y)
+            .map(Value::Number)
+            .map_err(|e| if y.is_zero() { Error::new(e, span.start, span.end) } else { overflow(e) }),
+        Operator::Less => Ok(Value::Boolean(x < y)),
+        Operator::LessOrEqual => Ok(Value::Boolean(x <= y)),
+        Operator::Greater => Ok(Value::Boolean(x > y)),
+        Operator::GreaterOrEqual => Ok(Value::Boolean(x >= y)),
+        Operator::Equal | Operator::NotEqual | Operator::Of => unreachable!()
+    }
+}
+
+/// Applies an operator to matrices & vectors, i.e. lists of numbers. Vectors are added & substracted entry by entry,
+/// multiplying them gives their dot product, and multiplying a matrix by a vector takes it as a column.
+/// Gives `None` if the operator doesn't apply to the operands
+fn linear_algebra(operator: Operator, left: &Value, right: &Value) -> Option<Result<Value, &'static str>> {
+    let scalar = |value: &Value| value.as_number();
+
+    let result = match (operator, left, right) {
+        (Operator::Add, Value::Matrix(a), Value::Matrix(b)) => a.add(b).map(Value::Matrix),
+        (Operator::Substract, Value::Matrix(a), Value::Matrix(b)) => a.substract(b).map(Value::Matrix),
+        (Operator::Multiply, Value::Matrix(a), Value::Matrix(b)) => a.multiply(b).map(Value::Matrix),
+        (Operator::Multiply, Value::Matrix(a), Value::List(v)) => a.apply(v).map(Value::List),
+        (Operator::Add, Value::List(u), Value::List(v)) => matrix::add_vectors(u, v).map(Value::List),
+        (Operator::Substract, Value::List(u), Value::List(v)) => matrix::substract_vectors(u, v).map(Value::List),
+        (Operator::Multiply, Value::List(u), Value::List(v)) => matrix::dot(u, v).map(Value::Number),
+        (Operator::Multiply, Value::Matrix(a), x) | (Operator::Multiply, x, Value::Matrix(a)) =>
+            a.scale(&scalar(x)?).map(Value::Matrix),
+        (Operator::Divide, Value::Matrix(a), x) => scalar(x)?.recip().and_then(|factor| a.scale(&factor)).map(Value::Matrix),
+        (Operator::Multiply, Value::List(v), x) | (Operator::Multiply, x, Value::List(v)) =>
+            scale(v, &scalar(x)?).map(Value::List),
+        (Operator::Divide, Value::List(v), x) => scalar(x)?.recip().and_then(|factor| scale(v, &factor)).map(Value::List),
+        _ => return None
+    };
+    Some(result)
+}
+
+/// Applies an operator to polynomials. Numbers are taken as constant polynomials, except as divisors which
+/// divide every coefficient. Dividing two polynomials gives the quotient & the remainder of their long division.
+/// Gives `None` if the operator doesn't apply to the operands
+fn polynomial_arithmetic(operator: Operator, left: &Value, right: &Value) -> Option<Result<Value, &'static str>> {
+    let polynomial = |value: &Value, other: &Polynomial| match value {
+        Value::Polynomial(polynomial) => Some(polynomial.clone()),
+        value => value.as_number().map(|number| Polynomial::new(vec![number], other.variable()))
+    };
+    let (p, q) = match (left, right) {
+        (Value::Polynomial(p), Value::Polynomial(q)) => (p.clone(), q.clone()),
+        (Value::Polynomial(p), value) => (p.clone(), polynomial(value, p)?),
+        (value, Value::Polynomial(q)) => (polynomial(value, q)?, q.clone()),
+        _ => return None
+    };
+
+    let result = match (operator, right) {
+        (Operator::Add, _) => p.add(&q).map(Value::Polynomial),
+        (Operator::Substract, _) => p.substract(&q).map(Value::Polynomial),
+        (Operator::Multiply, _) => p.multiply(&q).map(Value::Polynomial),
+        (Operator::Divide, Value::Polynomial(_)) => p.divide(&q)
+            .map(|(quotient, remainder)| Value::PolynomialDivision { quotient, remainder }),
+        (Operator::Divide, divisor) => divisor.as_number()?.recip().and_then(|factor| p.scale(&factor)).map(Value::Polynomial),
+        _ => return None
+    };
+    Some(result)
+}
+
+/// Applies an operator to quantities. Quantities of the same dimension can be added, substracted & compared whatever
+/// their units, and dividing them gives how many times one fits in the other. Numbers can only scale quantities.
+/// Gives `None` if the operator doesn't apply to the operands
+fn quantity_arithmetic(operator: Operator, left: &Value, right: &Value) -> Option<Result<Value, &'static str>> {
+    let result = match (left, right) {
+        (Value::Quantity(a), Value::Quantity(b)) => match operator {
+            Operator::Add => a.add(b).map(Value::Quantity),
+            Operator::Substract => a.substract(b).map(Value::Quantity),
+            Operator::Multiply => Err("Expected a number!"),
+            Operator::Divide => a.ratio(b).map(Value::Number),
+            operator => a.compare(b).map(|ordering| Value::Boolean(operator.holds(ordering)))
+        },
+        (Value::Quantity(a), x) => match (operator, x.as_number()?) {
+            (Operator::Multiply, factor) => a.scale(&factor).map(Value::Quantity),
+            (Operator::Divide, divisor) => divisor.recip().and_then(|factor| a.scale(&factor)).map(Value::Quantity),
+            _ => Err("Units don't match!")
+        },
+        (x, Value::Quantity(a)) => match (operator, x.as_number()?) {
+            (Operator::Multiply, factor) => a.scale(&factor).map(Value::Quantity),
+            _ => Err("Units don't match!")
+        },
+        _ => return None
+    };
+    Some(result)
+}
+
+/// Applies an operator to times. Times can be added, substracted & compared, and dividing them gives how many
+/// times one fits in the other. Quantities of time are taken as times, e.g. `1:00:00 + 90 min`, and numbers can only
+/// scale times. Gives `None` if the operator doesn't apply to the operands
+fn time_arithmetic(operator: Operator, left: &Value, right: &Value) -> Option<Result<Value, &'static str>> {
+    let seconds = |value: &Value| match value {
+        Value::Time(seconds) => Some(Ok(*seconds)),
+        Value::Quantity(quantity) => Some(quantity.convert(second()).map(|quantity| quantity.amount)),
+        _ => None
+    };
+    if !matches!(left, Value::Time(_)) && !matches!(right, Value::Time(_)) {
+        return None;
+    }
+
+    let result = match (seconds(left), seconds(right)) {
+        (Some(a), Some(b)) => a.and_then(|a| b.map(|b| (a, b))).and_then(|(a, b)| match operator {
+            Operator::Add => timecode::add(&a, &b).map(Value::Time),
+            Operator::Substract => timecode::add(&a, &-b).map(Value::Time),
+            Operator::Multiply => Err("Expected a number!"),
+            Operator::Divide => b.recip().and_then(|b| timecode::multiply(&a, &b)).map(Value::Number),
+            operator => Ok(Value::Boolean(operator.holds(a.cmp(&b))))
+        }),
+        (Some(a), None) => a.and_then(|a| match (operator, right.as_number()) {
+            (Operator::Multiply, Some(factor)) => timecode::multiply(&a, &factor).map(Value::Time),
+            (Operator::Divide, Some(divisor)) => divisor.recip().and_then(|factor| timecode::multiply(&a, &factor)).map(Value::Time),
+            _ => Err("Expected a time!")
+        }),
+        (None, Some(b)) => b.and_then(|b| match (operator, left.as_number()) {
+            (Operator::Multiply, Some(factor)) => timecode::multiply(&factor, &b).map(Value::Time),
+            _ => Err("Expected a time!")
+        }),
+        (None, None) => unreachable!()
+    };
+    Some(result)
+}
+
+/// Applies an operator to distributions, taking them as independent. Adding, substracting or multiplying them gives
+/// the distribution of the result, e.g. `1d8 + 2`, and comparing them gives the probability the comparison holds.
+/// Whole numbers are taken as certain outcomes. Gives `None` if the operator doesn't apply to the operands
+fn distribution_arithmetic(operator: Operator, left: &Value, right: &Value) -> Option<Result<Value, &'static str>> {
+    let distribution = |value: &Value| match value {
+        Value::Distribution(distribution) => Some(Ok(distribution.clone())),
+        value => value.as_number().map(|number| match number.is_whole() {
+            true => Ok(Distribution::constant(number.numerator)),
+            false => Err("Expected a whole number!")
+        })
+    };
+    if !matches!(left, Value::Distribution(_)) && !matches!(right, Value::Distribution(_)) {
+        return None;
+    }
+
+    let (x, y) = match (distribution(left)?, distribution(right)?) {
+        (Ok(x), Ok(y)) => (x, y),
+        (Err(e), _) | (_, Err(e)) => return Some(Err(e))
+    };
+    let result = match operator {
+        Operator::Add => x.combine(&y, i32::checked_add).map(Value::Distribution),
+        Operator::Substract => x.combine(&y, i32::checked_sub).map(Value::Distribution),
+        Operator::Multiply => x.combine(&y, i32::checked_mul).map(Value::Distribution),
+        Operator::Divide => Err("Expected a number!"),
+        operator => x.probability(&y, |a, b| operator.holds(a.cmp(&b))).map(Value::Event)
+    };
+    Some(result)
+}
+
+/// Applies an arithmetic operator to intervals, taking numbers as intervals of a single value.
+/// Gives `None` if the operator doesn't apply to the operands
+fn interval_arithmetic(operator: Operator, left: &Value, right: &Value) -> Option<Result<Value, &'static str>> {
+    let interval = |value: &Value| match value {
+        Value::Interval(interval) => Some(*interval),
+        value => value.as_number().map(|number| Interval::new(number, number))
+    };
+    if !matches!(left, Value::Interval(_)) && !matches!(right, Value::Interval(_)) {
+        return None;
+    }
+
+    let (a, b) = (interval(left)?, interval(right)?);
+    let result = match operator {
+        Operator::Add => a.add(&b),
+        Operator::Substract => a.substract(&b),
+        Operator::Multiply => a.multiply(&b),
+        Operator::Divide => a.divide(&b),
+        _ => return None
+    };
+    Some(result.map(Value::Interval))
+}
+
+/// Applies an arithmetic operator to complex numbers, taking numbers as complex numbers without imaginary part.
+/// Complex numbers can't be ordered, so comparisons only check equality.
+/// Gives `None` if the operator doesn't apply to the operands
+fn complex_arithmetic(operator: Operator, left: &Value, right: &Value) -> Option<Result<Value, &'static str>> {
+    let complex_number = |value: &Value| match value {
+        Value::Complex(complex) => Some(*complex),
+        value => value.as_number().map(|number| Complex::new(number, Fraction::default()))
+    };
+    if !matches!(left, Value::Complex(_)) && !matches!(right, Value::Complex(_)) {
+        return None;
+    }
+
+    let (z, w) = (complex_number(left)?, complex_number(right)?);
+    let result = match operator {
+        Operator::Add => z.add(&w),
+        Operator::Substract => z.substract(&w),
+        Operator::Multiply => z.multiply(&w),
+        Operator::Divide => z.divide(&w),
+        _ => return None
+    };
+    Some(result.map(Value::from))
+}
+
+/// Applies an operator to amounts of money, which stay exact until they're rounded. Amounts can be added, substracted
+/// & compared, and dividing them gives how many times one fits in the other. Numbers can only scale amounts, while
+/// adding or substracting a percentage changes an amount by that percentage of it, e.g. `$40 + 10%` is `$44`.
+/// Gives `None` if the operator doesn't apply to the operands
+fn money_arithmetic(operator: Operator, left: &Value, right: &Value) -> Option<Result<Value, &'static str>> {
+    let result = match (left, right) {
+        (Value::Money(a), Value::Money(b)) => match operator {
+            Operator::Add => a.checked_add(b).map(Value::Money),
+            Operator::Substract => a.checked_substract(b).map(Value::Money),
+            Operator::Multiply => Err("Expected a number!"),
+            Operator::Divide => a.divide(b).map(Value::Number),
+            operator => Ok(Value::Boolean(operator.holds(a.cmp(b))))
+        },
+        (Value::Money(a), x) => match (operator, x) {
+            (Operator::Add, Value::Percent(percentage)) => Fraction::from(1).checked_add(percentage)
+                .and_then(|factor| a.checked_multiply(&factor))
+                .map(Value::Money),
+            (Operator::Substract, Value::Percent(percentage)) => Fraction::from(1).checked_substract(percentage)
+                .and_then(|factor| a.checked_multiply(&factor))
+                .map(Value::Money),
+            (Operator::Multiply, x) => a.checked_multiply(&x.as_number()?).map(Value::Money),
+            (Operator::Divide, x) => x.as_number()?.recip().and_then(|factor| a.checked_multiply(&factor)).map(Value::Money),
+            _ => Err("Expected an amount of money!")
+        },
+        (x, Value::Money(a)) => match (operator, x.as_number()?) {
+            (Operator::Multiply, factor) => factor.checked_multiply(a).map(Value::Money),
+            _ => Err("Expected an amount of money!")
+        },
+        _ => return None
+    };
+    Some(result)
+}
+
+/// Applies adding or substracting a percentage, which changes a number by that percentage of it, e.g. `40 + 10%` is `44`,
+/// while percentages are added & substracted as is, e.g. `10% + 5%` is `15%`.
+/// Gives `None` if the operator doesn't apply to the operands
+fn percent_arithmetic(operator: Operator, left: &Value, right: &Value) -> Option<Result<Value, &'static str>> {
+    let change = match (operator, right) {
+        (Operator::Add, Value::Percent(percentage)) => *percentage,
+        (Operator::Substract, Value::Percent(percentage)) => -*percentage,
+        _ => return None
+    };
+
+    let result = match left {
+        Value::Percent(percentage) => percentage.checked_add(&change).and_then(Value::percent),
+        value => {
+            let number = value.as_number()?;
+            Fraction::from(1).checked_add(&change).and_then(|factor| number.checked_multiply(&factor)).map(Value::Number)
+        }
+    };
+    Some(result)
+}
+
+/// Whether an operation changes a number by a percentage of it, like `40 + 10%` does
+pub(crate) fn changes_by_percent(operator: Operator, left: &Value, right: &Value) -> bool {
+    matches!((operator, right), (Operator::Add | Operator::Substract, Value::Percent(_)))
+        && !matches!(left, Value::Percent(_)) && left.as_number().is_some()
+}
+
+fn scale(vector: &[Fraction], factor: &Fraction) -> Result<Vec<Fraction>, &'static str> {
+    vector.iter().map(|entry| entry.checked_multiply(factor)).collect()
+}
+
+fn call(name: &str, arguments: &[Expression], values: &[Value], span: Span) -> Result<Value, Error> {
+    let function = function::lookup(name)
+        .ok_or_else(|| Error::new("Unknown function!", span.start, span.end))?;
+    if let Some(parameters) = function.parameters() {
+        let mismatch = parameters.iter().zip(arguments.iter().zip(values))
+            .find(|(kind, (_, value))| !kind.matches(value));
+        if let (true, Some((kind, (argument, _)))) = (values.len() == parameters.len(), mismatch) {
+            let span = argument.span();
+            return Err(Error::new(kind.error(), span.start, span.end));
+        }
+        return function.apply_to_values(values)
+            .map_err(|e| Error::new(e, span.start, span.end));
+    }
+    let mut numbers = Vec::new();
+    for (argument, value) in arguments.iter().zip(values) {
+        match value {
+            Value::List(items) if function.arity == Arity::Numbers => numbers.extend(items),
+            value => numbers.push(expect_number(argument, value)?)
+        }
+    }
+
+    function.apply(&numbers)
+        .map_err(|e| Error::new(e, span.start, span.end))
+}
+
+fn expect_number(expression: &Expression, value: &Value) -> Result<Fraction, Error> {
+    value.as_number().ok_or_else(|| {
+        let span = expression.span();
+        Error::new("Expected a number!", span.start, span.end)
+    })
+}
+
+#[cfg(test)]
+mod tests;
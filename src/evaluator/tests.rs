@@ -0,0 +1,662 @@
+use spectral::prelude::*;
+use super::*;
+use crate::expression;
+use crate::mixed_number;
+use crate::matrix::Matrix;
+use crate::polynomial::Polynomial;
+use std::cmp::Ordering;
+use crate::quantity::{self, Quantity};
+use crate::fraction::fraction;
+
+fn evaluate_input(input: &str) -> Result<Value, Error> {
+    let statement = expression::parse(input).unwrap();
+
+    evaluate(&statement.expression, &HashMap::new())
+}
+
+// This function will help test supported computations in tests below
+fn test_compute_operations(context: &str, operation_test_cases: &[(&str, &str)]) {
+    for (operation_expression, result_expression) in operation_test_cases {
+        println!("{} expression {}", context, operation_expression);
+
+        let actual_result = evaluate_input(operation_expression).unwrap();
+
+        let expected_result = mixed_number::parse_as_fraction(result_expression).unwrap();
+        assert_that!(&actual_result)
+            .is_equal_to(Value::Number(expected_result));
+    }
+}
+
+fn test_comparisons(operation_test_cases: &[(&str, bool)]) {
+    for (operation_expression, expected) in operation_test_cases {
+        println!("Comparison expression {}", operation_expression);
+
+        let actual_result = evaluate_input(operation_expression).unwrap();
+
+        assert_that!(&actual_result)
+            .is_equal_to(Value::Boolean(*expected));
+    }
+}
+
+#[test]
+fn compute_addition() {
+    test_compute_operations("Addition", &[("2_3/8 + 3_1/5", "5_23/40")]);
+}
+
+#[test]
+fn compute_substraction() {
+    test_compute_operations("Substraction", &[("2_3/8 - 3_1/5", "-33/40")]);
+}
+
+#[test]
+fn compute_multiplication() {
+    test_compute_operations("Multiplication", &[("2_3/8 * 3_1/5", "7_3/5")]);
+}
+
+#[test]
+fn compute_division() {
+    test_compute_operations("Division", &[("2_3/8 / 3_1/5", "95/128")]);
+}
+
+#[test]
+fn compute_nested_operations() {
+    test_compute_operations("Nested", &[("(1/2 + 1/4) * 2", "1_1/2"), ("1 - (1/2 - 1/4)", "3/4")]);
+}
+
+#[test]
+fn compute_comparisons() {
+    test_comparisons(&[
+        ("5_3/8 > 43/8", false),
+        ("5_3/8 >= 43/8", true),
+        ("-1/2 < 1/3", true),
+        ("2/4 <= 1/3", false),
+        ("2/4 == 1/2", true),
+        ("1_1/2 != 3/2", false),
+        ("(1/2 < 1) == (2 > 1)", true)
+    ]);
+}
+
+#[test]
+fn compute_min_max_and_clamp() {
+    test_compute_operations("Functions", &[
+        ("min(1/2, 2/3, -1/4)", "-1/4"),
+        ("max(1/2, 2/3)", "2/3"),
+        ("clamp(5/4, 0, 1)", "1"),
+        ("clamp(-1, 0, 1)", "0"),
+        ("clamp(1/2, 0, 1)", "1/2")
+    ]);
+}
+
+#[test]
+fn compute_division_by_zero_error_points_to_divisor() {
+    let error = evaluate_input("2_1/2 / 0/3").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Division by zero!", 8, 11));
+}
+
+#[test]
+fn compute_with_unknown_variable() {
+    let error = evaluate_input("1/2 + y").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unknown variable!", 6, 7));
+}
+
+#[test]
+fn compute_with_variables() {
+    let mut variables = HashMap::new();
+    variables.insert("x".to_string(), Value::Number(Fraction::new(5, 2).unwrap()));
+    let statement = expression::parse("x * 3_3/4").unwrap();
+
+    let actual = evaluate(&statement.expression, &variables).unwrap();
+
+    assert_that!(&actual)
+        .is_equal_to(Value::Number(Fraction::new(75, 8).unwrap()));
+}
+
+#[test]
+fn compute_arithmetic_on_boolean_is_an_error() {
+    let error = evaluate_input("(1 < 2) + 1").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Expected a number!", 1, 6));
+}
+
+#[test]
+fn compute_with_library_functions() {
+    test_compute_operations("Library functions", &[
+        ("abs(1/2 - 3/4)", "1/4"),
+        ("recip(2_1/2) * 5", "2"),
+        ("floor(-2_1/4) + ceil(2_1/4)", "0"),
+        ("round(5/2) - trunc(-5/2)", "5"),
+        ("frac(19/8) + whole(19/8)", "2_3/8"),
+        ("sign(-3/4) * num(6/8)", "-3"),
+        ("den(6/8)", "4")
+    ]);
+}
+
+#[test]
+fn compute_with_number_theory_functions() {
+    test_compute_operations("Number theory", &[
+        ("gcd(12, 18) + lcm(4, 6)", "18"),
+        ("1/6 * lcd(1/6, 3/8, 5/12)", "4"),
+        ("factor(360) / 8", "45")
+    ]);
+    test_comparisons(&[("factor(12) == 12", true), ("lcd(1/2, 1/3) != 6", false)]);
+}
+
+#[test]
+fn compute_smallest_integer_is_an_overflow() {
+    assert_that!(&evaluate_input("abs(-2147483647 - 1)"))
+        .is_equal_to(Err(Error::new("Integer overflow!", 4, 19)));
+    assert_that!(&expression::parse("factor(-2147483648)").err())
+        .is_equal_to(Some(Error::new("Integer overflow!", 7, 18)));
+}
+
+#[test]
+fn compute_with_continued_fractions() {
+    test_compute_operations("Continued fractions", &[
+        ("[4; 2, 6, 7] * 93", "415"),
+        ("cf(415/93) - 4", "43/93")
+    ]);
+}
+
+#[test]
+fn compute_with_statistics_functions() {
+    test_compute_operations("Statistics", &[
+        ("sum([1/2, 3_1/4, 7/8])", "4_5/8"),
+        ("mean([1/2, 3_1/4], 7/8)", "1_13/24"),
+        ("median([1, 2, 3, 4])", "2_1/2"),
+        ("max([1/2, 3/4]) - min([1/2, 3/4])", "1/4"),
+        ("variance([1, 2, 3, 4]) * 4", "5")
+    ]);
+}
+
+#[test]
+fn compute_list_literal() {
+    let actual = evaluate_input("[1/2, 1/4 + 1/4, 3]").unwrap();
+
+    assert_that!(&actual)
+        .is_equal_to(Value::List(vec![Fraction::new(1, 2).unwrap(), Fraction::new(1, 2).unwrap(), Fraction::from(3)]));
+}
+
+#[test]
+fn compute_list_with_boolean_is_an_error() {
+    let error = evaluate_input("[1/2, 1 < 2]").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Expected a number!", 6, 11));
+}
+
+#[test]
+fn call_with_list_where_number_is_expected() {
+    let error = evaluate_input("abs([1/2])").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Expected a number!", 4, 9));
+}
+
+#[test]
+fn call_mean_of_empty_list() {
+    let error = evaluate_input("mean([])").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Expected at least one number!", 0, 8));
+}
+
+#[test]
+fn compute_arithmetic_on_list_is_an_error() {
+    let error = evaluate_input("convergents(9/2) + 1").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Expected a number!", 0, 16));
+}
+
+#[test]
+fn call_gcd_with_fraction() {
+    let error = evaluate_input("gcd(1/2, 4)").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Expected a whole number!", 0, 11));
+}
+
+#[test]
+fn call_recip_of_zero() {
+    let error = evaluate_input("recip(0) + 1").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Division by zero!", 0, 8));
+}
+
+#[test]
+fn call_clamp_with_wrong_arguments() {
+    let error = evaluate_input("clamp(1, 2)").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Wrong number of arguments!", 0, 11));
+}
+
+#[test]
+fn call_clamp_with_inverted_bounds() {
+    let error = evaluate_input("clamp(1/2, 1, 0)").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Lower bound greater than upper bound!", 0, 16));
+}
+
+#[test]
+fn evaluate_ratios() {
+    assert_that!(&evaluate_input("1_1/2 : 2"))
+        .is_equal_to(Ok(Value::Ratio(vec![3, 4])));
+    assert_that!(&evaluate_input("6 : 8 : (5 * 2)"))
+        .is_equal_to(Ok(Value::Ratio(vec![3, 4, 5])));
+}
+
+#[test]
+fn solve_proportion() {
+    assert_that!(&evaluate_input("3/4 : 6 = x : 10"))
+        .is_equal_to(Ok(Value::Solution(vec![("x".to_string(), Fraction::new(5, 4).unwrap())])));
+    assert_that!(&evaluate_input("servings : 3/4 = 10 : 1_1/4"))
+        .is_equal_to(Ok(Value::Solution(vec![("servings".to_string(), Fraction::from(6))])));
+}
+
+#[test]
+fn check_proportion_without_unknowns() {
+    assert_that!(&evaluate_input("3/4 : 6 = 1_1/4 : 10"))
+        .is_equal_to(Ok(Value::Boolean(true)));
+    assert_that!(&evaluate_input("1 : 2 = 2 : 3"))
+        .is_equal_to(Ok(Value::Boolean(false)));
+}
+
+#[test]
+fn solve_degenerate_proportion() {
+    let error = evaluate_input("0 : 6 = 4 : x").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Degenerate proportion!", 0, 13));
+}
+
+#[test]
+fn solve_proportion_with_several_unknowns() {
+    let error = evaluate_input("x : y = 1 : 2").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Expected a single unknown!", 4, 5));
+}
+
+#[test]
+fn solve_equations() {
+    assert_that!(&evaluate_input("solve 2/3 x + 1_1/4 = 5/6"))
+        .is_equal_to(Ok(Value::Solution(vec![("x".to_string(), Fraction::new(-5, 8).unwrap())])));
+    assert_that!(&evaluate_input("solve { x + y = 3/2; x - y = 1/4 }")).is_equal_to(Ok(Value::Solution(vec![
+        ("x".to_string(), Fraction::new(7, 8).unwrap()),
+        ("y".to_string(), Fraction::new(5, 8).unwrap())
+    ])));
+}
+
+#[test]
+fn solve_system_without_single_solution() {
+    assert_that!(&evaluate_input("solve { x + y = 1; 2x + 2y = 3 }"))
+        .is_equal_to(Err(Error::new("No solution!", 0, 32)));
+    assert_that!(&evaluate_input("solve { x + y = 1; 2x + 2y = 2 }"))
+        .is_equal_to(Err(Error::new("Infinitely many solutions!", 0, 32)));
+}
+
+// This function will help build the matrices used in tests below
+fn matrix(rows: &[&[i32]]) -> Value {
+    let rows = rows.iter().map(|row| row.iter().map(|&entry| Fraction::from(entry)).collect()).collect();
+
+    Value::Matrix(Matrix::new(rows).unwrap())
+}
+
+#[test]
+fn evaluate_matrix_literal() {
+    assert_that!(&evaluate_input("[[1, 2], [3, 1 + 3]]"))
+        .is_equal_to(Ok(matrix(&[&[1, 2], &[3, 4]])));
+}
+
+#[test]
+fn evaluate_ragged_matrix_literal() {
+    assert_that!(&evaluate_input("[[1, 2], [3]]"))
+        .is_equal_to(Err(Error::new("Expected a rectangular matrix!", 0, 13)));
+}
+
+#[test]
+fn compute_matrix_operations() {
+    assert_that!(&evaluate_input("[[1, 2], [3, 4]] + [[1, 0], [0, 1]]"))
+        .is_equal_to(Ok(matrix(&[&[2, 2], &[3, 5]])));
+    assert_that!(&evaluate_input("[[1, 2], [3, 4]] * [[0, 1], [1, 0]]"))
+        .is_equal_to(Ok(matrix(&[&[2, 1], &[4, 3]])));
+    assert_that!(&evaluate_input("2 * [[1, 2], [3, 4]]"))
+        .is_equal_to(Ok(matrix(&[&[2, 4], &[6, 8]])));
+    assert_that!(&evaluate_input("[[2, 4]] / 2"))
+        .is_equal_to(Ok(matrix(&[&[1, 2]])));
+    assert_that!(&evaluate_input("[[1, 2], [3, 4]] * [1, 1]"))
+        .is_equal_to(Ok(Value::List(vec![Fraction::from(3), Fraction::from(7)])));
+}
+
+#[test]
+fn compute_vector_operations() {
+    assert_that!(&evaluate_input("[1/2, 2] + [1/2, 1]"))
+        .is_equal_to(Ok(Value::List(vec![Fraction::from(1), Fraction::from(3)])));
+    assert_that!(&evaluate_input("[1/2, 2] * [4, 1/4]"))
+        .is_equal_to(Ok(Value::Number(Fraction::new(5, 2).unwrap())));
+    assert_that!(&evaluate_input("[1/2, 2] * 2"))
+        .is_equal_to(Ok(Value::List(vec![Fraction::from(1), Fraction::from(4)])));
+}
+
+#[test]
+fn multiply_matrices_with_mismatched_dimensions() {
+    assert_that!(&evaluate_input("[[1, 2]] * [[1, 2]]"))
+        .is_equal_to(Err(Error::new("Matrix dimensions don't match!", 0, 19)));
+}
+
+#[test]
+fn call_matrix_functions() {
+    assert_that!(&evaluate_input("det([[1/2, 1/3], [1/4, 1/5]])"))
+        .is_equal_to(Ok(Value::Number(Fraction::new(1, 60).unwrap())));
+    assert_that!(&evaluate_input("inverse([[1/2, 1/3], [1/4, 1/5]])"))
+        .is_equal_to(Ok(matrix(&[&[12, -20], &[-15, 30]])));
+    assert_that!(&evaluate_input("transpose([[1, 2]])"))
+        .is_equal_to(Ok(matrix(&[&[1], &[2]])));
+}
+
+#[test]
+fn call_matrix_function_with_number() {
+    assert_that!(&evaluate_input("det(2)"))
+        .is_equal_to(Err(Error::new("Expected a matrix!", 4, 5)));
+}
+
+// This function will help build the polynomials used in tests below, from their coefficients from the lowest degree up
+fn polynomial(coefficients: &[i32]) -> Polynomial {
+    Polynomial::new(coefficients.iter().map(|&coefficient| Fraction::from(coefficient)).collect(), "x")
+}
+
+#[test]
+fn compute_polynomial_operations() {
+    assert_that!(&evaluate_input("poly(x^2 - 1) * poly(x + 1)"))
+        .is_equal_to(Ok(Value::Polynomial(polynomial(&[-1, -1, 1, 1]))));
+    assert_that!(&evaluate_input("poly(x^2) - 1"))
+        .is_equal_to(Ok(Value::Polynomial(polynomial(&[-1, 0, 1]))));
+    assert_that!(&evaluate_input("poly(2x) / 2"))
+        .is_equal_to(Ok(Value::Polynomial(polynomial(&[0, 1]))));
+    assert_that!(&evaluate_input("poly(x^2 + 1) / poly(x - 1)"))
+        .is_equal_to(Ok(Value::PolynomialDivision { quotient: polynomial(&[1, 1]), remainder: polynomial(&[2]) }));
+}
+
+#[test]
+fn divide_polynomial_by_zero() {
+    assert_that!(&evaluate_input("poly(x) / poly(0)"))
+        .is_equal_to(Err(Error::new("Division by zero!", 10, 17)));
+}
+
+#[test]
+fn add_polynomials_in_different_variables() {
+    assert_that!(&evaluate_input("poly(x) + poly(y)"))
+        .is_equal_to(Err(Error::new("Polynomial variables don't match!", 0, 17)));
+}
+
+#[test]
+fn call_polynomial_functions() {
+    assert_that!(&evaluate_input("eval(poly(x^2 + 1), 1/2)"))
+        .is_equal_to(Ok(Value::Number(Fraction::new(5, 4).unwrap())));
+    assert_that!(&evaluate_input("deriv(poly(x^3 + 2x))"))
+        .is_equal_to(Ok(Value::Polynomial(polynomial(&[2, 0, 3]))));
+    assert_that!(&evaluate_input("poly_gcd(poly(x^2 - 1), poly(x^2 + 2x + 1))"))
+        .is_equal_to(Ok(Value::Polynomial(polynomial(&[1, 1]))));
+    assert_that!(&evaluate_input("roots(poly(6x^2 - x - 2))"))
+        .is_equal_to(Ok(Value::List(vec![Fraction::new(-1, 2).unwrap(), Fraction::new(2, 3).unwrap()])));
+}
+
+#[test]
+fn call_polynomial_function_with_wrong_arguments() {
+    assert_that!(&evaluate_input("eval(2, 1)"))
+        .is_equal_to(Err(Error::new("Expected a polynomial!", 5, 6)));
+    assert_that!(&evaluate_input("eval(poly(x), poly(x))"))
+        .is_equal_to(Err(Error::new("Expected a number!", 14, 21)));
+    assert_that!(&evaluate_input("deriv(poly(x), 1)"))
+        .is_equal_to(Err(Error::new("Wrong number of arguments!", 0, 17)));
+}
+
+// This function will help build the quantities used in tests below
+fn quantity(amount: &str, unit: &str) -> Value {
+    Value::Quantity(Quantity::new(fraction(amount), quantity::lookup(unit).unwrap()))
+}
+
+#[test]
+fn compute_quantity_operations() {
+    assert_that!(&evaluate_input("3/4 in * 2"))
+        .is_equal_to(Ok(quantity("1_1/2", "in")));
+    assert_that!(&evaluate_input("1 ft + 3 in"))
+        .is_equal_to(Ok(quantity("1_1/4", "ft")));
+    assert_that!(&evaluate_input("1 ft / 3 in"))
+        .is_equal_to(Ok(Value::Number(Fraction::from(4))));
+    assert_that!(&evaluate_input("1 lb / 4"))
+        .is_equal_to(Ok(quantity("1/4", "lb")));
+    assert_that!(&evaluate_input("12 in == 1 ft"))
+        .is_equal_to(Ok(Value::Boolean(true)));
+    assert_that!(&evaluate_input("90 min < 1 h"))
+        .is_equal_to(Ok(Value::Boolean(false)));
+}
+
+#[test]
+fn convert_quantities() {
+    assert_that!(&evaluate_input("1_1/2 ft to in"))
+        .is_equal_to(Ok(quantity("18", "in")));
+    assert_that!(&evaluate_input("(1 kg - 250 g) in lb"))
+        .is_equal_to(Ok(quantity("75000000/45359237", "lb")));
+}
+
+#[test]
+fn add_quantities_of_different_dimensions() {
+    assert_that!(&evaluate_input("2 in + 3 lb"))
+        .is_equal_to(Err(Error::new("Units don't match!", 0, 11)));
+    assert_that!(&evaluate_input("2 in to s"))
+        .is_equal_to(Err(Error::new("Units don't match!", 0, 9)));
+}
+
+#[test]
+fn convert_number_without_unit() {
+    assert_that!(&evaluate_input("(1 + 2) to in"))
+        .is_equal_to(Err(Error::new("Expected a quantity!", 1, 6)));
+}
+
+// This function will help build the times used in tests below
+fn time(seconds: &str) -> Value {
+    Value::Time(fraction(seconds))
+}
+
+#[test]
+fn compute_time_operations() {
+    assert_that!(&evaluate_input("1:23:45 + 2:50:30"))
+        .is_equal_to(Ok(time("15255")));
+    assert_that!(&evaluate_input("0:10:00 - 0:25:30.5"))
+        .is_equal_to(Ok(time("-930_1/2")));
+    assert_that!(&evaluate_input("0:00:10 * 1/3"))
+        .is_equal_to(Ok(time("3_1/3")));
+    assert_that!(&evaluate_input("1:30:00 / 0:45:00"))
+        .is_equal_to(Ok(Value::Number(Fraction::from(2))));
+    assert_that!(&evaluate_input("1:00:00 + 90 min"))
+        .is_equal_to(Ok(time("9000")));
+    assert_that!(&evaluate_input("0:59:59.9 < 1:00:00"))
+        .is_equal_to(Ok(Value::Boolean(true)));
+    assert_that!(&evaluate_input("1:30:00 to min"))
+        .is_equal_to(Ok(quantity("90", "min")));
+}
+
+#[test]
+fn compute_time_with_frames() {
+    let mut variables = HashMap::new();
+    let statement = expression::parse("0:00:01;15 + 0:00:00;15").unwrap();
+
+    assert_that!(&evaluate(&statement.expression, &variables))
+        .is_equal_to(Ok(time("2")));
+
+    variables.insert("fps".to_string(), Value::Number(Fraction::new(30000, 1001).unwrap()));
+    assert_that!(&evaluate(&statement.expression, &variables))
+        .is_equal_to(Ok(time("2_1/1000")));
+}
+
+#[test]
+fn compute_time_with_invalid_operands() {
+    assert_that!(&evaluate_input("1:00:00 + 2"))
+        .is_equal_to(Err(Error::new("Expected a time!", 0, 11)));
+    assert_that!(&evaluate_input("1:00:00 + 2 in"))
+        .is_equal_to(Err(Error::new("Units don't match!", 0, 14)));
+    assert_that!(&evaluate_input("0:00:00;30 * 2"))
+        .is_equal_to(Err(Error::new("Too many frames for the frame rate!", 0, 10)));
+}
+
+#[test]
+fn compute_note_durations() {
+    assert_that!(&evaluate_input("1/4. + 1/8"))
+        .is_equal_to(Ok(Value::Number(Fraction::new(1, 2).unwrap())));
+    assert_that!(&evaluate_input("tuplet(3:2, 1/8) * 3"))
+        .is_equal_to(Ok(Value::Number(Fraction::new(1, 4).unwrap())));
+    assert_that!(&evaluate_input("tuplet(3, 1/8)"))
+        .is_equal_to(Err(Error::new("Expected a ratio!", 7, 8)));
+}
+
+#[test]
+fn check_bars() {
+    let bar = |input: &str| match evaluate_input(input) {
+        Ok(Value::Bar(bar)) => (bar.signature.to_string(), bar.fill(), bar.difference().unwrap()),
+        result => panic!("Expected a bar, got {:?}", result)
+    };
+
+    assert_that!(&bar("bar(4/4, 1/4, 1/8, 1/8, 1/2)"))
+        .is_equal_to(&("4/4".to_string(), Ordering::Equal, Fraction::from(0)));
+    assert_that!(&bar("bar(7/8, [1/4, 1/4], 1/2.)"))
+        .is_equal_to(&("7/8".to_string(), Ordering::Greater, Fraction::new(3, 8).unwrap()));
+    assert_that!(&bar("bar(3/4, tuplet(3:2, 1/4))"))
+        .is_equal_to(&("3/4".to_string(), Ordering::Less, Fraction::new(7, 12).unwrap()));
+}
+
+#[test]
+fn compute_dice_probabilities() {
+    assert_that!(&evaluate_input("P(2d6 = 7)"))
+        .is_equal_to(Ok(Value::Event(Fraction::new(1, 6).unwrap())));
+    assert_that!(&evaluate_input("P(3d6 >= 15)"))
+        .is_equal_to(Ok(Value::Event(Fraction::new(5, 54).unwrap())));
+    assert_that!(&evaluate_input("P(1d20 = 20 or 1d20 = 1)"))
+        .is_equal_to(Ok(Value::Event(Fraction::new(39, 400).unwrap())));
+    assert_that!(&evaluate_input("P(not 1d6 = 1 and (1d4 + 1d4) > 6)"))
+        .is_equal_to(Ok(Value::Event(Fraction::new(5, 32).unwrap())));
+    assert_that!(&evaluate_input("E(1d8 + 2)"))
+        .is_equal_to(Ok(Value::Number(Fraction::new(13, 2).unwrap())));
+}
+
+#[test]
+fn compute_dice_with_invalid_operands() {
+    assert_that!(&evaluate_input("1d6 + 1/2"))
+        .is_equal_to(Err(Error::new("Expected a whole number!", 0, 9)));
+    assert_that!(&evaluate_input("P(1d6)"))
+        .is_equal_to(Err(Error::new("Expected an event!", 2, 5)));
+    assert_that!(&evaluate_input("0d6 + 1"))
+        .is_equal_to(Err(Error::new("Expected 1 to 100 dice with 1 to 100 sides!", 0, 3)));
+}
+
+#[test]
+fn stack_tolerances() {
+    let interval = |lower: &str, upper: &str| Value::Interval(Interval::new(fraction(lower), fraction(upper)));
+
+    assert_that!(&evaluate_input("2_1/2 ± 1/64 + 3/4 +/- 1/32"))
+        .is_equal_to(Ok(interval("3_13/64", "3_19/64")));
+    assert_that!(&evaluate_input("(2 ± 1/2) * (-1 ± 1/4)"))
+        .is_equal_to(Ok(interval("-3_1/8", "-1_1/8")));
+    assert_that!(&evaluate_input("(1 ± 1/2) / 2"))
+        .is_equal_to(Ok(interval("1/4", "3/4")));
+    assert_that!(&evaluate_input("1 / (1 ± 1)"))
+        .is_equal_to(Err(Error::new("Division by an interval containing zero!", 5, 11)));
+    assert_that!(&evaluate_input("3 ± -1/8"))
+        .is_equal_to(Err(Error::new("Expected a non-negative tolerance!", 5, 9)));
+}
+
+#[test]
+fn compute_with_complex_numbers() {
+    let complex = |real: &str, imaginary: &str| Value::Complex(Complex::new(fraction(real), fraction(imaginary)));
+
+    assert_that!(&evaluate_input("1/2 + 3/4i"))
+        .is_equal_to(Ok(complex("1/2", "3/4")));
+    assert_that!(&evaluate_input("(1/2 + 3/4i) * (2 - 1i)"))
+        .is_equal_to(Ok(complex("1_3/4", "1")));
+    assert_that!(&evaluate_input("1 / (1 + 1i)"))
+        .is_equal_to(Ok(complex("1/2", "-1/2")));
+    assert_that!(&evaluate_input("2i * 2i"))
+        .is_equal_to(Ok(Value::Number(Fraction::from(-4))));
+    assert_that!(&evaluate_input("norm(conj(3 + 4i))"))
+        .is_equal_to(Ok(Value::Number(Fraction::from(25))));
+    assert_that!(&evaluate_input("1i < 2"))
+        .is_equal_to(Err(Error::new("Expected a number!", 0, 2)));
+    assert_that!(&evaluate_input("1 / (0i)"))
+        .is_equal_to(Err(Error::new("Division by zero!", 5, 7)));
+}
+
+#[test]
+fn compute_with_percentages() {
+    let percent = |text: &str| Value::Percent(fraction(text));
+
+    assert_that!(&evaluate_input("15% of 2_1/2"))
+        .is_equal_to(Ok(Value::Number(Fraction::new(3, 8).unwrap())));
+    assert_that!(&evaluate_input("40 + 10%"))
+        .is_equal_to(Ok(Value::Number(Fraction::from(44))));
+    assert_that!(&evaluate_input("2_1/2 - 20%"))
+        .is_equal_to(Ok(Value::Number(Fraction::from(2))));
+    assert_that!(&evaluate_input("10% + 2_1/2%"))
+        .is_equal_to(Ok(percent("1/8")));
+    assert_that!(&evaluate_input("3/8 as %"))
+        .is_equal_to(Ok(percent("3/8")));
+    assert_that!(&evaluate_input("pct_change(3/4, 7/8) as %"))
+        .is_equal_to(Ok(percent("1/6")));
+    assert_that!(&evaluate_input("10% * 30"))
+        .is_equal_to(Ok(Value::Number(Fraction::from(3))));
+    assert_that!(&evaluate_input("[1, 2] as %"))
+        .is_equal_to(Err(Error::new("Expected a number!", 0, 6)));
+}
+
+#[test]
+fn compute_percentages_on_overflow() {
+    assert_that!(&evaluate_input("2000000000 as %"))
+        .is_equal_to(Err(Error::new("Integer overflow!", 0, 15)));
+    assert_that!(&evaluate_input("pct_change(-2147483647, 2147483647)"))
+        .is_equal_to(Err(Error::new("Integer overflow!", 0, 35)));
+    assert_that!(&evaluate_input("2000000000 + 10%"))
+        .is_equal_to(Err(Error::new("Integer overflow!", 0, 16)));
+    assert_that!(&evaluate_input("10% + 2147483647%"))
+        .is_equal_to(Err(Error::new("Integer overflow!", 0, 17)));
+}
+
+#[test]
+fn compute_with_money() {
+    let money = |amount: &str| Value::Money(fraction(amount));
+
+    assert_that!(&evaluate_input("$129.99 * 2/3"))
+        .is_equal_to(Ok(money("86_33/50")));
+    assert_that!(&evaluate_input("($100 / 3) * 3"))
+        .is_equal_to(Ok(money("100")));
+    assert_that!(&evaluate_input("$0.125 rounded half_up"))
+        .is_equal_to(Ok(money("13/100")));
+    assert_that!(&evaluate_input("$0.125 rounded half_even"))
+        .is_equal_to(Ok(money("3/25")));
+    assert_that!(&evaluate_input("$40 + 10%"))
+        .is_equal_to(Ok(money("44")));
+    assert_that!(&evaluate_input("$10 / $4"))
+        .is_equal_to(Ok(Value::Number(Fraction::new(5, 2).unwrap())));
+    assert_that!(&evaluate_input("$5 + 1"))
+        .is_equal_to(Err(Error::new("Expected an amount of money!", 0, 6)));
+    assert_that!(&evaluate_input("1/2 rounded truncate"))
+        .is_equal_to(Err(Error::new("Expected an amount of money!", 0, 3)));
+}
+
+#[test]
+fn compute_money_on_overflow() {
+    assert_that!(&evaluate_input("$2147483647 * 100"))
+        .is_equal_to(Err(Error::new("Integer overflow!", 0, 17)));
+    assert_that!(&evaluate_input("100 * $2147483647"))
+        .is_equal_to(Err(Error::new("Integer overflow!", 0, 17)));
+    assert_that!(&evaluate_input("$2000000000 + $2000000000"))
+        .is_equal_to(Err(Error::new("Integer overflow!", 0, 25)));
+    assert_that!(&evaluate_input("$2000000000 + 10%"))
+        .is_equal_to(Err(Error::new("Integer overflow!", 0, 17)));
+}
@@ -0,0 +1,419 @@
+use std::collections::HashMap;
+use std::cmp::Ordering;
+use crate::math;
+use crate::mixed_number;
+use crate::continued_fraction;
+use crate::ratio;
+use crate::linear;
+use crate::quantity;
+use crate::music;
+use crate::money::Rounding;
+use crate::fraction::Fraction;
+use crate::expression::{Event, Expression, Operator};
+use crate::evaluator;
+use crate::value::{percentage, Value};
+use crate::error::{Error, Span};
+
+/// Explains how an expression gets computed as a list of plain-text steps.
+/// Operands are explained before the operation using them, and the steps follow the same arithmetic
+/// `Fraction` uses, so the working always matches the result.
+pub fn explain(expression: &Expression, variables: &HashMap<String, Value>) -> Result<Vec<String>, Error> {
+    let mut steps = Vec::new();
+    explain_expression(expression, variables, &mut steps)?;
+
+    Ok(steps)
+}
+
+fn explain_expression(expression: &Expression, variables: &HashMap<String, Value>, steps: &mut Vec<String>) -> Result<Value, Error> {
+    match expression {
+        Expression::Operation { operator, left, right, .. } => {
+            let left_value = explain_expression(left, variables, steps)?;
+            let right_value = explain_expression(right, variables, steps)?;
+            let value = evaluator::apply(*operator, (left, left_value.clone()), (right, right_value.clone()))?;
+
+            match (left_value.as_number(), right_value.as_number(), &value) {
+                // Changing a number by a percentage takes that percentage of it first, e.g. `40 + 10%` adds `4`
+                (Some(x), Some(y), Value::Number(result)) if evaluator::changes_by_percent(*operator, &left_value, &right_value) => {
+                    steps.extend(conversion_step(left, &x));
+                    steps.extend(conversion_step(right, &y));
+                    let span = expression.span();
+                    let change = x.checked_multiply(&y).map_err(|e| Error::new(e, span.start, span.end))?;
+                    steps.push(format!("Take {} of {}: {} * {} = {}", right_value, x, x, y, change));
+                    steps.push(match operator {
+                        Operator::Add => format!("Add it to {}: {} + {} = {}", x, x, change, result),
+                        _ => format!("Substract it from {}: {} - {} = {}", x, x, change, result)
+                    });
+                },
+                (Some(x), Some(y), Value::Number(result)) => {
+                    steps.extend(conversion_step(left, &x));
+                    steps.extend(conversion_step(right, &y));
+                    steps.extend(arithmetic_steps(*operator, &x, &y, result));
+                },
+                (Some(x), Some(y), Value::Boolean(result)) => {
+                    steps.extend(conversion_step(left, &x));
+                    steps.extend(conversion_step(right, &y));
+                    steps.extend(comparison_steps(*operator, &x, &y, *result));
+                },
+                (_, _, value) => steps.push(format!("{} is {}", expression, value))
+            }
+            Ok(value)
+        },
+        Expression::Call { arguments, .. } => {
+            for argument in arguments {
+                explain_expression(argument, variables, steps)?;
+            }
+            let value = evaluator::evaluate(expression, variables)?;
+            steps.push(format!("{} = {}", expression, value));
+            Ok(value)
+        },
+        Expression::Ratio { terms, .. } => {
+            for term in terms {
+                explain_expression(term, variables, steps)?;
+            }
+            let value = evaluator::evaluate(expression, variables)?;
+            if let Value::Ratio(simplified) = &value {
+                let values = terms.iter()
+                    .map(|term| evaluator::evaluate(term, variables).map(|value| value.as_number().unwrap_or_default()))
+                    .collect::<Result<Vec<Fraction>, Error>>()?;
+                steps.extend(ratio_steps(expression, &values, simplified));
+            }
+            Ok(value)
+        },
+        Expression::Solve { equations, .. } => {
+            let value = evaluator::evaluate(expression, variables)?;
+            steps.extend(linear::steps(equations));
+            Ok(value)
+        },
+        Expression::Proportion { left, right, .. } => {
+            let value = evaluator::evaluate(expression, variables)?;
+            steps.extend(proportion_steps(left, right, variables, &value)?);
+            Ok(value)
+        },
+        Expression::Conversion { expression: converted, unit, .. } => {
+            let from = explain_expression(converted, variables, steps)?;
+            let value = evaluator::evaluate(expression, variables)?;
+            if let (Value::Quantity(from), Value::Quantity(to)) = (&from, &value) {
+                // The factor alone may not fit in a fraction even if the converted amount does, e.g. from litres to teaspoons
+                if let Ok(factor) = quantity::factor(from.unit, unit) {
+                    steps.push(format!(
+                        "Multiply by {} to convert {} to {}: {} * {} = {}",
+                        factor, from.unit.name, unit.name, from.amount, factor, to.amount
+                    ));
+                }
+            }
+            Ok(value)
+        },
+        Expression::Bar { durations, .. } => {
+            let value = evaluator::evaluate(expression, variables)?;
+            if let Value::Bar(bar) = &value {
+                let notes: Vec<String> = durations.iter().map(|duration| duration.to_string()).collect();
+                steps.push(format!("Add up the notes: {} = {}", notes.join(" + "), bar.total));
+                let difference = bar.difference().unwrap_or_default();
+                let fill = match bar.fill() {
+                    Ordering::Less => format!("underfull by {}", difference),
+                    Ordering::Equal => "full".to_string(),
+                    Ordering::Greater => format!("overfull by {}", difference)
+                };
+                steps.push(format!("A {} bar lasts {}, so the bar is {}", bar.signature, bar.signature.length(), fill));
+            }
+            Ok(value)
+        },
+        Expression::Time { text, .. } => {
+            let value = evaluator::evaluate(expression, variables)?;
+            if let Value::Time(seconds) = &value {
+                steps.push(format!("Write {} in seconds: {}", text, seconds));
+            }
+            Ok(value)
+        },
+        Expression::AsPercent { expression: converted, span } => {
+            let from = explain_expression(converted, variables, steps)?;
+            let value = evaluator::evaluate(expression, variables)?;
+            if let Some(x) = from.as_number() {
+                let percent = percentage(&x).map_err(|e| Error::new(e, span.start, span.end))?;
+                steps.push(format!(
+                    "Multiply by 100 to write {} as a percentage: {} * 100 = {}, so {} = {}",
+                    x, x, percent, x, value
+                ));
+            }
+            Ok(value)
+        },
+        Expression::Rounded { expression: rounded, rounding, .. } => {
+            let from = explain_expression(rounded, variables, steps)?;
+            let value = evaluator::evaluate(expression, variables)?;
+            if let Value::Money(amount) = &from {
+                let rule = match rounding {
+                    Rounding::HalfUp => "rounding halves of a cent up",
+                    Rounding::HalfEven => "rounding halves of a cent to the even cent",
+                    Rounding::Truncate => "dropping fractions of a cent"
+                };
+                steps.push(format!("Round {} to whole cents, {}: {}", amount, rule, value));
+            }
+            Ok(value)
+        },
+        Expression::Probability { event, span } => {
+            let value = evaluator::evaluate(expression, variables)?;
+            event_steps(event, variables, *span, steps)?;
+            Ok(value)
+        },
+        _ => evaluator::evaluate(expression, variables)
+    }
+}
+
+/// Outcomes are counted over every roll, then independent events are combined by multiplying their probabilities,
+/// taking complements for `not` & `or`
+fn event_steps(event: &Event, variables: &HashMap<String, Value>, span: Span, steps: &mut Vec<String>) -> Result<Fraction, Error> {
+    let probability = |event: &Event| evaluator::evaluate(&Expression::Probability { event: event.clone(), span }, variables)
+        .map(|value| value.as_number().unwrap_or_default());
+
+    match event {
+        Event::Outcome(_) => {
+            let p = probability(event)?;
+            steps.push(format!("P({}) = {}", event, p));
+            Ok(p)
+        },
+        Event::Not(negated) => {
+            let p = event_steps(negated, variables, span, steps)?;
+            let q = probability(event)?;
+            steps.push(format!("Take the complement: 1 - {} = {}", p, q));
+            Ok(q)
+        },
+        Event::All(events) | Event::Any(events) => {
+            let ps = events.iter()
+                .map(|event| event_steps(event, variables, span, steps))
+                .collect::<Result<Vec<Fraction>, Error>>()?;
+            let q = probability(event)?;
+            if let Event::All(_) = event {
+                let ps: Vec<String> = ps.iter().map(|p| p.to_string()).collect();
+                steps.push(format!("Multiply the probabilities of the independent events: {} = {}", ps.join(" * "), q));
+            } else {
+                let ps: Vec<String> = ps.iter().map(|p| format!("(1 - {})", p)).collect();
+                steps.push(format!("Take the complement of none of the events happening: 1 - {} = {}", ps.join(" * "), q));
+            }
+            Ok(q)
+        }
+    }
+}
+
+/// Ratios are scaled to whole terms by the LCM of their denominators, then divided by the GCD of those terms
+fn ratio_steps(ratio: &Expression, values: &[Fraction], simplified: &[i32]) -> Vec<String> {
+    let scale = math::lcd(values).unwrap_or(1);
+    let scaled: Vec<i32> = values.iter().map(|value| value.numerator * (scale / value.denominator)).collect();
+    let gcd = math::gcd_of(&scaled).unwrap_or(1);
+    let mut steps = Vec::new();
+
+    if scale > 1 {
+        steps.push(format!(
+            "Multiply every term by {} to clear the fractions: {}", scale, ratio::format_terms(&scaled)
+        ));
+    }
+    if gcd > 1 {
+        steps.push(format!(
+            "Divide every term by their GCD {}: {}", gcd, ratio::format_terms(simplified)
+        ));
+    }
+    if steps.is_empty() {
+        steps.push(format!("{} is already in lowest terms", ratio));
+    }
+    steps
+}
+
+/// Proportions are cross-multiplied, then the unknown is isolated by dividing both sides by the term multiplying it
+fn proportion_steps(left: &Expression, right: &Expression, variables: &HashMap<String, Value>, value: &Value) -> Result<Vec<String>, Error> {
+    let mut terms: Vec<(String, Option<Fraction>)> = Vec::new();
+    for side in [left, right] {
+        if let Expression::Ratio { terms: side_terms, .. } = side {
+            for term in side_terms {
+                terms.push(match term {
+                    Expression::Variable { name, .. } if !variables.contains_key(name) => (name.clone(), None),
+                    term => {
+                        let number = evaluator::evaluate(term, variables)?.as_number().unwrap_or_default();
+                        (number.to_string(), Some(number))
+                    }
+                });
+            }
+        }
+    }
+    let texts: Vec<&str> = terms.iter().map(|(text, _)| text.as_str()).collect();
+
+    let steps = match value {
+        Value::Solution(solution) => {
+            let (variable, value) = &solution[0];
+            // The unknown times its opposite term equals the product of the other two terms
+            let unknown = terms.iter().position(|(_, number)| number.is_none()).unwrap_or_default();
+            let (opposite, others) = match unknown {
+                0 => (3, (1, 2)),
+                1 => (2, (0, 3)),
+                2 => (1, (0, 3)),
+                _ => (0, (1, 2))
+            };
+            let product = terms[others.0].1.unwrap_or_default() * terms[others.1].1.unwrap_or_default();
+            vec![
+                format!("Cross-multiply the proportion: {} * {} = {} * {}", texts[0], texts[3], texts[1], texts[2]),
+                format!(
+                    "Divide both sides by {}: {} = {} / {} = {}",
+                    texts[opposite], variable, improper(&product), texts[opposite], improper(value)
+                ),
+                conversion_back_step(value)
+            ]
+        },
+        Value::Boolean(result) => {
+            let numbers: Vec<Fraction> = terms.iter().map(|(_, number)| number.unwrap_or_default()).collect();
+            let (left_product, right_product) = (numbers[0] * numbers[3], numbers[1] * numbers[2]);
+            vec![
+                format!(
+                    "Cross-multiply the proportion: {} * {} = {} and {} * {} = {}",
+                    texts[0], texts[3], improper(&left_product), texts[1], texts[2], improper(&right_product)
+                ),
+                format!("{} = {} is {}", improper(&left_product), improper(&right_product), result)
+            ]
+        },
+        _ => Vec::new()
+    };
+    Ok(steps)
+}
+
+fn conversion_step(operand: &Expression, value: &Fraction) -> Option<String> {
+    match operand {
+        Expression::Number { text, .. } if text.starts_with('[') => {
+            let terms = continued_fraction::parse_terms(text).ok()?;
+            let convergents: Vec<String> = continued_fraction::convergents_of(&terms).ok()?
+                .iter()
+                .map(improper)
+                .collect();
+            Some(format!(
+                "Collapse {} into a fraction through its convergents: {}", text, convergents.join(", ")
+            ))
+        },
+        Expression::Number { text, .. } if text.ends_with('.') => {
+            let (note, dots) = music::split_dots(text);
+            let factor = music::dotted(&Fraction::from(1), dots).ok()?;
+            Some(format!("Lengthen {} by its dots: {} * {} = {}", text, note, improper(&factor), improper(value)))
+        },
+        Expression::Number { text, .. } => {
+            if let Some((whole, numerator, denominator)) = mixed_number::split_mixed_number(text) {
+                let sign = if whole < 0 { "-" } else { "" };
+                let whole = math::abs(whole);
+                let improper_numerator = whole * denominator + numerator;
+                let mut step = format!(
+                    "Convert {} to an improper fraction: {}({} * {} + {})/{} = {}{}/{}",
+                    text, sign, whole, denominator, numerator, denominator, sign, improper_numerator, denominator
+                );
+                if value.denominator != denominator {
+                    step.push_str(&format!(" = {}", improper(value)));
+                }
+                Some(step)
+            } else if mixed_number::is_whole_number(text) {
+                Some(format!("Write {} as a fraction: {}", text, improper(value)))
+            } else {
+                None
+            }
+        },
+        Expression::Variable { name, .. } => Some(format!("Write {} as a fraction: {}", name, improper(value))),
+        Expression::Percent { text, .. } => Some(format!(
+            "Divide {} by 100 to write {} as a fraction: {}", &text[..text.len() - 1], text, improper(value)
+        )),
+        _ => None
+    }
+}
+
+fn arithmetic_steps(operator: Operator, left: &Fraction, right: &Fraction, result: &Fraction) -> Vec<String> {
+    let mut steps = Vec::new();
+
+    let (numerator, denominator) = match operator {
+        Operator::Add | Operator::Substract => {
+            let denominator = i64::from(left.denominator) * i64::from(right.denominator);
+            steps.push(format!(
+                "Find a common denominator: {} * {} = {}",
+                left.denominator, right.denominator, denominator
+            ));
+
+            let left_product = i64::from(left.numerator) * i64::from(right.denominator);
+            let right_product = i64::from(right.numerator) * i64::from(left.denominator);
+            let numerator = if operator == Operator::Add {
+                left_product + right_product
+            } else {
+                left_product - right_product
+            };
+            steps.push(format!(
+                "Cross-multiply the numerators: {} * {} {} {} * {} = {}, giving {}/{}",
+                left.numerator, right.denominator, operator.symbol(), right.numerator, left.denominator,
+                numerator, numerator, denominator
+            ));
+            (numerator, denominator)
+        },
+        Operator::Multiply | Operator::Of => {
+            let numerator = i64::from(left.numerator) * i64::from(right.numerator);
+            let denominator = i64::from(left.denominator) * i64::from(right.denominator);
+            steps.push(format!(
+                "Multiply the numerators and the denominators: ({} * {})/({} * {}) = {}/{}",
+                left.numerator, right.numerator, left.denominator, right.denominator, numerator, denominator
+            ));
+            (numerator, denominator)
+        },
+        _ => {
+            let numerator = i64::from(left.numerator) * i64::from(right.denominator);
+            let denominator = i64::from(left.denominator) * i64::from(right.numerator);
+            steps.push(format!(
+                "Invert the divisor and cross-multiply: ({} * {})/({} * {}) = {}/{}",
+                left.numerator, right.denominator, left.denominator, right.numerator, numerator, denominator
+            ));
+            (numerator, denominator)
+        }
+    };
+
+    steps.push(simplification_step(numerator, denominator, result));
+    steps.push(conversion_back_step(result));
+    steps
+}
+
+/// Denominators are always positive, so cross-multiplying keeps the order of the fractions
+fn comparison_steps(operator: Operator, left: &Fraction, right: &Fraction, result: bool) -> Vec<String> {
+    let left_product = i64::from(left.numerator) * i64::from(right.denominator);
+    let right_product = i64::from(right.numerator) * i64::from(left.denominator);
+
+    vec![
+        format!(
+            "Cross-multiply to compare: {} * {} = {} and {} * {} = {}",
+            left.numerator, right.denominator, left_product, right.numerator, left.denominator, right_product
+        ),
+        format!("{} {} {} is {}", left_product, operator.symbol(), right_product, result)
+    ]
+}
+
+fn simplification_step(numerator: i64, denominator: i64, result: &Fraction) -> String {
+    let gcd = math::wide_gcd(i128::from(numerator), i128::from(denominator));
+
+    if gcd == 1 && denominator > 0 {
+        format!("{}/{} is already in lowest terms", numerator, denominator)
+    } else if gcd == 1 {
+        format!("Move the sign to the numerator: {}/{} = {}", numerator, denominator, improper(result))
+    } else {
+        format!(
+            "Simplify by the GCD: gcd({}, {}) = {}, so {}/{} = {}",
+            numerator.abs(), denominator.abs(), gcd, numerator, denominator, improper(result)
+        )
+    }
+}
+
+fn conversion_back_step(result: &Fraction) -> String {
+    if result.is_whole() {
+        format!("{} is the whole number {}", improper(result), result)
+    } else if result.is_proper() {
+        format!("{} is a proper fraction, so it stays as it is", result)
+    } else {
+        let numerator = math::abs(result.numerator);
+        format!(
+            "Convert back to a mixed number: {} / {} = {} remainder {}, so {} = {}",
+            numerator, result.denominator, numerator / result.denominator, numerator % result.denominator,
+            improper(result), result
+        )
+    }
+}
+
+/// Formats a fraction as `numerator/denominator` without turning it into a mixed number
+fn improper(fraction: &Fraction) -> String {
+    format!("{}/{}", fraction.numerator, fraction.denominator)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,212 @@
+use spectral::prelude::*;
+use super::*;
+use crate::expression;
+
+// This function will help test explanations in tests below
+fn test_explain_expression(input: &str, expected_steps: &[&str]) {
+    let statement = expression::parse(input).unwrap();
+
+    let actual_steps = explain(&statement.expression, &HashMap::new()).unwrap();
+
+    assert_that!(&actual_steps)
+        .is_equal_to(expected_steps.iter().map(|step| step.to_string()).collect::<Vec<String>>());
+}
+
+#[test]
+fn explain_addition_of_mixed_numbers() {
+    test_explain_expression("2_3/8 + 9/8", &[
+        "Convert 2_3/8 to an improper fraction: (2 * 8 + 3)/8 = 19/8",
+        "Find a common denominator: 8 * 8 = 64",
+        "Cross-multiply the numerators: 19 * 8 + 9 * 8 = 224, giving 224/64",
+        "Simplify by the GCD: gcd(224, 64) = 32, so 224/64 = 7/2",
+        "Convert back to a mixed number: 7 / 2 = 3 remainder 1, so 7/2 = 3_1/2"
+    ]);
+}
+
+#[test]
+fn explain_substraction_with_negative_result() {
+    test_explain_expression("1_3/7 - 5_2/4", &[
+        "Convert 1_3/7 to an improper fraction: (1 * 7 + 3)/7 = 10/7",
+        "Convert 5_2/4 to an improper fraction: (5 * 4 + 2)/4 = 22/4 = 11/2",
+        "Find a common denominator: 7 * 2 = 14",
+        "Cross-multiply the numerators: 10 * 2 - 11 * 7 = -57, giving -57/14",
+        "-57/14 is already in lowest terms",
+        "Convert back to a mixed number: 57 / 14 = 4 remainder 1, so -57/14 = -4_1/14"
+    ]);
+}
+
+#[test]
+fn explain_multiplication() {
+    test_explain_expression("1/2 * 3_3/4", &[
+        "Convert 3_3/4 to an improper fraction: (3 * 4 + 3)/4 = 15/4",
+        "Multiply the numerators and the denominators: (1 * 15)/(2 * 4) = 15/8",
+        "15/8 is already in lowest terms",
+        "Convert back to a mixed number: 15 / 8 = 1 remainder 7, so 15/8 = 1_7/8"
+    ]);
+}
+
+#[test]
+fn explain_division() {
+    test_explain_expression("7 / -3_1/2", &[
+        "Write 7 as a fraction: 7/1",
+        "Convert -3_1/2 to an improper fraction: -(3 * 2 + 1)/2 = -7/2",
+        "Invert the divisor and cross-multiply: (7 * 2)/(1 * -7) = 14/-7",
+        "Simplify by the GCD: gcd(14, 7) = 7, so 14/-7 = -2/1",
+        "-2/1 is the whole number -2"
+    ]);
+}
+
+#[test]
+fn explain_multiplication_with_products_beyond_integers() {
+    test_explain_expression("70000/70001 * 70001/70000", &[
+        "Multiply the numerators and the denominators: (70000 * 70001)/(70001 * 70000) = 4900070000/4900070000",
+        "Simplify by the GCD: gcd(4900070000, 4900070000) = 4900070000, so 4900070000/4900070000 = 1/1",
+        "1/1 is the whole number 1"
+    ]);
+}
+
+#[test]
+fn explain_comparison() {
+    test_explain_expression("5_3/8 > 43/8", &[
+        "Convert 5_3/8 to an improper fraction: (5 * 8 + 3)/8 = 43/8",
+        "Cross-multiply to compare: 43 * 8 = 344 and 43 * 8 = 344",
+        "344 > 344 is false"
+    ]);
+}
+
+#[test]
+fn explain_nested_expression() {
+    test_explain_expression("max(1/2, 2/3) * 3", &[
+        "max(1/2, 2/3) = 2/3",
+        "Write 3 as a fraction: 3/1",
+        "Multiply the numerators and the denominators: (2 * 3)/(3 * 1) = 6/3",
+        "Simplify by the GCD: gcd(6, 3) = 3, so 6/3 = 2/1",
+        "2/1 is the whole number 2"
+    ]);
+}
+
+#[test]
+fn explain_continued_fraction_literal() {
+    test_explain_expression("[4; 2, 6, 7] - 4", &[
+        "Collapse [4; 2, 6, 7] into a fraction through its convergents: 4/1, 9/2, 58/13, 415/93",
+        "Write 4 as a fraction: 4/1",
+        "Find a common denominator: 93 * 1 = 93",
+        "Cross-multiply the numerators: 415 * 1 - 4 * 93 = 43, giving 43/93",
+        "43/93 is already in lowest terms",
+        "43/93 is a proper fraction, so it stays as it is"
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "Division by zero!")]
+fn explain_division_by_zero() {
+    let statement = expression::parse("1/2 / 0").unwrap();
+
+    explain(&statement.expression, &HashMap::new()).unwrap();
+}
+
+#[test]
+fn explain_ratio() {
+    test_explain_expression("1_1/2 : 3", &[
+        "Multiply every term by 2 to clear the fractions: 3 : 6",
+        "Divide every term by their GCD 3: 1 : 2"
+    ]);
+}
+
+#[test]
+fn explain_solve() {
+    test_explain_expression("solve 3x - 1 = x + 1/2", &[
+        "Collect the unknowns on the left: 2 x = 1_1/2",
+        "Eliminate x: x = 3/4"
+    ]);
+}
+
+#[test]
+fn explain_proportion() {
+    test_explain_expression("3/4 : 6 = x : 10", &[
+        "Cross-multiply the proportion: 3/4 * 10 = 6 * x",
+        "Divide both sides by 6: x = 15/2 / 6 = 5/4",
+        "Convert back to a mixed number: 5 / 4 = 1 remainder 1, so 5/4 = 1_1/4"
+    ]);
+}
+
+#[test]
+fn explain_time_addition() {
+    test_explain_expression("1:23:45 + 2:50:30", &[
+        "Write 1:23:45 in seconds: 5025",
+        "Write 2:50:30 in seconds: 10230",
+        "1:23:45 + 2:50:30 is 4:14:15"
+    ]);
+}
+
+#[test]
+fn explain_bar() {
+    test_explain_expression("bar(7/8, 1/4., 1/4)", &[
+        "Add up the notes: 1/4. + 1/4 = 5/8",
+        "A 7/8 bar lasts 7/8, so the bar is underfull by 1/4"
+    ]);
+}
+
+#[test]
+fn explain_dotted_note() {
+    test_explain_expression("1/4. * 2", &[
+        "Lengthen 1/4. by its dots: 1/4 * 3/2 = 3/8",
+        "Write 2 as a fraction: 2/1",
+        "Multiply the numerators and the denominators: (3 * 2)/(8 * 1) = 6/8",
+        "Simplify by the GCD: gcd(6, 8) = 2, so 6/8 = 3/4",
+        "3/4 is a proper fraction, so it stays as it is"
+    ]);
+}
+
+#[test]
+fn explain_conversion() {
+    test_explain_expression("1_1/2 ft to in", &[
+        "Multiply by 12 to convert ft to in: 1_1/2 * 12 = 18"
+    ]);
+}
+
+#[test]
+fn explain_probability() {
+    test_explain_expression("P(not 1d6 = 6 or 1d20 >= 20)", &[
+        "P(1d6 == 6) = 1/6",
+        "Take the complement: 1 - 1/6 = 5/6",
+        "P(1d20 >= 20) = 1/20",
+        "Take the complement of none of the events happening: 1 - (1 - 5/6) * (1 - 1/20) = 101/120"
+    ]);
+}
+
+#[test]
+fn explain_percent_of_number() {
+    test_explain_expression("15% of 2_1/2", &[
+        "Divide 15 by 100 to write 15% as a fraction: 3/20",
+        "Convert 2_1/2 to an improper fraction: (2 * 2 + 1)/2 = 5/2",
+        "Multiply the numerators and the denominators: (3 * 5)/(20 * 2) = 15/40",
+        "Simplify by the GCD: gcd(15, 40) = 5, so 15/40 = 3/8",
+        "3/8 is a proper fraction, so it stays as it is"
+    ]);
+}
+
+#[test]
+fn explain_percent_increase() {
+    test_explain_expression("40 + 10%", &[
+        "Write 40 as a fraction: 40/1",
+        "Divide 10 by 100 to write 10% as a fraction: 1/10",
+        "Take 10% of 40: 40 * 1/10 = 4",
+        "Add it to 40: 40 + 4 = 44"
+    ]);
+}
+
+#[test]
+fn explain_conversion_to_percent() {
+    test_explain_expression("3/8 as %", &[
+        "Multiply by 100 to write 3/8 as a percentage: 3/8 * 100 = 37_1/2, so 3/8 = 37_1/2%"
+    ]);
+}
+
+#[test]
+fn explain_rounding_money() {
+    test_explain_expression("$129.99 * 2/3 rounded half_up", &[
+        "$129.99 * 2/3 is $86.66",
+        "Round 86_33/50 to whole cents, rounding halves of a cent up: $86.66"
+    ]);
+}
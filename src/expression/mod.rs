@@ -0,0 +1,800 @@
+use std::fmt;
+use std::cmp::Ordering;
+use crate::mixed_number;
+use crate::continued_fraction;
+use crate::linear::{Equation, Term};
+use crate::polynomial::Polynomial;
+use crate::quantity::{self, Unit};
+use crate::timecode::{self, Timecode};
+use crate::music::{self, TimeSignature};
+use crate::money::{self, Rounding};
+use crate::fraction::Fraction;
+use crate::error::{Error, Span};
+use crate::tokenizer::{self, Token, TokenKind};
+
+/// Operators an operation can apply to its operands
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Add,
+    Substract,
+    Multiply,
+    Divide,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+    /// Takes a part of a number, e.g. `15% of 2_1/2`, which is multiplying them
+    Of
+}
+
+impl Operator {
+
+    /// Whether a comparison holds for operands ordered like given, e.g. `<=` holds for `Less` & `Equal`.
+    /// Arithmetic operators never hold
+    pub fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Operator::Less => ordering == Ordering::Less,
+            Operator::LessOrEqual => ordering != Ordering::Greater,
+            Operator::Greater => ordering == Ordering::Greater,
+            Operator::GreaterOrEqual => ordering != Ordering::Less,
+            Operator::Equal => ordering == Ordering::Equal,
+            Operator::NotEqual => ordering != Ordering::Equal,
+            _ => false
+        }
+    }
+
+    fn from_symbol(symbol: &str) -> Option<Operator> {
+        match symbol {
+            "+" => Some(Operator::Add),
+            "-" => Some(Operator::Substract),
+            "*" => Some(Operator::Multiply),
+            "/" => Some(Operator::Divide),
+            "<" => Some(Operator::Less),
+            "<=" => Some(Operator::LessOrEqual),
+            ">" => Some(Operator::Greater),
+            ">=" => Some(Operator::GreaterOrEqual),
+            "==" => Some(Operator::Equal),
+            "!=" => Some(Operator::NotEqual),
+            "of" => Some(Operator::Of),
+            _ => None
+        }
+    }
+
+    /// The symbol the operator is typed with
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Substract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+            Operator::Less => "<",
+            Operator::LessOrEqual => "<=",
+            Operator::Greater => ">",
+            Operator::GreaterOrEqual => ">=",
+            Operator::Equal => "==",
+            Operator::NotEqual => "!=",
+            Operator::Of => "of"
+        }
+    }
+}
+
+/// Models the syntax tree of an expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    /// A number literal. The text it was typed with is kept, e.g. to explain how `2_3/8` becomes `19/8`
+    Number { value: Fraction, text: String, span: Span },
+    Variable { name: String, span: Span },
+    Call { function: String, arguments: Vec<Expression>, span: Span },
+    /// A list literal like `[1/2, 3_1/4, 7/8]`
+    List { items: Vec<Expression>, span: Span },
+    Operation { operator: Operator, left: Box<Expression>, right: Box<Expression>, span: Span },
+    /// A ratio like `3 : 4` or `1_1/2 : 2 : 5`
+    Ratio { terms: Vec<Expression>, span: Span },
+    /// A proportion between two ratios of two terms like `3/4 : 6 = x : 10`
+    Proportion { left: Box<Expression>, right: Box<Expression>, span: Span },
+    /// A linear equation, or a system of them, to solve like `solve { x + y = 3/2; x - y = 1/4 }`
+    Solve { equations: Vec<Equation>, span: Span },
+    /// A polynomial literal like `poly(1/2 x^2 - 3/4 x + 1)`
+    Polynomial { value: Polynomial, span: Span },
+    /// A number literal followed by a unit like `1_1/2 ft`
+    Quantity { value: Fraction, text: String, unit: &'static Unit, span: Span },
+    /// The conversion of an expression into a unit like `1_1/2 ft to in`
+    Conversion { expression: Box<Expression>, unit: &'static Unit, span: Span },
+    /// A time literal like `1:23:45` or `0:01:00;12`
+    Time { value: Timecode, text: String, span: Span },
+    /// A bar of a time signature filled with notes like `bar(7/8, 1/4., 1/4, 1/8)`
+    Bar { signature: TimeSignature, durations: Vec<Expression>, span: Span },
+    /// A roll of dice like `2d6`
+    Dice { count: i32, sides: i32, span: Span },
+    /// The probability of an event like `P(2d6 = 7)` or `P(1d20 = 20 or 1d20 = 1)`
+    Probability { event: Event, span: Span },
+    /// A value give or take a tolerance like `2_1/2 ± 1/64`
+    Interval { center: Box<Expression>, tolerance: Box<Expression>, span: Span },
+    /// An imaginary number literal like `3/4i`, where `value` is the number multiplying `i`
+    Imaginary { value: Fraction, text: String, span: Span },
+    /// A percentage literal like `15%`, where `value` is the number it stands for, e.g. `3/20`
+    Percent { value: Fraction, text: String, span: Span },
+    /// The conversion of an expression into a percentage like `3/8 as %`
+    AsPercent { expression: Box<Expression>, span: Span },
+    /// An amount of money like `$129.99`
+    Money { value: Fraction, text: String, span: Span },
+    /// The rounding of an amount of money to whole cents following a rule like `$129.99 * 2/3 rounded half_up`
+    Rounded { expression: Box<Expression>, rounding: Rounding, span: Span }
+}
+
+impl Expression {
+
+    /// Where the expression is located in the parsed input
+    pub fn span(&self) -> Span {
+        match self {
+            Expression::Number { span, .. } => *span,
+            Expression::Variable { span, .. } => *span,
+            Expression::Call { span, .. } => *span,
+            Expression::List { span, .. } => *span,
+            Expression::Operation { span, .. } => *span,
+            Expression::Ratio { span, .. } => *span,
+            Expression::Proportion { span, .. } => *span,
+            Expression::Solve { span, .. } => *span,
+            Expression::Polynomial { span, .. } => *span,
+            Expression::Quantity { span, .. } => *span,
+            Expression::Conversion { span, .. } => *span,
+            Expression::Time { span, .. } => *span,
+            Expression::Bar { span, .. } => *span,
+            Expression::Dice { span, .. } => *span,
+            Expression::Probability { span, .. } => *span,
+            Expression::Interval { span, .. } => *span,
+            Expression::Imaginary { span, .. } => *span,
+            Expression::Percent { span, .. } => *span,
+            Expression::AsPercent { span, .. } => *span,
+            Expression::Money { span, .. } => *span,
+            Expression::Rounded { span, .. } => *span
+        }
+    }
+}
+
+/// Implementation to make `Expression` displayable as it would be typed.
+impl fmt::Display for Expression {
+
+    /// Operations used as operands are wrapped in parentheses, as the grammar requires
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expression::Number { text, .. } => write!(f, "{}", text),
+            Expression::Variable { name, .. } => write!(f, "{}", name),
+            Expression::Call { function, arguments, .. } => {
+                let arguments: Vec<String> = arguments.iter().map(|argument| argument.to_string()).collect();
+                write!(f, "{}({})", function, arguments.join(", "))
+            },
+            Expression::List { items, .. } => {
+                let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+                write!(f, "[{}]", items.join(", "))
+            },
+            Expression::Operation { operator, left, right, .. } => {
+                write!(f, "{} {} {}", Operand(left), operator.symbol(), Operand(right))
+            },
+            Expression::Ratio { terms, .. } => {
+                let terms: Vec<String> = terms.iter().map(|term| term.to_string()).collect();
+                write!(f, "{}", terms.join(" : "))
+            },
+            Expression::Proportion { left, right, .. } => write!(f, "{} = {}", left, right),
+            Expression::Solve { equations, .. } if equations.len() == 1 => write!(f, "solve {}", equations[0]),
+            Expression::Solve { equations, .. } => {
+                let equations: Vec<String> = equations.iter().map(|equation| equation.to_string()).collect();
+                write!(f, "solve {{ {} }}", equations.join("; "))
+            },
+            Expression::Polynomial { value, .. } => write!(f, "poly({})", value),
+            Expression::Quantity { text, unit, .. } => write!(f, "{} {}", text, unit.name),
+            Expression::Conversion { expression, unit, .. } => write!(f, "{} to {}", Operand(expression), unit.name),
+            Expression::Time { text, .. } => write!(f, "{}", text),
+            Expression::Bar { signature, durations, .. } => {
+                let durations: Vec<String> = durations.iter().map(|duration| format!(", {}", duration)).collect();
+                write!(f, "bar({}{})", signature, durations.concat())
+            },
+            Expression::Dice { count, sides, .. } => write!(f, "{}d{}", count, sides),
+            Expression::Probability { event, .. } => write!(f, "P({})", event),
+            Expression::Interval { center, tolerance, .. } => write!(f, "{} ± {}", Operand(center), Operand(tolerance)),
+            Expression::Imaginary { text, .. } => write!(f, "{}", text),
+            Expression::Percent { text, .. } => write!(f, "{}", text),
+            Expression::AsPercent { expression, .. } => write!(f, "{} as %", Operand(expression)),
+            Expression::Money { text, .. } => write!(f, "{}", text),
+            Expression::Rounded { expression, rounding, .. } => write!(f, "{} rounded {}", Operand(expression), rounding.name())
+        }
+    }
+}
+
+/// Models an event whose probability is computed. Events joined together are taken as independent
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// An expression giving the probability of the event, usually a comparison like `2d6 == 7`
+    Outcome(Box<Expression>),
+    Not(Box<Event>),
+    /// Events that must all happen
+    All(Vec<Event>),
+    /// Events of which at least one must happen
+    Any(Vec<Event>)
+}
+
+/// Implementation to make `Event` displayable as it would be typed, e.g. `not 1d6 == 1 and 1d6 == 2`
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Event::Outcome(expression) => write!(f, "{}", expression),
+            Event::Not(event) => write!(f, "not {}", event),
+            Event::All(events) | Event::Any(events) => {
+                let connective = if matches!(self, Event::All(_)) { " and " } else { " or " };
+                let events: Vec<String> = events.iter().map(|event| event.to_string()).collect();
+                write!(f, "{}", events.join(connective))
+            }
+        }
+    }
+}
+
+/// Helper to display an expression used as an operand
+struct Operand<'a>(&'a Expression);
+
+impl<'a> fmt::Display for Operand<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Expression::Operation { .. } => write!(f, "({})", self.0),
+            expression => write!(f, "{}", expression)
+        }
+    }
+}
+
+/// Models a parsed input: an expression, optionally assigned to a variable like in `x = 1/2 + 1/4`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    pub variable: Option<String>,
+    pub expression: Expression
+}
+
+/// Parses an input into a statement. The grammar is:
+///
+/// ```text
+/// statement  := [identifier "="] ratio | ratio "=" ratio | "solve" (equation | "{" equation (";" equation)* [";"] "}")
+/// ratio      := expression (":" expression)*
+/// expression := sum [comparison sum] [("to" | "in") unit | "as" "%" | "rounded" rounding]
+/// sum        := product (("+" | "-") product)*
+/// product    := interval (("*" | "/" | "of") interval)*
+/// comparison := "<" | "<=" | ">" | ">=" | "==" | "!="
+/// interval   := operand [("±" | "+/-") operand]
+/// operand    := number [unit] | number "i" | number "%" | "$" decimal | time | dice | identifier | function "(" ratio ("," ratio)* ")" | "(" expression ")"
+///             | "[" [expression ("," expression)*] "]" | "poly" "(" polynomial ")" | "bar" "(" number ("," expression)* ")"
+///             | "P" "(" event ")"
+/// event      := outcome (("and" | "or") outcome)*
+/// outcome    := "not" outcome | expression ["=" operand]
+/// ```
+///
+/// Functions are the built-in ones registered in `function`, so they can't be used as variable names.
+/// Units are the ones registered in `quantity`, e.g. `in` or `min`, so `2 in in cm` converts inches into centimetres.
+/// Numbers can be dotted notes like `1/4.`, and bars take their time signature as written so `4/4` isn't simplified.
+/// Times are written `h:mm:ss` without spaces, e.g. `1:23:45`, so they aren't confused with ratios like `1 : 23 : 45`.
+/// Dice, imaginary numbers, percentages & money are written without spaces too, e.g. `2d6`, `3/4i`, `15%` or `$129.99`, as `%` isn't an operator.
+/// Amounts of money are rounded to whole cents `half_up`, `half_even` or `truncate`. Within `P`, `=` compares like `==` & events can't mix `and` with `or`.
+///
+/// So operators chain from left to right, `*`, `/` & `of` binding tighter than `+` & `-`, e.g. `1/2 + 1/3 * 2` is `1/2 + (1/3 * 2)`.
+/// Comparisons like `<` or `==` don't chain, they compare two sums. Tolerances bind tighter than any operator, so
+/// `2_1/2 ± 1/64 + 3/4 ± 1/32` adds two intervals.
+/// A lone number or variable isn't a valid statement, there must be something to compute, unless it's assigned like `x = 1/2`.
+/// Literals that get computed are, e.g. `[4; 2, 6, 7]` gives `4_43/93` like `1:23:45` or `3/4i` give their value.
+/// Two ratios joined by `=` make a proportion, whose ratios must have two terms each.
+/// Equations to solve are linear, their grammar is:
+///
+/// ```text
+/// equation := linear "=" linear
+/// linear   := ["+" | "-"] term (("+" | "-") term)*
+/// term     := number | [number ["*"]] identifier
+/// ```
+///
+/// So a coefficient can be written right before its unknown, e.g. `2/3 x` or `2x`.
+/// Polynomials are written the same way, with whole exponents up to 100 & a single variable, `x` if there's none:
+///
+/// ```text
+/// polynomial := ["+" | "-"] monomial (("+" | "-") monomial)*
+/// monomial   := term ["^" number]
+/// ```
+pub fn parse(input: &str) -> Result<Statement, Error> {
+    let tokens = tokenizer::tokenize(input)?;
+    let mut parser = Parser { tokens, position: 0, end: input.len(), depth: 0 };
+
+    let solve = match (parser.tokens.first(), parser.tokens.get(1)) {
+        (Some(keyword), Some(next)) => keyword.kind == TokenKind::Identifier && keyword.text == "solve"
+            && (next.text == "{" || next.kind == TokenKind::Number || next.kind == TokenKind::Identifier),
+        _ => false
+    };
+    if solve {
+        parser.position = 1;
+        let expression = parser.solve()?;
+        return match parser.peek() {
+            Some(token) => Err(Error::new("Unparseable operation!", token.span.start, parser.end)),
+            None => Ok(Statement { variable: None, expression })
+        };
+    }
+
+    let variable = match (parser.tokens.first(), parser.tokens.get(1)) {
+        (Some(name), Some(equals)) if name.kind == TokenKind::Identifier && equals.text == "=" => {
+            parser.position = 2;
+            Some(name.text.clone())
+        },
+        _ => None
+    };
+    let mut expression = parser.ratio()?;
+
+    if variable.is_none() && parser.peek().is_some_and(|token| token.text == "=") {
+        parser.position += 1;
+        let left = two_terms(expression)?;
+        let right = two_terms(parser.ratio()?)?;
+        let span = Span { start: left.span().start, end: right.span().end };
+        expression = Expression::Proportion { left: Box::new(left), right: Box::new(right), span };
+    }
+
+    if let Some(token) = parser.peek() {
+        return Err(Error::new("Unparseable operation!", token.span.start, parser.end));
+    }
+    // Continued fractions & dotted notes are worked out, so they're computations on their own
+    let lone = match &expression {
+        Expression::Number { text, .. } => !text.starts_with('[') && !text.ends_with('.'),
+        Expression::Variable { .. } | Expression::Percent { .. } | Expression::Money { .. } => true,
+        _ => false
+    };
+    if lone && variable.is_none() {
+        let span = expression.span();
+        Err(Error::new("Unparseable operation!", span.start, span.end))
+    } else {
+        Ok(Statement { variable, expression })
+    }
+}
+
+/// Checks one side of a proportion is a ratio of two terms
+fn two_terms(expression: Expression) -> Result<Expression, Error> {
+    match expression {
+        Expression::Ratio { ref terms, .. } if terms.len() == 2 => Ok(expression),
+        expression => {
+            let span = expression.span();
+            Err(Error::new("Expected a ratio of two terms!", span.start, span.end))
+        }
+    }
+}
+
+/// Operands & events nested deeper than this are rejected, so a hostile expression can't exhaust the stack
+const MAX_DEPTH: usize = 64;
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    end: usize,
+    depth: usize
+}
+
+impl Parser {
+
+    /// Parses one more level of nesting with the given rule, failing once the nesting is too deep
+    fn nested<T>(&mut self, rule: fn(&mut Parser) -> Result<T, Error>) -> Result<T, Error> {
+        if self.depth == MAX_DEPTH {
+            let span = self.peek().map_or(Span { start: self.end, end: self.end }, |token| token.span);
+            return Err(Error::new("Too deeply nested!", span.start, span.end));
+        }
+
+        self.depth += 1;
+        let result = rule(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Result<Token, Error> {
+        let token = self.tokens.get(self.position).cloned()
+            .ok_or_else(|| Error::new("Unparseable operation!", self.end, self.end))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, symbol: &str) -> Result<Token, Error> {
+        let token = self.next()?;
+        if token.kind == TokenKind::Symbol && token.text == symbol {
+            Ok(token)
+        } else {
+            Err(Error::new("Unparseable operation!", token.span.start, token.span.end))
+        }
+    }
+
+    fn solve(&mut self) -> Result<Expression, Error> {
+        let mut equations = Vec::new();
+        if self.peek().is_some_and(|next| next.text == "{") {
+            self.position += 1;
+            equations.push(self.equation()?);
+            while self.peek().is_some_and(|next| next.text == ";") {
+                self.position += 1;
+                if self.peek().is_some_and(|next| next.text == "}") {
+                    break;
+                }
+                equations.push(self.equation()?);
+            }
+            self.expect("}")?;
+        } else {
+            equations.push(self.equation()?);
+        }
+
+        let span = Span { start: self.tokens[0].span.start, end: self.tokens[self.position - 1].span.end };
+        if equations.iter().all(|equation| equation.unknowns().is_empty()) {
+            return Err(Error::new("Expected an unknown!", span.start, span.end));
+        }
+        Ok(Expression::Solve { equations, span })
+    }
+
+    fn equation(&mut self) -> Result<Equation, Error> {
+        let left = self.linear()?;
+        self.expect("=")?;
+        let right = self.linear()?;
+
+        Ok(Equation { left, right })
+    }
+
+    fn linear(&mut self) -> Result<Vec<Term>, Error> {
+        let mut terms = Vec::new();
+        let mut negative = false;
+        if let Some(sign) = self.peek().filter(|next| next.text == "+" || next.text == "-") {
+            negative = sign.text == "-";
+            self.position += 1;
+        }
+
+        loop {
+            terms.push(self.term(negative)?);
+            match self.peek() {
+                Some(sign) if sign.text == "+" || sign.text == "-" => {
+                    negative = sign.text == "-";
+                    self.position += 1;
+                },
+                _ => return Ok(terms)
+            }
+        }
+    }
+
+    fn term(&mut self, negative: bool) -> Result<Term, Error> {
+        let token = self.next()?;
+        let (coefficient, unknown) = match token.kind {
+            TokenKind::Number if !token.text.starts_with('[') => {
+                let coefficient = mixed_number::parse_as_fraction(&token.text)
+                    .map_err(|e| Error::new(e, token.span.start, token.span.end))?;
+                if self.peek().is_some_and(|next| next.text == "*") {
+                    self.position += 1;
+                    let unknown = self.next()?;
+                    if unknown.kind != TokenKind::Identifier {
+                        return Err(Error::new("Unparseable operation!", unknown.span.start, unknown.span.end));
+                    }
+                    (coefficient, Some(unknown.text))
+                } else if let Some(unknown) = self.peek().filter(|next| next.kind == TokenKind::Identifier) {
+                    let unknown = unknown.text.clone();
+                    self.position += 1;
+                    (coefficient, Some(unknown))
+                } else {
+                    (coefficient, None)
+                }
+            },
+            TokenKind::Identifier => (Fraction::from(1), Some(token.text)),
+            _ => return Err(Error::new("Unparseable operation!", token.span.start, token.span.end))
+        };
+
+        let coefficient = if negative { -coefficient } else { coefficient };
+        Ok(Term { coefficient, unknown })
+    }
+
+    fn polynomial(&mut self) -> Result<Polynomial, Error> {
+        let mut coefficients = Vec::new();
+        let mut variable: Option<String> = None;
+        let mut negative = false;
+        if let Some(sign) = self.peek().filter(|next| next.text == "+" || next.text == "-") {
+            negative = sign.text == "-";
+            self.position += 1;
+        }
+
+        loop {
+            let start = self.peek().map_or(self.end, |next| next.span.start);
+            let Term { coefficient, unknown } = self.term(negative)?;
+            let mut degree = 0;
+            if let Some(unknown) = unknown {
+                if variable.as_ref().is_some_and(|variable| *variable != unknown) {
+                    let end = self.tokens[self.position - 1].span.end;
+                    return Err(Error::new("Polynomial variables don't match!", start, end));
+                }
+                variable = Some(unknown);
+                degree = 1;
+                if self.peek().is_some_and(|next| next.text == "^") {
+                    self.position += 1;
+                    degree = self.exponent()?;
+                }
+            }
+
+            if coefficients.len() <= degree {
+                coefficients.resize(degree + 1, Fraction::default());
+            }
+            let end = self.tokens[self.position - 1].span.end;
+            coefficients[degree] = coefficients[degree].checked_add(&coefficient)
+                .map_err(|e| Error::new(e, start, end))?;
+            match self.peek() {
+                Some(sign) if sign.text == "+" || sign.text == "-" => {
+                    negative = sign.text == "-";
+                    self.position += 1;
+                },
+                _ => return Ok(Polynomial::new(coefficients, variable.as_deref().unwrap_or("x")))
+            }
+        }
+    }
+
+    fn exponent(&mut self) -> Result<usize, Error> {
+        let token = self.next()?;
+        let exponent = match token.kind {
+            TokenKind::Number => token.text.parse::<usize>().ok().filter(|&exponent| exponent <= 100),
+            _ => None
+        };
+
+        exponent.ok_or_else(|| Error::new("Expected a whole exponent up to 100!", token.span.start, token.span.end))
+    }
+
+    fn ratio(&mut self) -> Result<Expression, Error> {
+        let mut terms = vec![self.expression()?];
+        while self.peek().is_some_and(|next| next.text == ":") {
+            self.position += 1;
+            terms.push(self.expression()?);
+        }
+
+        if terms.len() == 1 {
+            Ok(terms.remove(0))
+        } else {
+            let span = Span { start: terms[0].span().start, end: terms[terms.len() - 1].span().end };
+            Ok(Expression::Ratio { terms, span })
+        }
+    }
+
+    fn expression(&mut self) -> Result<Expression, Error> {
+        let left = self.sum()?;
+        let expression = match self.operator(&[Operator::Less, Operator::LessOrEqual, Operator::Greater,
+            Operator::GreaterOrEqual, Operator::Equal, Operator::NotEqual]) {
+            Some(operator) => {
+                let right = self.sum()?;
+                let span = Span { start: left.span().start, end: right.span().end };
+                Expression::Operation { operator, left: Box::new(left), right: Box::new(right), span }
+            },
+            None => left
+        };
+
+        match self.peek() {
+            Some(keyword) if keyword.kind == TokenKind::Identifier && (keyword.text == "to" || keyword.text == "in") => {
+                let keyword = keyword.clone();
+                self.position += 1;
+                let (unit, unit_span) = self.unit()
+                    .ok_or_else(|| Error::new("Unknown unit!", keyword.span.end, self.peek().map_or(self.end, |next| next.span.end)))?;
+                let span = Span { start: expression.span().start, end: unit_span.end };
+                Ok(Expression::Conversion { expression: Box::new(expression), unit, span })
+            },
+            Some(keyword) if keyword.kind == TokenKind::Identifier && keyword.text == "as" => {
+                self.position += 1;
+                let percent = self.expect("%")?;
+                let span = Span { start: expression.span().start, end: percent.span.end };
+                Ok(Expression::AsPercent { expression: Box::new(expression), span })
+            },
+            Some(keyword) if keyword.kind == TokenKind::Identifier && keyword.text == "rounded" => {
+                let keyword = keyword.clone();
+                self.position += 1;
+                let rule = self.next()
+                    .map_err(|_| Error::new("Unknown rounding!", keyword.span.end, self.end))?;
+                let rounding = rule.text.parse()
+                    .map_err(|e| Error::new(e, rule.span.start, rule.span.end))?;
+                let span = Span { start: expression.span().start, end: rule.span.end };
+                Ok(Expression::Rounded { expression: Box::new(expression), rounding, span })
+            },
+            _ => Ok(expression)
+        }
+    }
+
+    fn sum(&mut self) -> Result<Expression, Error> {
+        let mut expression = self.product()?;
+        while let Some(operator) = self.operator(&[Operator::Add, Operator::Substract]) {
+            let right = self.product()?;
+            let span = Span { start: expression.span().start, end: right.span().end };
+            expression = Expression::Operation { operator, left: Box::new(expression), right: Box::new(right), span };
+        }
+        Ok(expression)
+    }
+
+    fn product(&mut self) -> Result<Expression, Error> {
+        let mut expression = self.interval()?;
+        while let Some(operator) = self.operator(&[Operator::Multiply, Operator::Divide, Operator::Of]) {
+            let right = self.interval()?;
+            let span = Span { start: expression.span().start, end: right.span().end };
+            expression = Expression::Operation { operator, left: Box::new(expression), right: Box::new(right), span };
+        }
+        Ok(expression)
+    }
+
+    /// Takes the next token if it's one of the given operators
+    fn operator(&mut self, operators: &[Operator]) -> Option<Operator> {
+        let token = self.peek().filter(|token| token.kind == TokenKind::Symbol || token.text == "of")?;
+        let operator = Operator::from_symbol(&token.text).filter(|operator| operators.contains(operator))?;
+        self.position += 1;
+        Some(operator)
+    }
+
+    fn interval(&mut self) -> Result<Expression, Error> {
+        let center = self.operand()?;
+        if self.peek().is_none_or(|next| next.kind != TokenKind::Symbol || (next.text != "±" && next.text != "+/-")) {
+            return Ok(center);
+        }
+
+        self.position += 1;
+        let tolerance = self.operand()?;
+        let span = Span { start: center.span().start, end: tolerance.span().end };
+        Ok(Expression::Interval { center: Box::new(center), tolerance: Box::new(tolerance), span })
+    }
+
+    /// Takes the next token if it's a unit. Some units are named like functions, e.g. `min`, so those are units
+    /// unless they're called
+    fn unit(&mut self) -> Option<(&'static Unit, Span)> {
+        let token = self.peek()?;
+        let called = self.tokens.get(self.position + 1).is_some_and(|next| next.text == "(");
+        if token.kind == TokenKind::Symbol || token.kind == TokenKind::Number || called {
+            return None;
+        }
+
+        let unit = quantity::lookup(&token.text)?;
+        let span = token.span;
+        self.position += 1;
+        Some((unit, span))
+    }
+
+    fn event(&mut self) -> Result<Event, Error> {
+        let mut events = vec![self.outcome()?];
+        let mut connective: Option<String> = None;
+        while let Some(keyword) = self.peek().filter(|next| next.kind == TokenKind::Identifier && (next.text == "and" || next.text == "or")) {
+            if connective.as_ref().is_some_and(|connective| *connective != keyword.text) {
+                return Err(Error::new("Unparseable operation!", keyword.span.start, keyword.span.end));
+            }
+            connective = Some(keyword.text.clone());
+            self.position += 1;
+            events.push(self.outcome()?);
+        }
+
+        Ok(match connective.as_deref() {
+            Some("and") => Event::All(events),
+            Some(_) => Event::Any(events),
+            None => events.remove(0)
+        })
+    }
+
+    fn outcome(&mut self) -> Result<Event, Error> {
+        if self.peek().is_some_and(|next| next.kind == TokenKind::Identifier && next.text == "not") {
+            self.position += 1;
+            return Ok(Event::Not(Box::new(self.nested(Parser::outcome)?)));
+        }
+
+        let left = self.expression()?;
+        if self.peek().is_some_and(|next| next.text == "=") {
+            self.position += 1;
+            let right = self.operand()?;
+            let span = Span { start: left.span().start, end: right.span().end };
+            return Ok(Event::Outcome(Box::new(Expression::Operation { operator: Operator::Equal, left: Box::new(left), right: Box::new(right), span })));
+        }
+        Ok(Event::Outcome(Box::new(left)))
+    }
+
+    fn operand(&mut self) -> Result<Expression, Error> {
+        self.nested(Parser::primary)
+    }
+
+    fn primary(&mut self) -> Result<Expression, Error> {
+        let token = self.next()?;
+
+        match token.kind {
+            TokenKind::Number => {
+                let (note, dots) = music::split_dots(&token.text);
+                let value = if token.text.starts_with('[') {
+                    continued_fraction::parse(&token.text)
+                } else {
+                    mixed_number::parse_as_fraction(note).and_then(|value| music::dotted(&value, dots))
+                };
+                let value = value.map_err(|e| Error::new(e, token.span.start, token.span.end))?;
+                match self.unit() {
+                    Some((unit, unit_span)) => {
+                        let span = Span { start: token.span.start, end: unit_span.end };
+                        Ok(Expression::Quantity { value, text: token.text, unit, span })
+                    },
+                    None => Ok(Expression::Number { value, text: token.text, span: token.span })
+                }
+            },
+            TokenKind::Time => {
+                let value = timecode::parse(&token.text).map_err(|e| Error::new(e, token.span.start, token.span.end))?;
+                Ok(Expression::Time { value, text: token.text, span: token.span })
+            },
+            TokenKind::Imaginary => {
+                let value = mixed_number::parse_as_fraction(&token.text[..token.text.len() - 1])
+                    .map_err(|e| Error::new(e, token.span.start, token.span.end))?;
+                Ok(Expression::Imaginary { value, text: token.text, span: token.span })
+            },
+            TokenKind::Percent => {
+                let value = mixed_number::parse_as_fraction(&token.text[..token.text.len() - 1])
+                    .and_then(|percentage| percentage.divide(&Fraction::from(100)))
+                    .map_err(|e| Error::new(e, token.span.start, token.span.end))?;
+                Ok(Expression::Percent { value, text: token.text, span: token.span })
+            },
+            TokenKind::Money => {
+                let value = money::parse(&token.text).map_err(|e| Error::new(e, token.span.start, token.span.end))?;
+                Ok(Expression::Money { value, text: token.text, span: token.span })
+            },
+            TokenKind::Dice => {
+                let (count, sides) = token.text.split_once('d').unwrap_or_default();
+                match (count.parse(), sides.parse()) {
+                    (Ok(count), Ok(sides)) => Ok(Expression::Dice { count, sides, span: token.span }),
+                    _ => Err(Error::new("Integer overflow!", token.span.start, token.span.end))
+                }
+            },
+            TokenKind::Function => {
+                self.expect("(")?;
+                let mut arguments = vec![self.ratio()?];
+                while self.peek().is_some_and(|next| next.text == ",") {
+                    self.position += 1;
+                    arguments.push(self.ratio()?);
+                }
+                let closing = self.expect(")")?;
+                let span = Span { start: token.span.start, end: closing.span.end };
+                Ok(Expression::Call { function: token.text, arguments, span })
+            },
+            TokenKind::Identifier if token.text == "poly" && self.peek().is_some_and(|next| next.text == "(") => {
+                self.position += 1;
+                let value = self.polynomial()?;
+                let closing = self.expect(")")?;
+                let span = Span { start: token.span.start, end: closing.span.end };
+                Ok(Expression::Polynomial { value, span })
+            },
+            TokenKind::Identifier if token.text == "bar" && self.peek().is_some_and(|next| next.text == "(") => {
+                self.position += 1;
+                let signature = self.next()?;
+                let signature = TimeSignature::parse(&signature.text)
+                    .map_err(|e| Error::new(e, signature.span.start, signature.span.end))?;
+                let mut durations = Vec::new();
+                while self.peek().is_some_and(|next| next.text == ",") {
+                    self.position += 1;
+                    durations.push(self.expression()?);
+                }
+                let closing = self.expect(")")?;
+                let span = Span { start: token.span.start, end: closing.span.end };
+                Ok(Expression::Bar { signature, durations, span })
+            },
+            TokenKind::Identifier if token.text == "P" && self.peek().is_some_and(|next| next.text == "(") => {
+                self.position += 1;
+                let event = self.event()?;
+                let closing = self.expect(")")?;
+                let span = Span { start: token.span.start, end: closing.span.end };
+                Ok(Expression::Probability { event, span })
+            },
+            TokenKind::Identifier if self.peek().is_some_and(|next| next.text == "(") =>
+                Err(Error::new("Unknown function!", token.span.start, token.span.end)),
+            TokenKind::Identifier => Ok(Expression::Variable { name: token.text, span: token.span }),
+            TokenKind::Symbol if token.text == "[" => {
+                let mut items = Vec::new();
+                if self.peek().is_none_or(|next| next.text != "]") {
+                    items.push(self.expression()?);
+                    while self.peek().is_some_and(|next| next.text == ",") {
+                        self.position += 1;
+                        items.push(self.expression()?);
+                    }
+                }
+                let closing = self.expect("]")?;
+                let span = Span { start: token.span.start, end: closing.span.end };
+                Ok(Expression::List { items, span })
+            },
+            TokenKind::Symbol if token.text == "(" => {
+                let expression = self.expression()?;
+                self.expect(")")?;
+                Ok(expression)
+            },
+            TokenKind::Symbol => Err(Error::new("Unparseable operation!", token.span.start, token.span.end))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,539 @@
+use spectral::prelude::*;
+use super::*;
+
+#[test]
+fn parse_with_valid_expressions() {
+    let expressions = ["2_1/2 * 3_3/4", "  2_1/2 * 3_3/4  ", "2_1/2  *  3_3/4"];
+
+    // This is the only way to "parameterize" tests in Rust
+    for expression in &expressions {
+        println!("Testing expression {}", expression); // To help identify failing test case
+        let statement = parse(expression).unwrap();
+
+        match statement.expression {
+            Expression::Operation { operator, left, right, .. } => {
+                assert_that!(&operator)
+                    .is_equal_to(Operator::Multiply);
+                assert_that!(&left.to_string().as_str())
+                    .is_equal_to("2_1/2");
+                assert_that!(&right.to_string().as_str())
+                    .is_equal_to("3_3/4");
+            },
+            expression => panic!("Expected an operation, got {:?}", expression)
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Unparseable operation!")]
+fn parse_with_invalid_expression() {
+    parse("2_1/2 * 2 3_3/4").unwrap();
+}
+
+#[test]
+fn parse_lone_number_is_an_error() {
+    let error = parse("5/2").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unparseable operation!", 0, 3));
+}
+
+#[test]
+fn parse_lone_computed_literals() {
+    let parse_test_cases = ["[4; 2, 6, 7]", "1:23:45", "3/4i", "2d6", "1/4."];
+
+    for input in &parse_test_cases {
+        println!("Parsing {}", input);
+
+        assert_that!(parse(input).unwrap().expression.to_string().as_str()).is_equal_to(input);
+    }
+}
+
+#[test]
+fn parse_chained_operations_with_precedence() {
+    let parse_test_cases = [
+        ("1/4 + 1/8 + 1/8 + 1/2", "((1/4 + 1/8) + 1/8) + 1/2"),
+        ("1/2 + 1/3 * 2", "1/2 + (1/3 * 2)"),
+        ("1 / 2 - 3_3/4", "(1 / 2) - 3_3/4"),
+        ("1 - 1/2 * 3 / 4 - 1", "(1 - ((1/2 * 3) / 4)) - 1"),
+        ("1/2 + 1/4 >= 2/3 * 1", "(1/2 + 1/4) >= (2/3 * 1)")
+    ];
+
+    for (input, expected) in &parse_test_cases {
+        println!("Parsing {}", input);
+
+        assert_that!(parse(input).unwrap().expression.to_string().as_str()).is_equal_to(expected);
+    }
+}
+
+#[test]
+fn parse_chained_comparisons_is_an_error() {
+    let error = parse("1 < 2 < 3").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unparseable operation!", 6, 9));
+}
+
+#[test]
+fn parse_comparison_with_parentheses() {
+    let statement = parse("(1/2 + 1/4) >= 2/3").unwrap();
+
+    assert_that!(statement.expression.to_string().as_str())
+        .is_equal_to("(1/2 + 1/4) >= 2/3");
+}
+
+#[test]
+fn parse_function_call() {
+    let statement = parse("clamp(x, 0, 1)").unwrap();
+
+    match statement.expression {
+        Expression::Call { function, arguments, span } => {
+            assert_that!(function.as_str())
+                .is_equal_to("clamp");
+            assert_that!(&arguments)
+                .has_length(3);
+            assert_that!(&span)
+                .is_equal_to(Span { start: 0, end: 14 });
+        },
+        expression => panic!("Expected a call, got {:?}", expression)
+    }
+}
+
+#[test]
+fn parse_unknown_function() {
+    let error = parse("sqrt(4)").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unknown function!", 0, 4));
+}
+
+#[test]
+fn parse_function_name_without_arguments_is_an_error() {
+    let error = parse("x = abs + 1").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unparseable operation!", 8, 9));
+}
+
+#[test]
+fn parse_unclosed_call_is_an_error() {
+    let error = parse("max(1/2, 2/3").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unparseable operation!", 12, 12));
+}
+
+#[test]
+fn parse_assignment() {
+    let statement = parse("x = 1/2 + 1/4").unwrap();
+
+    assert_that!(&statement.variable)
+        .is_equal_to(Some("x".to_string()));
+    assert_that!(statement.expression.to_string().as_str())
+        .is_equal_to("1/2 + 1/4");
+}
+
+#[test]
+fn parse_error_points_to_operand() {
+    let error = parse("  2_1/2 * 3/0").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Fraction with zero denominator!", 10, 13));
+}
+
+#[test]
+fn parse_continued_fraction_literal() {
+    let statement = parse("[4; 2, 6, 7] + 1").unwrap();
+
+    match statement.expression {
+        Expression::Operation { left, .. } => assert_that!(&*left).is_equal_to(&Expression::Number {
+            value: Fraction::new(415, 93).unwrap(),
+            text: "[4; 2, 6, 7]".to_string(),
+            span: Span { start: 0, end: 12 }
+        }),
+        expression => panic!("Expected an operation, got {:?}", expression)
+    }
+}
+
+#[test]
+fn parse_invalid_continued_fraction_literal() {
+    let error = parse("1 + [4; 0, 2]").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Invalid continued fraction!", 4, 13));
+}
+
+#[test]
+fn parse_list_literal() {
+    let statement = parse("data = [1/2, 3_1/4, (7/8 - 1/8), []]").unwrap();
+
+    match statement.expression {
+        Expression::List { items, span } => {
+            assert_that!(&items)
+                .has_length(4);
+            assert_that!(&span)
+                .is_equal_to(Span { start: 7, end: 36 });
+        },
+        expression => panic!("Expected a list, got {:?}", expression)
+    }
+}
+
+#[test]
+fn parse_unclosed_list_is_an_error() {
+    let error = parse("[1/2, 3/4").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unparseable operation!", 9, 9));
+}
+
+#[test]
+fn parse_ratio() {
+    let statement = parse("1_1/2 : 2 : (1 + 1)").unwrap();
+
+    match statement.expression {
+        Expression::Ratio { terms, span } => {
+            assert_that!(&terms)
+                .has_length(3);
+            assert_that!(&span)
+                .is_equal_to(Span { start: 0, end: 18 });
+        },
+        expression => panic!("Expected a ratio, got {:?}", expression)
+    }
+}
+
+#[test]
+fn parse_proportion() {
+    let statement = parse("3/4 : 6 = x : 10").unwrap();
+
+    assert_that!(&statement.variable)
+        .is_none();
+    match statement.expression {
+        Expression::Proportion { left, right, span } => {
+            assert_that!(left.to_string().as_str())
+                .is_equal_to("3/4 : 6");
+            assert_that!(right.to_string().as_str())
+                .is_equal_to("x : 10");
+            assert_that!(&span)
+                .is_equal_to(Span { start: 0, end: 16 });
+        },
+        expression => panic!("Expected a proportion, got {:?}", expression)
+    }
+}
+
+#[test]
+fn parse_assignment_of_ratio() {
+    let statement = parse("r = 3:4").unwrap();
+
+    assert_that!(&statement.variable)
+        .is_equal_to(Some("r".to_string()));
+    assert_that!(statement.expression.to_string().as_str())
+        .is_equal_to("3 : 4");
+}
+
+#[test]
+fn parse_proportion_without_two_term_ratios() {
+    let error = parse("1:2:3 = x:4").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Expected a ratio of two terms!", 0, 5));
+}
+
+#[test]
+fn parse_equation_to_solve() {
+    let statement = parse("solve 2/3 x + 1_1/4 = 5/6").unwrap();
+
+    match statement.expression {
+        Expression::Solve { equations, span } => {
+            assert_that!(&equations).is_equal_to(vec![Equation {
+                left: vec![
+                    Term { coefficient: Fraction::new(2, 3).unwrap(), unknown: Some("x".to_string()) },
+                    Term { coefficient: Fraction::new(5, 4).unwrap(), unknown: None }
+                ],
+                right: vec![Term { coefficient: Fraction::new(5, 6).unwrap(), unknown: None }]
+            }]);
+            assert_that!(&span)
+                .is_equal_to(Span { start: 0, end: 25 });
+        },
+        expression => panic!("Expected equations to solve, got {:?}", expression)
+    }
+}
+
+#[test]
+fn parse_system_to_solve() {
+    let statement = parse("solve { x + y = 3/2; -2x - 3 * y = 1/4; }").unwrap();
+
+    assert_that!(statement.expression.to_string().as_str())
+        .is_equal_to("solve { x + y = 1_1/2; -2 x - 3 y = 1/4 }");
+}
+
+#[test]
+fn parse_solve_as_variable() {
+    let statement = parse("solve = 1/2 + 1/4").unwrap();
+
+    assert_that!(&statement.variable)
+        .is_equal_to(Some("solve".to_string()));
+}
+
+#[test]
+fn parse_non_linear_equation_is_an_error() {
+    let error = parse("solve x * y = 2").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unparseable operation!", 8, 9));
+}
+
+#[test]
+fn parse_equation_without_unknowns_is_an_error() {
+    let error = parse("solve 1 = 2").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Expected an unknown!", 0, 11));
+}
+
+#[test]
+fn parse_polynomial() {
+    let statement = parse("poly(1/2 x^2 - 3/4x + x^2 + 1)").unwrap();
+
+    match &statement.expression {
+        Expression::Polynomial { value, span } => {
+            assert_that!(value.coefficients()).is_equal_to(&[
+                Fraction::from(1), Fraction::new(-3, 4).unwrap(), Fraction::new(3, 2).unwrap()
+            ][..]);
+            assert_that!(span).is_equal_to(&Span { start: 0, end: 30 });
+        },
+        expression => panic!("Expected a polynomial, got {:?}", expression)
+    }
+    assert_that!(statement.expression.to_string().as_str())
+        .is_equal_to("poly(1_1/2 x^2 - 3/4 x + 1)");
+}
+
+#[test]
+fn parse_polynomial_in_several_variables_is_an_error() {
+    let error = parse("poly(x^2 + 2y)").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Polynomial variables don't match!", 11, 13));
+}
+
+#[test]
+fn parse_polynomial_with_overflowing_coefficient_is_an_error() {
+    let error = parse("poly(2147483647 x + 2147483647 x)").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Integer overflow!", 20, 32));
+}
+
+#[test]
+fn parse_polynomial_with_fraction_exponent_is_an_error() {
+    let error = parse("poly(x^1/2)").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Expected a whole exponent up to 100!", 7, 10));
+}
+
+#[test]
+fn parse_quantity_conversion() {
+    let statement = parse("3 in in cm").unwrap();
+
+    match &statement.expression {
+        Expression::Conversion { expression, unit, span } => {
+            assert_that!(expression.to_string().as_str()).is_equal_to("3 in");
+            assert_that!(unit.name).is_equal_to("cm");
+            assert_that!(span).is_equal_to(&Span { start: 0, end: 10 });
+        },
+        expression => panic!("Expected a conversion, got {:?}", expression)
+    }
+}
+
+#[test]
+fn parse_quantity_with_unit_named_like_function() {
+    let statement = parse("5 min + 30 s").unwrap();
+
+    assert_that!(statement.expression.to_string().as_str())
+        .is_equal_to("5 min + 30 s");
+}
+
+#[test]
+fn parse_times_apart_from_ratios() {
+    let statement = parse("1:23:45;12 + 0:00:10.5").unwrap();
+
+    match &statement.expression {
+        Expression::Operation { left, right, .. } => {
+            assert_that!(left.as_ref()).is_equal_to(&Expression::Time {
+                value: Timecode { seconds: Fraction::from(5025), frames: Some(12) },
+                text: "1:23:45;12".to_string(),
+                span: Span { start: 0, end: 10 }
+            });
+            assert_that!(right.to_string().as_str()).is_equal_to("0:00:10.5");
+        },
+        expression => panic!("Expected an operation, got {:?}", expression)
+    }
+    assert_that!(parse("1 : 23 : 45").unwrap().expression.to_string().as_str())
+        .is_equal_to("1 : 23 : 45");
+}
+
+#[test]
+fn parse_time_with_minutes_over_59_is_an_error() {
+    let error = parse("1:75:00 + 0:00:01").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unparseable time!", 0, 7));
+}
+
+#[test]
+fn parse_dotted_notes() {
+    let statement = parse("1/4. + 1/2..").unwrap();
+
+    match &statement.expression {
+        Expression::Operation { left, right, .. } => {
+            assert_that!(left.as_ref()).is_equal_to(&Expression::Number {
+                value: Fraction::new(3, 8).unwrap(),
+                text: "1/4.".to_string(),
+                span: Span { start: 0, end: 4 }
+            });
+            assert_that!(right.to_string().as_str()).is_equal_to("1/2..");
+        },
+        expression => panic!("Expected an operation, got {:?}", expression)
+    }
+}
+
+#[test]
+fn parse_bar_keeps_time_signature() {
+    let statement = parse("bar(4/4, 1/2, tuplet(3:2, 1/4))").unwrap();
+
+    match &statement.expression {
+        Expression::Bar { signature, durations, .. } => {
+            assert_that!(signature).is_equal_to(&TimeSignature { beats: 4, note: 4 });
+            assert_that!(durations.len()).is_equal_to(2);
+        },
+        expression => panic!("Expected a bar, got {:?}", expression)
+    }
+    assert_that!(statement.expression.to_string().as_str())
+        .is_equal_to("bar(4/4, 1/2, tuplet(3 : 2, 1/4))");
+}
+
+#[test]
+fn parse_bar_with_invalid_time_signature_is_an_error() {
+    let error = parse("bar(6/5, 1/2)").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Expected a time signature!", 4, 7));
+}
+
+#[test]
+fn parse_conversion_to_unknown_unit_is_an_error() {
+    let error = parse("3 ft to furlong").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unknown unit!", 7, 15));
+}
+
+#[test]
+fn parse_probability_of_dice() {
+    let statement = parse("P(2d6 = 7)").unwrap();
+
+    assert_that!(&statement.expression.to_string()).is_equal_to("P(2d6 == 7)".to_string());
+    assert_that!(&parse("P(not 1d20 >= 15 or 1d20 = 1)").unwrap().expression.to_string())
+        .is_equal_to("P(not 1d20 >= 15 or 1d20 == 1)".to_string());
+    assert_that!(&parse("P((1d6 + 1d6) = 12)").unwrap().expression.to_string())
+        .is_equal_to("P((1d6 + 1d6) == 12)".to_string());
+}
+
+#[test]
+fn parse_probability_mixing_and_with_or_is_an_error() {
+    let error = parse("P(1d6 = 1 and 1d6 = 2 or 1d6 = 3)").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unparseable operation!", 22, 24));
+}
+
+#[test]
+fn parse_tolerances_before_operators() {
+    let statement = parse("2_1/2 ± 1/64 + 3/4 +/- 1/32").unwrap();
+
+    match statement.expression {
+        Expression::Operation { operator, left, right, .. } => {
+            assert_that!(&operator).is_equal_to(Operator::Add);
+            assert_that!(&left.to_string()).is_equal_to("2_1/2 ± 1/64".to_string());
+            assert_that!(&right.span()).is_equal_to(Span { start: 16, end: 28 });
+        },
+        expression => panic!("Expected an operation, got {:?}", expression)
+    }
+}
+
+#[test]
+fn parse_complex_number() {
+    let statement = parse("1/2 + 3/4i").unwrap();
+
+    match statement.expression {
+        Expression::Operation { right, .. } => match *right {
+            Expression::Imaginary { value, text, .. } => {
+                assert_that!(&value).is_equal_to(Fraction::new(3, 4).unwrap());
+                assert_that!(text.as_str()).is_equal_to("3/4i");
+            },
+            expression => panic!("Expected an imaginary number, got {:?}", expression)
+        },
+        expression => panic!("Expected an operation, got {:?}", expression)
+    }
+}
+
+#[test]
+fn parse_percentages() {
+    let statement = parse("15% of 2_1/2").unwrap();
+
+    match statement.expression {
+        Expression::Operation { operator, left, .. } => {
+            assert_that!(&operator).is_equal_to(Operator::Of);
+            match *left {
+                Expression::Percent { value, .. } => assert_that!(&value).is_equal_to(Fraction::new(3, 20).unwrap()),
+                expression => panic!("Expected a percentage, got {:?}", expression)
+            }
+        },
+        expression => panic!("Expected an operation, got {:?}", expression)
+    }
+    assert_that!(&parse("(1/2 + 1/4) as %").unwrap().expression.to_string())
+        .is_equal_to("(1/2 + 1/4) as %".to_string());
+}
+
+#[test]
+fn parse_percent_as_remainder_is_an_error() {
+    let error = parse("7 % 3").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unparseable operation!", 2, 5));
+}
+
+#[test]
+fn parse_rounded_money() {
+    let statement = parse("$129.99 * 2/3 rounded half_up").unwrap();
+
+    match &statement.expression {
+        Expression::Rounded { expression, rounding, span } => {
+            assert_that!(rounding).is_equal_to(&Rounding::HalfUp);
+            assert_that!(&expression.to_string()).is_equal_to("$129.99 * 2/3".to_string());
+            assert_that!(span).is_equal_to(&Span { start: 0, end: 29 });
+        },
+        expression => panic!("Expected a rounding, got {:?}", expression)
+    }
+    assert_that!(&parse("$1 * 2 rounded down").err().unwrap())
+        .is_equal_to(Error::new("Unknown rounding!", 15, 19));
+}
+
+#[test]
+fn parse_nested_up_to_the_depth_limit() {
+    let input = format!("{}1 + 1{}", "(".repeat(63), ")".repeat(63));
+
+    assert_that!(&parse(&input).map(|statement| statement.expression.to_string())).is_equal_to(Ok("1 + 1".to_string()));
+}
+
+#[test]
+fn parse_nested_beyond_the_depth_limit() {
+    let input = format!("{}1{}", "(".repeat(64), ")".repeat(64));
+
+    assert_that!(&parse(&input).err().unwrap())
+        .is_equal_to(Error::new("Too deeply nested!", 64, 65));
+    assert_that!(&parse(&"(".repeat(100000)).err().unwrap())
+        .is_equal_to(Error::new("Too deeply nested!", 64, 65));
+    assert_that!(&parse(&format!("P({}1 = 1)", "not ".repeat(100000))).err().unwrap())
+        .is_equal_to(Error::new("Too deeply nested!", 258, 261));
+}
@@ -0,0 +1,366 @@
+use std::cmp::Ordering;
+use crate::expression::{Event, Expression, Operator, Statement};
+use crate::value::{percentage, Value};
+use crate::continued_fraction;
+use crate::ratio;
+use crate::fraction::Fraction;
+use crate::linear::{Equation, Term};
+use crate::polynomial::Polynomial;
+use crate::timecode;
+use crate::probability;
+use crate::complex::Complex;
+use crate::money::{self, Rounding};
+use super::NumberParts;
+
+/// Renders an expression and its value as a LaTeX equation, e.g. `2\tfrac{3}{8} + \frac{9}{8} = 3\tfrac{1}{2}`.
+/// Mixed numbers use `\tfrac` so the fraction doesn't dwarf the whole part.
+/// Comparisons are rendered as a statement followed by its truth, e.g. `\frac{1}{2} < \frac{2}{3} \text{ is true}`
+/// and solved proportions & equations as implying their solution, e.g. `3 : 6 = x : 10 \Rightarrow x = 5`.
+/// Systems of equations are rendered as cases. Assignments are rendered with their variable on the left, e.g. `x = \frac{1}{2} + \frac{1}{4} = \frac{3}{4}`
+pub fn render(statement: &Statement, value: &Value) -> String {
+    let equation = render_evaluation(&statement.expression, value);
+    match &statement.variable {
+        Some(variable) => format!("{} = {}", variable.replace('_', "\\_"), equation),
+        None => equation
+    }
+}
+
+fn render_evaluation(expression: &Expression, value: &Value) -> String {
+    match value {
+        Value::Number(result) => format!(
+            "{} = {}",
+            render_expression(expression), render_number(&NumberParts::from_result(result))
+        ),
+        Value::Boolean(result) => format!("{} \\text{{ is {}}}", render_expression(expression), result),
+        Value::Factors { factors, .. } => format!("{} = {}", render_expression(expression), render_factors(factors)),
+        Value::CommonDenominator { denominator, fractions } => {
+            let fractions: Vec<String> = fractions.iter()
+                .map(|fraction| format!(
+                    "{} = {}",
+                    render_number(&NumberParts::from_result(fraction)),
+                    render_number(&NumberParts::over_denominator(fraction, *denominator))
+                ))
+                .collect();
+            format!(
+                "{} = {} \\quad \\left({}\\right)",
+                render_expression(expression), denominator, fractions.join(", ")
+            )
+        },
+        Value::ContinuedFraction { terms, .. } => format!("{} = {}", render_expression(expression), render_terms(terms)),
+        Value::List(numbers) => {
+            let numbers: Vec<String> = numbers.iter()
+                .map(|number| render_number(&NumberParts::from_result(number)))
+                .collect();
+            format!("{} = \\left[{}\\right]", render_expression(expression), numbers.join(", "))
+        },
+        Value::UnitFractions { denominators, .. } => {
+            let units: Vec<String> = denominators.iter()
+                .map(|denominator| format!("\\frac{{1}}{{{}}}", denominator))
+                .collect();
+            format!("{} = {}", render_expression(expression), units.join(" + "))
+        },
+        Value::Ratio(terms) => format!("{} = {}", render_expression(expression), ratio::format_terms(terms)),
+        Value::Solution(solution) => {
+            let solution: Vec<String> = solution.iter()
+                .map(|(variable, value)| format!(
+                    "{} = {}", variable.replace('_', "\\_"), render_number(&NumberParts::from_result(value))
+                ))
+                .collect();
+            format!("{} \\Rightarrow {}", render_expression(expression), solution.join(", "))
+        },
+        Value::Matrix(matrix) => {
+            let rows: Vec<Vec<String>> = matrix.rows().iter()
+                .map(|row| row.iter().map(|entry| render_number(&NumberParts::from_result(entry))).collect())
+                .collect();
+            format!("{} = {}", render_expression(expression), render_matrix(&rows))
+        },
+        Value::Polynomial(polynomial) => format!("{} = {}", render_expression(expression), render_polynomial(polynomial)),
+        Value::PolynomialDivision { quotient, remainder } => format!(
+            "{} = {} \\text{{ remainder }} {}",
+            render_expression(expression), render_polynomial(quotient), render_polynomial(remainder)
+        ),
+        Value::Quantity(quantity) => format!(
+            "{} = {}",
+            render_expression(expression), render_quantity(&NumberParts::from_result(&quantity.amount), quantity.unit.name)
+        ),
+        Value::Time(seconds) => format!("{} = {}", render_expression(expression), render_time(&timecode::format(seconds))),
+        Value::Bar(bar) => match bar.fill() {
+            Ordering::Equal => format!("{} \\text{{ is full}}", render_expression(expression)),
+            fill => format!(
+                "{} \\text{{ is {} by }} {}",
+                render_expression(expression), if fill == Ordering::Less { "underfull" } else { "overfull" },
+                render_number(&NumberParts::from_result(&bar.difference().unwrap_or_default()))
+            )
+        },
+        Value::Distribution(distribution) => {
+            let outcomes: Vec<String> = distribution.outcomes().iter()
+                .map(|(outcome, probability)| format!(
+                    "{} \\mapsto {}", outcome, render_number(&NumberParts::from_result(probability))
+                ))
+                .collect();
+            format!("{} = \\left\\{{{}\\right\\}}", render_expression(expression), outcomes.join(", "))
+        },
+        Value::Event(probability) => {
+            let (favourable, unfavourable) = probability::odds(probability);
+            format!(
+                "{} = {} \\quad \\left(\\text{{odds }} {} : {}\\right)",
+                render_expression(expression), render_number(&NumberParts::from_result(probability)), favourable, unfavourable
+            )
+        },
+        Value::Interval(interval) => format!(
+            "{} = {} \\pm {} = \\left[{}, {}\\right]",
+            render_expression(expression),
+            render_number(&NumberParts::from_result(&interval.center().unwrap_or_default())),
+            render_number(&NumberParts::from_result(&interval.half_width().unwrap_or_default())),
+            render_number(&NumberParts::from_result(&interval.lower)),
+            render_number(&NumberParts::from_result(&interval.upper))
+        ),
+        Value::Complex(complex) => format!("{} = {}", render_expression(expression), render_complex(complex)),
+        Value::Percent(fraction) => format!(
+            "{} = {}", render_expression(expression), render_percent(&NumberParts::from_result(&percentage(fraction).unwrap_or_default()))
+        ),
+        Value::Money(amount) => format!("{} = {}", render_expression(expression), render_money(&money::format(amount, Rounding::default()))),
+        Value::Shares(shares) => {
+            let shares: Vec<String> = shares.iter()
+                .map(|share| render_money(&money::format(share, Rounding::default())))
+                .collect();
+            format!("{} = \\left[{}\\right]", render_expression(expression), shares.join(", "))
+        }
+    }
+}
+
+/// Complex numbers are rendered with their parts as mixed numbers, leaving out a real part of `0`, e.g. `\frac{1}{2} - \frac{3}{4}i`
+fn render_complex(complex: &Complex) -> String {
+    let imaginary = format!("{}i", render_number(&NumberParts::from_result(&complex.imaginary.abs())));
+
+    match (complex.real.numerator == 0, complex.imaginary.numerator < 0) {
+        (true, true) => format!("-{}", imaginary),
+        (true, false) => imaginary,
+        (false, true) => format!("{} - {}", render_number(&NumberParts::from_result(&complex.real)), imaginary),
+        (false, false) => format!("{} + {}", render_number(&NumberParts::from_result(&complex.real)), imaginary)
+    }
+}
+
+/// Polynomials are rendered from the highest degree down, leaving out coefficients of `1`, e.g. `\frac{1}{2} x^{2} - x`
+fn render_polynomial(polynomial: &Polynomial) -> String {
+    if polynomial.is_zero() {
+        return "0".to_string();
+    }
+
+    let variable = polynomial.variable().replace('_', "\\_");
+    let mut text = String::new();
+    for (degree, coefficient) in polynomial.coefficients().iter().enumerate().rev().filter(|(_, c)| c.numerator != 0) {
+        text.push_str(match (text.is_empty(), coefficient.numerator < 0) {
+            (true, true) => "-",
+            (true, false) => "",
+            (false, true) => " - ",
+            (false, false) => " + "
+        });
+
+        let coefficient = coefficient.abs();
+        let power = match degree {
+            1 => variable.clone(),
+            _ => format!("{}^{{{}}}", variable, degree)
+        };
+        match (degree, coefficient == Fraction::from(1)) {
+            (0, _) => text.push_str(&render_number(&NumberParts::from_result(&coefficient))),
+            (_, true) => text.push_str(&power),
+            (_, false) => text.push_str(&format!("{} {}", render_number(&NumberParts::from_result(&coefficient)), power))
+        }
+    }
+    text
+}
+
+/// Matrices are rendered with brackets, e.g. `\begin{bmatrix} 1 & 2 \\ 3 & 4 \end{bmatrix}`
+fn render_matrix(rows: &[Vec<String>]) -> String {
+    let rows: Vec<String> = rows.iter().map(|row| row.join(" & ")).collect();
+
+    format!("\\begin{{bmatrix}} {} \\end{{bmatrix}}", rows.join(" \\\\ "))
+}
+
+/// Continued fractions get brackets that grow with their terms, e.g. `\left[4; 2, 6, 7\right]`
+fn render_terms(terms: &[i32]) -> String {
+    let text = continued_fraction::format_terms(terms);
+
+    format!("\\left{}\\right]", &text[..text.len() - 1])
+}
+
+fn render_factors(factors: &[(i32, u32)]) -> String {
+    if factors.is_empty() {
+        return "1".to_string();
+    }
+
+    let factors: Vec<String> = factors.iter()
+        .map(|&(prime, exponent)| match exponent {
+            1 => prime.to_string(),
+            _ => format!("{}^{{{}}}", prime, exponent)
+        })
+        .collect();
+    factors.join(" \\cdot ")
+}
+
+fn render_expression(expression: &Expression) -> String {
+    match expression {
+        Expression::Number { text, .. } if text.starts_with('[') =>
+            render_terms(&continued_fraction::parse_terms(text).unwrap_or_default()),
+        Expression::Number { text, .. } if text.ends_with('.') => {
+            let (note, dots) = NumberParts::from_dotted(text);
+            format!("{}{}", render_number(&note), ".".repeat(dots as usize))
+        },
+        Expression::Number { value, text, .. } => render_number(&NumberParts::from_literal(text, value)),
+        // Underscores are subscripts in LaTeX, so they're escaped in names
+        Expression::Variable { name, .. } => name.replace('_', "\\_"),
+        Expression::Call { function, arguments, .. } => {
+            let arguments: Vec<String> = arguments.iter().map(render_expression).collect();
+            format!("\\operatorname{{{}}}\\left({}\\right)", function.replace('_', "\\_"), arguments.join(", "))
+        },
+        Expression::List { items, .. } if !items.is_empty() && items.iter().all(|item| matches!(item, Expression::List { .. })) => {
+            let rows: Vec<Vec<String>> = items.iter()
+                .map(|row| match row {
+                    Expression::List { items, .. } => items.iter().map(render_expression).collect(),
+                    _ => Vec::new()
+                })
+                .collect();
+            render_matrix(&rows)
+        },
+        Expression::List { items, .. } => {
+            let items: Vec<String> = items.iter().map(render_expression).collect();
+            format!("\\left[{}\\right]", items.join(", "))
+        },
+        Expression::Operation { operator, left, right, .. } => format!(
+            "{} {} {}",
+            render_operand(left, false), render_operator(*operator), render_operand(right, true)
+        ),
+        Expression::Ratio { terms, .. } => {
+            let terms: Vec<String> = terms.iter().map(render_expression).collect();
+            terms.join(" : ")
+        },
+        Expression::Proportion { left, right, .. } =>
+            format!("{} = {}", render_expression(left), render_expression(right)),
+        Expression::Solve { equations, .. } if equations.len() == 1 => render_equation(&equations[0]),
+        Expression::Solve { equations, .. } => {
+            let equations: Vec<String> = equations.iter().map(render_equation).collect();
+            format!("\\begin{{cases}} {} \\end{{cases}}", equations.join(" \\\\ "))
+        },
+        Expression::Polynomial { value, .. } => format!("\\left({}\\right)", render_polynomial(value)),
+        Expression::Quantity { value, text, unit, .. } => render_quantity(&NumberParts::from_literal(text, value), unit.name),
+        Expression::Conversion { expression, unit, .. } =>
+            format!("{} \\rightarrow \\text{{{}}}", render_operand(expression, false), unit.name),
+        Expression::Time { text, .. } => render_time(text),
+        Expression::Bar { signature, durations, .. } => {
+            let durations: Vec<String> = durations.iter().map(|duration| format!(", {}", render_expression(duration))).collect();
+            format!(
+                "\\operatorname{{bar}}\\left(\\frac{{{}}}{{{}}}{}\\right)",
+                signature.beats, signature.note, durations.concat()
+            )
+        },
+        Expression::Dice { count, sides, .. } => format!("{}\\text{{d}}{}", count, sides),
+        Expression::Probability { event, .. } => format!("P\\left({}\\right)", render_event(event)),
+        Expression::Interval { center, tolerance, .. } =>
+            format!("{} \\pm {}", render_operand(center, false), render_operand(tolerance, true)),
+        Expression::Imaginary { value, text, .. } =>
+            format!("{}i", render_number(&NumberParts::from_literal(&text[..text.len() - 1], value))),
+        Expression::Percent { value, text, .. } =>
+            render_percent(&NumberParts::from_literal(&text[..text.len() - 1], &percentage(value).unwrap_or_default())),
+        Expression::AsPercent { expression, .. } => format!("{} \\rightarrow \\%", render_operand(expression, false)),
+        Expression::Money { text, .. } => render_money(text),
+        Expression::Rounded { expression, rounding, .. } =>
+            format!("{} \\text{{ rounded {}}}", render_operand(expression, false), rounding.name().replace('_', "\\_"))
+    }
+}
+
+/// Events are joined with logical connectives, e.g. `\lnot 1\text{d}6 = 1 \land 1\text{d}6 = 2`
+fn render_event(event: &Event) -> String {
+    match event {
+        Event::Outcome(expression) => render_expression(expression),
+        Event::Not(event) => format!("\\lnot {}", render_event(event)),
+        Event::All(events) => events.iter().map(render_event).collect::<Vec<String>>().join(" \\land "),
+        Event::Any(events) => events.iter().map(render_event).collect::<Vec<String>>().join(" \\lor ")
+    }
+}
+
+fn render_equation(equation: &Equation) -> String {
+    format!("{} = {}", render_linear(&equation.left), render_linear(&equation.right))
+}
+
+/// Coefficients of `1` are left out & negative ones are substracted, like in plain text
+fn render_linear(terms: &[Term]) -> String {
+    let mut text = String::new();
+
+    for (index, term) in terms.iter().enumerate() {
+        let negative = term.coefficient.numerator < 0;
+        text.push_str(match (index, negative) {
+            (0, true) => "-",
+            (0, false) => "",
+            (_, true) => " - ",
+            (_, false) => " + "
+        });
+
+        let coefficient = term.coefficient.abs();
+        match &term.unknown {
+            Some(unknown) if coefficient == Fraction::from(1) => text.push_str(&unknown.replace('_', "\\_")),
+            Some(unknown) => text.push_str(&format!(
+                "{} {}", render_number(&NumberParts::from_result(&coefficient)), unknown.replace('_', "\\_")
+            )),
+            None => text.push_str(&render_number(&NumberParts::from_result(&coefficient)))
+        }
+    }
+    text
+}
+
+/// Operations used as operands get parentheses, negative numbers only when they're right operands
+fn render_operand(operand: &Expression, is_right: bool) -> String {
+    match operand {
+        Expression::Operation { .. } => format!("\\left({}\\right)", render_expression(operand)),
+        Expression::Number { value, .. } | Expression::Imaginary { value, .. } | Expression::Percent { value, .. }
+            if is_right && value.numerator < 0 =>
+            format!("\\left({}\\right)", render_expression(operand)),
+        _ => render_expression(operand)
+    }
+}
+
+/// Units are set upright with a thin space after the number, e.g. `1\tfrac{1}{2}\,\text{in}`
+/// Times are rendered as text so their colons aren't spaced like ratios, e.g. `\text{1:23:45}`
+fn render_time(time: &str) -> String {
+    format!("\\text{{{}}}", time)
+}
+
+/// Percent signs start comments in LaTeX, so they're escaped, e.g. `37\tfrac{1}{2}\%`
+fn render_percent(number: &NumberParts) -> String {
+    format!("{}\\%", render_number(number))
+}
+
+/// Dollar signs start math mode in LaTeX, so they're escaped too, e.g. `\$1,234.57`
+fn render_money(amount: &str) -> String {
+    amount.replace('$', "\\$")
+}
+
+fn render_quantity(amount: &NumberParts, unit: &str) -> String {
+    format!("{}\\,\\text{{{}}}", render_number(amount), unit)
+}
+
+fn render_number(number: &NumberParts) -> String {
+    let sign = if number.negative { "-" } else { "" };
+
+    match (number.whole, number.fraction) {
+        (Some(whole), Some((numerator, denominator))) =>
+            format!("{}{}\\tfrac{{{}}}{{{}}}", sign, whole, numerator, denominator),
+        (None, Some((numerator, denominator))) =>
+            format!("{}\\frac{{{}}}{{{}}}", sign, numerator, denominator),
+        (Some(whole), None) => format!("{}{}", sign, whole),
+        (None, None) => "0".to_string()
+    }
+}
+
+fn render_operator(operator: Operator) -> &'static str {
+    match operator {
+        Operator::Multiply => "\\times",
+        Operator::Divide => "\\div",
+        Operator::LessOrEqual => "\\le",
+        Operator::GreaterOrEqual => "\\ge",
+        Operator::Equal => "=",
+        Operator::NotEqual => "\\ne",
+        Operator::Of => "\\text{ of }",
+        operator => operator.symbol()
+    }
+}
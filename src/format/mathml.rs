@@ -0,0 +1,358 @@
+use std::cmp::Ordering;
+use crate::expression::{Event, Expression, Operator, Statement};
+use crate::value::{percentage, Value};
+use crate::continued_fraction;
+use crate::fraction::Fraction;
+use crate::linear::{Equation, Term};
+use crate::polynomial::Polynomial;
+use crate::timecode;
+use crate::probability;
+use crate::complex::Complex;
+use crate::money::{self, Rounding};
+use super::NumberParts;
+
+/// Renders an expression and its value as a Presentation MathML `<math>` element.
+/// Comparisons are rendered as a statement followed by its truth & assignments with their variable on the left, like in LaTeX
+pub fn render(statement: &Statement, value: &Value) -> String {
+    let expression = &statement.expression;
+    let value = match value {
+        Value::Number(result) => format!("<mo>=</mo>{}", render_number(&NumberParts::from_result(result))),
+        Value::Boolean(result) => format!("<mtext>&#xA0;is {}</mtext>", result),
+        Value::Factors { factors, .. } => format!("<mo>=</mo>{}", render_factors(factors)),
+        Value::CommonDenominator { denominator, fractions } => {
+            let fractions: Vec<String> = fractions.iter()
+                .map(|fraction| format!(
+                    "{}<mo>=</mo>{}",
+                    render_number(&NumberParts::from_result(fraction)),
+                    render_number(&NumberParts::over_denominator(fraction, *denominator))
+                ))
+                .collect();
+            format!(
+                "<mo>=</mo><mn>{}</mn><mspace width=\"1em\"/><mrow><mo>(</mo>{}<mo>)</mo></mrow>",
+                denominator, fractions.join("<mo>,</mo>")
+            )
+        },
+        Value::ContinuedFraction { terms, .. } => format!("<mo>=</mo>{}", render_terms(terms)),
+        Value::List(numbers) => {
+            let numbers: Vec<String> = numbers.iter()
+                .map(|number| render_number(&NumberParts::from_result(number)))
+                .collect();
+            format!("<mo>=</mo><mrow><mo>[</mo>{}<mo>]</mo></mrow>", numbers.join("<mo>,</mo>"))
+        },
+        Value::UnitFractions { denominators, .. } => {
+            let units: Vec<String> = denominators.iter()
+                .map(|denominator| format!("<mfrac><mn>1</mn><mn>{}</mn></mfrac>", denominator))
+                .collect();
+            format!("<mo>=</mo><mrow>{}</mrow>", units.join("<mo>+</mo>"))
+        },
+        Value::Ratio(terms) => {
+            let terms: Vec<String> = terms.iter()
+                .map(|&term| render_number(&NumberParts::from_result(&Fraction::from(term))))
+                .collect();
+            format!("<mo>=</mo><mrow>{}</mrow>", terms.join("<mo>:</mo>"))
+        },
+        Value::Solution(solution) => {
+            let solution: Vec<String> = solution.iter()
+                .map(|(variable, value)| format!(
+                    "<mi>{}</mi><mo>=</mo>{}", variable, render_number(&NumberParts::from_result(value))
+                ))
+                .collect();
+            format!("<mo>&#x21D2;</mo>{}", solution.join("<mo>,</mo>"))
+        },
+        Value::Matrix(matrix) => {
+            let rows: Vec<Vec<String>> = matrix.rows().iter()
+                .map(|row| row.iter().map(|entry| render_number(&NumberParts::from_result(entry))).collect())
+                .collect();
+            format!("<mo>=</mo>{}", render_matrix(&rows))
+        },
+        Value::Polynomial(polynomial) => format!("<mo>=</mo>{}", render_polynomial(polynomial)),
+        Value::PolynomialDivision { quotient, remainder } => format!(
+            "<mo>=</mo>{}<mtext>&#xA0;remainder&#xA0;</mtext>{}",
+            render_polynomial(quotient), render_polynomial(remainder)
+        ),
+        Value::Quantity(quantity) => format!(
+            "<mo>=</mo>{}", render_quantity(&NumberParts::from_result(&quantity.amount), quantity.unit.name)
+        ),
+        Value::Time(seconds) => format!("<mo>=</mo><mn>{}</mn>", timecode::format(seconds)),
+        Value::Bar(bar) => match bar.fill() {
+            Ordering::Equal => "<mtext>&#xA0;is full</mtext>".to_string(),
+            fill => format!(
+                "<mtext>&#xA0;is {} by&#xA0;</mtext>{}",
+                if fill == Ordering::Less { "underfull" } else { "overfull" },
+                render_number(&NumberParts::from_result(&bar.difference().unwrap_or_default()))
+            )
+        },
+        Value::Distribution(distribution) => {
+            let outcomes: Vec<String> = distribution.outcomes().iter()
+                .map(|(outcome, probability)| format!(
+                    "<mn>{}</mn><mo>&#x21A6;</mo>{}", outcome, render_number(&NumberParts::from_result(probability))
+                ))
+                .collect();
+            format!("<mo>=</mo><mrow><mo>{{</mo>{}<mo>}}</mo></mrow>", outcomes.join("<mo>,</mo>"))
+        },
+        Value::Event(probability) => {
+            let (favourable, unfavourable) = probability::odds(probability);
+            format!(
+                "<mo>=</mo>{}<mspace width=\"1em\"/><mrow><mo>(</mo><mtext>odds&#xA0;</mtext><mn>{}</mn><mo>:</mo><mn>{}</mn><mo>)</mo></mrow>",
+                render_number(&NumberParts::from_result(probability)), favourable, unfavourable
+            )
+        },
+        Value::Interval(interval) => format!(
+            "<mo>=</mo>{}<mo>&#xB1;</mo>{}<mo>=</mo><mrow><mo>[</mo>{}<mo>,</mo>{}<mo>]</mo></mrow>",
+            render_number(&NumberParts::from_result(&interval.center().unwrap_or_default())),
+            render_number(&NumberParts::from_result(&interval.half_width().unwrap_or_default())),
+            render_number(&NumberParts::from_result(&interval.lower)),
+            render_number(&NumberParts::from_result(&interval.upper))
+        ),
+        Value::Complex(complex) => format!("<mo>=</mo>{}", render_complex(complex)),
+        Value::Percent(fraction) =>
+            format!("<mo>=</mo>{}", render_percent(&NumberParts::from_result(&percentage(fraction).unwrap_or_default()))),
+        Value::Money(amount) => format!("<mo>=</mo><mn>{}</mn>", money::format(amount, Rounding::default())),
+        Value::Shares(shares) => {
+            let shares: Vec<String> = shares.iter()
+                .map(|share| format!("<mn>{}</mn>", money::format(share, Rounding::default())))
+                .collect();
+            format!("<mo>=</mo><mrow><mo>[</mo>{}<mo>]</mo></mrow>", shares.join("<mo>,</mo>"))
+        }
+    };
+
+    let variable = statement.variable.as_ref()
+        .map_or_else(String::new, |variable| format!("<mi>{}</mi><mo>=</mo>", variable));
+    format!(
+        "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">{}{}{}</math>",
+        variable, render_expression(expression), value
+    )
+}
+
+fn render_expression(expression: &Expression) -> String {
+    match expression {
+        Expression::Number { text, .. } if text.starts_with('[') =>
+            render_terms(&continued_fraction::parse_terms(text).unwrap_or_default()),
+        Expression::Number { text, .. } if text.ends_with('.') => {
+            let (note, dots) = NumberParts::from_dotted(text);
+            format!("<mrow>{}{}</mrow>", render_number(&note), "<mo>.</mo>".repeat(dots as usize))
+        },
+        Expression::Number { value, text, .. } => render_number(&NumberParts::from_literal(text, value)),
+        Expression::Variable { name, .. } => format!("<mi>{}</mi>", name),
+        Expression::Call { function, arguments, .. } => {
+            let arguments: Vec<String> = arguments.iter().map(render_expression).collect();
+            format!("<mrow><mi>{}</mi><mo>(</mo>{}<mo>)</mo></mrow>", function, arguments.join("<mo>,</mo>"))
+        },
+        Expression::List { items, .. } if !items.is_empty() && items.iter().all(|item| matches!(item, Expression::List { .. })) => {
+            let rows: Vec<Vec<String>> = items.iter()
+                .map(|row| match row {
+                    Expression::List { items, .. } => items.iter().map(render_expression).collect(),
+                    _ => Vec::new()
+                })
+                .collect();
+            render_matrix(&rows)
+        },
+        Expression::List { items, .. } => {
+            let items: Vec<String> = items.iter().map(render_expression).collect();
+            format!("<mrow><mo>[</mo>{}<mo>]</mo></mrow>", items.join("<mo>,</mo>"))
+        },
+        Expression::Operation { operator, left, right, .. } => format!(
+            "{}<mo>{}</mo>{}",
+            render_operand(left, false), render_operator(*operator), render_operand(right, true)
+        ),
+        Expression::Ratio { terms, .. } => {
+            let terms: Vec<String> = terms.iter().map(render_expression).collect();
+            format!("<mrow>{}</mrow>", terms.join("<mo>:</mo>"))
+        },
+        Expression::Proportion { left, right, .. } =>
+            format!("{}<mo>=</mo>{}", render_expression(left), render_expression(right)),
+        Expression::Solve { equations, .. } if equations.len() == 1 => render_equation(&equations[0]),
+        Expression::Solve { equations, .. } => {
+            let rows: Vec<String> = equations.iter()
+                .map(|equation| format!("<mtr><mtd>{}</mtd></mtr>", render_equation(equation)))
+                .collect();
+            format!("<mrow><mo>{{</mo><mtable>{}</mtable></mrow>", rows.concat())
+        },
+        Expression::Polynomial { value, .. } => format!("<mrow><mo>(</mo>{}<mo>)</mo></mrow>", render_polynomial(value)),
+        Expression::Quantity { value, text, unit, .. } => render_quantity(&NumberParts::from_literal(text, value), unit.name),
+        Expression::Conversion { expression, unit, .. } => format!(
+            "{}<mo>&#x2192;</mo><mi mathvariant=\"normal\">{}</mi>", render_operand(expression, false), unit.name
+        ),
+        Expression::Time { text, .. } => format!("<mn>{}</mn>", text),
+        Expression::Bar { signature, durations, .. } => {
+            let durations: Vec<String> = durations.iter().map(|duration| format!("<mo>,</mo>{}", render_expression(duration))).collect();
+            format!(
+                "<mrow><mi>bar</mi><mo>(</mo><mfrac><mn>{}</mn><mn>{}</mn></mfrac>{}<mo>)</mo></mrow>",
+                signature.beats, signature.note, durations.concat()
+            )
+        },
+        Expression::Dice { count, sides, .. } =>
+            format!("<mrow><mn>{}</mn><mi mathvariant=\"normal\">d</mi><mn>{}</mn></mrow>", count, sides),
+        Expression::Probability { event, .. } => format!("<mrow><mi>P</mi><mo>(</mo>{}<mo>)</mo></mrow>", render_event(event)),
+        Expression::Interval { center, tolerance, .. } =>
+            format!("<mrow>{}<mo>&#xB1;</mo>{}</mrow>", render_operand(center, false), render_operand(tolerance, true)),
+        Expression::Imaginary { value, text, .. } =>
+            format!("<mrow>{}<mi>i</mi></mrow>", render_number(&NumberParts::from_literal(&text[..text.len() - 1], value))),
+        Expression::Percent { value, text, .. } =>
+            render_percent(&NumberParts::from_literal(&text[..text.len() - 1], &percentage(value).unwrap_or_default())),
+        Expression::AsPercent { expression, .. } =>
+            format!("{}<mo>&#x2192;</mo><mo>%</mo>", render_operand(expression, false)),
+        Expression::Money { text, .. } => format!("<mn>{}</mn>", text),
+        Expression::Rounded { expression, rounding, .. } =>
+            format!("{}<mtext>&#xA0;rounded {}</mtext>", render_operand(expression, false), rounding.name())
+    }
+}
+
+fn render_complex(complex: &Complex) -> String {
+    let imaginary = format!("{}<mi>i</mi>", render_number(&NumberParts::from_result(&complex.imaginary.abs())));
+
+    match (complex.real.numerator == 0, complex.imaginary.numerator < 0) {
+        (true, true) => format!("<mrow><mo>&#x2212;</mo>{}</mrow>", imaginary),
+        (true, false) => format!("<mrow>{}</mrow>", imaginary),
+        (false, true) => format!("<mrow>{}<mo>&#x2212;</mo>{}</mrow>", render_number(&NumberParts::from_result(&complex.real)), imaginary),
+        (false, false) => format!("<mrow>{}<mo>+</mo>{}</mrow>", render_number(&NumberParts::from_result(&complex.real)), imaginary)
+    }
+}
+
+fn render_event(event: &Event) -> String {
+    match event {
+        Event::Outcome(expression) => render_expression(expression),
+        Event::Not(event) => format!("<mo>&#xAC;</mo>{}", render_event(event)),
+        Event::All(events) => events.iter().map(render_event).collect::<Vec<String>>().join("<mo>&#x2227;</mo>"),
+        Event::Any(events) => events.iter().map(render_event).collect::<Vec<String>>().join("<mo>&#x2228;</mo>")
+    }
+}
+
+fn render_polynomial(polynomial: &Polynomial) -> String {
+    let terms: Vec<String> = polynomial.coefficients().iter().enumerate().rev()
+        .filter(|(_, coefficient)| coefficient.numerator != 0)
+        .enumerate()
+        .map(|(index, (degree, coefficient))| {
+            let sign = match (index, coefficient.numerator < 0) {
+                (_, true) => "<mo>&#x2212;</mo>",
+                (0, false) => "",
+                (_, false) => "<mo>+</mo>"
+            };
+            let coefficient = coefficient.abs();
+            let power = match degree {
+                1 => format!("<mi>{}</mi>", polynomial.variable()),
+                _ => format!("<msup><mi>{}</mi><mn>{}</mn></msup>", polynomial.variable(), degree)
+            };
+            let term = match (degree, coefficient == Fraction::from(1)) {
+                (0, _) => render_number(&NumberParts::from_result(&coefficient)),
+                (_, true) => power,
+                (_, false) => format!("{}<mo>&#x2062;</mo>{}", render_number(&NumberParts::from_result(&coefficient)), power)
+            };
+            format!("{}{}", sign, term)
+        })
+        .collect();
+
+    if terms.is_empty() {
+        "<mn>0</mn>".to_string()
+    } else {
+        format!("<mrow>{}</mrow>", terms.concat())
+    }
+}
+
+fn render_matrix(rows: &[Vec<String>]) -> String {
+    let rows: Vec<String> = rows.iter()
+        .map(|row| format!("<mtr><mtd>{}</mtd></mtr>", row.join("</mtd><mtd>")))
+        .collect();
+
+    format!("<mrow><mo>[</mo><mtable>{}</mtable><mo>]</mo></mrow>", rows.concat())
+}
+
+fn render_equation(equation: &Equation) -> String {
+    format!("<mrow>{}<mo>=</mo>{}</mrow>", render_linear(&equation.left), render_linear(&equation.right))
+}
+
+fn render_linear(terms: &[Term]) -> String {
+    let terms: Vec<String> = terms.iter().enumerate()
+        .map(|(index, term)| {
+            let sign = match (index, term.coefficient.numerator < 0) {
+                (_, true) => "<mo>&#x2212;</mo>",
+                (0, false) => "",
+                (_, false) => "<mo>+</mo>"
+            };
+            let coefficient = term.coefficient.abs();
+            let term = match &term.unknown {
+                Some(unknown) if coefficient == Fraction::from(1) => format!("<mi>{}</mi>", unknown),
+                Some(unknown) => format!(
+                    "{}<mo>&#x2062;</mo><mi>{}</mi>", render_number(&NumberParts::from_result(&coefficient)), unknown
+                ),
+                None => render_number(&NumberParts::from_result(&coefficient))
+            };
+            format!("{}{}", sign, term)
+        })
+        .collect();
+
+    terms.concat()
+}
+
+/// Operations used as operands get parentheses, negative numbers only when they're right operands
+fn render_operand(operand: &Expression, is_right: bool) -> String {
+    match operand {
+        Expression::Operation { .. } => format!("<mrow><mo>(</mo>{}<mo>)</mo></mrow>", render_expression(operand)),
+        Expression::Number { value, .. } | Expression::Imaginary { value, .. } | Expression::Percent { value, .. }
+            if is_right && value.numerator < 0 =>
+            format!("<mrow><mo>(</mo>{}<mo>)</mo></mrow>", render_expression(operand)),
+        _ => render_expression(operand)
+    }
+}
+
+fn render_percent(number: &NumberParts) -> String {
+    format!("<mrow>{}<mo>%</mo></mrow>", render_number(number))
+}
+
+fn render_quantity(amount: &NumberParts, unit: &str) -> String {
+    format!("<mrow>{}<mspace width=\"0.2em\"/><mi mathvariant=\"normal\">{}</mi></mrow>", render_number(amount), unit)
+}
+
+fn render_number(number: &NumberParts) -> String {
+    let sign = if number.negative { "<mo>&#x2212;</mo>" } else { "" };
+    let whole = number.whole.map_or(String::new(), |whole| format!("<mn>{}</mn>", whole));
+    let fraction = number.fraction.map_or(String::new(), |(numerator, denominator)| {
+        format!("<mfrac><mn>{}</mn><mn>{}</mn></mfrac>", numerator, denominator)
+    });
+
+    format!("<mrow>{}{}{}</mrow>", sign, whole, fraction)
+}
+
+fn render_terms(terms: &[i32]) -> String {
+    let terms: Vec<String> = terms.iter()
+        .map(|&term| render_number(&NumberParts::from_result(&Fraction::from(term))))
+        .collect();
+
+    match terms.split_first() {
+        Some((first, rest)) if !rest.is_empty() =>
+            format!("<mrow><mo>[</mo>{}<mo>;</mo>{}<mo>]</mo></mrow>", first, rest.join("<mo>,</mo>")),
+        _ => format!("<mrow><mo>[</mo>{}<mo>]</mo></mrow>", terms.concat())
+    }
+}
+
+fn render_factors(factors: &[(i32, u32)]) -> String {
+    let factors: Vec<String> = factors.iter()
+        .map(|&(prime, exponent)| match (prime, exponent) {
+            (-1, _) => "<mrow><mo>&#x2212;</mo><mn>1</mn></mrow>".to_string(),
+            (_, 1) => format!("<mn>{}</mn>", prime),
+            _ => format!("<msup><mn>{}</mn><mn>{}</mn></msup>", prime, exponent)
+        })
+        .collect();
+
+    if factors.is_empty() {
+        "<mn>1</mn>".to_string()
+    } else {
+        format!("<mrow>{}</mrow>", factors.join("<mo>&#x22C5;</mo>"))
+    }
+}
+
+fn render_operator(operator: Operator) -> &'static str {
+    match operator {
+        Operator::Substract => "&#x2212;",
+        Operator::Multiply => "&#xD7;",
+        Operator::Divide => "&#xF7;",
+        Operator::Less => "&lt;",
+        Operator::LessOrEqual => "&#x2264;",
+        Operator::Greater => "&gt;",
+        Operator::GreaterOrEqual => "&#x2265;",
+        Operator::Equal => "=",
+        Operator::NotEqual => "&#x2260;",
+        Operator::Add => "+",
+        Operator::Of => "of"
+    }
+}
@@ -0,0 +1,125 @@
+use std::str::FromStr;
+use crate::math;
+use crate::mixed_number;
+use crate::music;
+use crate::fraction::Fraction;
+use crate::expression::Statement;
+use crate::value::Value;
+
+mod latex;
+mod mathml;
+mod pretty;
+
+/// Supported output formats for evaluation results
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Format {
+    /// The classic `= 1_7/8` output
+    #[default]
+    Plain,
+    /// The whole equation rendered as LaTeX
+    Latex,
+    /// The whole equation rendered as Presentation MathML
+    MathML,
+    /// The result drawn as a stacked fraction using box-drawing characters
+    Pretty,
+    /// The result drawn as a stacked fraction using ASCII characters only
+    Ascii
+}
+
+impl FromStr for Format {
+    type Err = &'static str;
+
+    fn from_str(format: &str) -> Result<Format, &'static str> {
+        match format {
+            "plain" => Ok(Format::Plain),
+            "latex" => Ok(Format::Latex),
+            "mathml" => Ok(Format::MathML),
+            "pretty" => Ok(Format::Pretty),
+            "ascii" => Ok(Format::Ascii),
+            _ => Err("Unsupported format!")
+        }
+    }
+}
+
+/// Formats the evaluation of a statement with the given format
+pub fn format_evaluation(format: Format, statement: &Statement, value: &Value) -> String {
+    match (format, value) {
+        (Format::Latex, _) => latex::render(statement, value),
+        (Format::MathML, _) => mathml::render(statement, value),
+        (Format::Pretty, Value::Number(result)) => pretty::render(result, '─'),
+        (Format::Ascii, Value::Number(result)) => pretty::render(result, '-'),
+        (Format::Pretty, Value::Matrix(matrix)) => pretty::render_matrix(matrix, ['⎡', '⎢', '⎣'], ['⎤', '⎥', '⎦']),
+        (_, Value::Matrix(matrix)) => pretty::render_matrix(matrix, ['['; 3], [']'; 3]),
+        // Solutions already read as equations, e.g. `x = 7/8, y = 5/8`
+        (_, Value::Solution { .. }) => value.to_string(),
+        _ => format!("= {}", value)
+    }
+}
+
+/// The pieces a number is rendered with, e.g. `-2_3/8` is negative, whole `2` & fraction `3/8`.
+/// Renderers only need to lay these pieces out.
+#[derive(Debug, PartialEq)]
+struct NumberParts {
+    negative: bool,
+    whole: Option<i32>,
+    fraction: Option<(i32, i32)>
+}
+
+impl NumberParts {
+
+    /// Builds the parts of a number literal keeping the shape it was typed with, so `9/8` isn't shown as `1_1/8`
+    fn from_literal(text: &str, operand: &Fraction) -> NumberParts {
+        if let Some((whole, numerator, denominator)) = mixed_number::split_mixed_number(text) {
+            NumberParts {
+                negative: whole < 0,
+                whole: Some(math::abs(whole)),
+                fraction: Some((numerator, denominator))
+            }
+        } else if operand.is_whole() {
+            NumberParts {
+                negative: operand.numerator < 0,
+                whole: Some(math::abs(operand.numerator / operand.denominator)),
+                fraction: None
+            }
+        } else {
+            NumberParts {
+                negative: operand.numerator < 0,
+                whole: None,
+                fraction: Some((math::abs(operand.numerator), operand.denominator))
+            }
+        }
+    }
+
+    /// Builds the parts of a dotted note as written, along with its dots, e.g. `1/4.` is `1/4` with a dot
+    fn from_dotted(text: &str) -> (NumberParts, u32) {
+        let (note, dots) = music::split_dots(text);
+        let value = mixed_number::parse_as_fraction(note).unwrap_or_default();
+
+        (NumberParts::from_literal(note, &value), dots)
+    }
+
+    /// Builds the parts of a result the same way `Fraction` is displayed: whole, proper or mixed
+    fn from_result(result: &Fraction) -> NumberParts {
+        let numerator = math::abs(result.numerator);
+        let whole = numerator / result.denominator;
+        let remainder = numerator % result.denominator;
+
+        NumberParts {
+            negative: result.numerator < 0,
+            whole: if whole != 0 || remainder == 0 { Some(whole) } else { None },
+            fraction: if remainder != 0 { Some((remainder, result.denominator)) } else { None }
+        }
+    }
+
+    /// Builds the parts of a fraction rewritten over a multiple of its denominator, e.g. `3/8` over `24` is `9/24`
+    fn over_denominator(fraction: &Fraction, denominator: i32) -> NumberParts {
+        NumberParts {
+            negative: fraction.numerator < 0,
+            whole: None,
+            fraction: Some((math::abs(fraction.numerator) * (denominator / fraction.denominator), denominator))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,78 @@
+use std::cmp;
+use crate::fraction::Fraction;
+use crate::matrix::Matrix;
+use super::NumberParts;
+
+/// Renders a result as a stacked fraction drawn over 3 lines, with the whole part on the middle line:
+///
+/// ```text
+///     1
+/// = 3 ─
+///     2
+/// ```
+///
+/// Whole numbers need no bar, so they are rendered in a single line.
+pub fn render(result: &Fraction, bar: char) -> String {
+    let number = NumberParts::from_result(result);
+    let sign = if number.negative { "-" } else { "" };
+
+    match number.fraction {
+        None => format!("= {}{}", sign, number.whole.unwrap_or(0)),
+        Some((numerator, denominator)) => {
+            let whole = number.whole.map_or(String::new(), |whole| format!("{} ", whole));
+            let middle_line = format!("= {}{}", sign, whole);
+            let indent = " ".repeat(middle_line.chars().count());
+            let numerator = numerator.to_string();
+            let denominator = denominator.to_string();
+            let width = cmp::max(numerator.len(), denominator.len());
+
+            format!(
+                "{}{}\n{}{}\n{}{}",
+                indent, center(&numerator, width),
+                middle_line, bar.to_string().repeat(width),
+                indent, center(&denominator, width)
+            )
+        }
+    }
+}
+
+fn center(text: &str, width: usize) -> String {
+    format!("{}{}", " ".repeat((width - text.len()) / 2), text)
+}
+
+/// Renders a matrix with its entries right-aligned in columns, e.g. with `[` & `]` as brackets:
+///
+/// ```text
+/// = [   1/2  1/3 ]
+///   [ 1_1/4  1/5 ]
+/// ```
+///
+/// Brackets are given for the top, middle & bottom rows, so they can be drawn as a single tall bracket.
+/// A single row gets the middle ones.
+pub fn render_matrix(matrix: &Matrix, opening: [char; 3], closing: [char; 3]) -> String {
+    let rows: Vec<Vec<String>> = matrix.rows().iter()
+        .map(|row| row.iter().map(|entry| entry.to_string()).collect())
+        .collect();
+    let widths: Vec<usize> = (0..matrix.column_count())
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+        .collect();
+    let last = rows.len() - 1;
+
+    let lines: Vec<String> = rows.iter().enumerate()
+        .map(|(index, row)| {
+            let bracket = match index {
+                _ if last == 0 => 1,
+                0 => 0,
+                _ if index == last => 2,
+                _ => 1
+            };
+            let entries: Vec<String> = row.iter().zip(&widths)
+                .map(|(entry, width)| format!("{:>width$}", entry, width = width))
+                .collect();
+            let prefix = if index == last / 2 { "= " } else { "  " };
+            format!("{}{} {} {}", prefix, opening[bracket], entries.join("  "), closing[bracket])
+        })
+        .collect();
+
+    lines.join("\n")
+}
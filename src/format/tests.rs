@@ -0,0 +1,171 @@
+use spectral::prelude::*;
+use super::*;
+use crate::session::Session;
+
+// This function will help test formatting in tests below
+fn test_format_evaluation(format: Format, format_test_cases: &[(&str, &str)]) {
+    for (input, expected_output) in format_test_cases {
+        println!("Formatting expression {}", input);
+        let (statement, value) = Session::new().evaluate(input).unwrap();
+
+        let actual_output = format_evaluation(format, &statement, &value);
+
+        assert_that!(actual_output.as_str())
+            .is_equal_to(expected_output);
+    }
+}
+
+#[test]
+fn parse_supported_formats() {
+    assert_that!(&"plain".parse::<Format>()).is_equal_to(Ok(Format::Plain));
+    assert_that!(&"latex".parse::<Format>()).is_equal_to(Ok(Format::Latex));
+    assert_that!(&"mathml".parse::<Format>()).is_equal_to(Ok(Format::MathML));
+    assert_that!(&"pretty".parse::<Format>()).is_equal_to(Ok(Format::Pretty));
+    assert_that!(&"ascii".parse::<Format>()).is_equal_to(Ok(Format::Ascii));
+}
+
+#[test]
+#[should_panic(expected = "Unsupported format!")]
+fn parse_unsupported_format() {
+    "html".parse::<Format>().unwrap();
+}
+
+#[test]
+fn format_as_plain() {
+    test_format_evaluation(Format::Plain, &[
+        ("1/2 * 3_3/4", "= 1_7/8"),
+        ("factor(360)", "= 2^3 * 3^2 * 5"),
+        ("lcd(1/6, -3/8)", "= 24 (1/6 = 4/24, -3/8 = -9/24)"),
+        ("cf(415/93)", "= [4; 2, 6, 7]"),
+        ("convergents([4; 2, 6, 7])", "= [4, 4_1/2, 4_6/13, 4_43/93]"),
+        ("mode([1/2, 3/4, 1/2, 3/4])", "= [1/2, 3/4]"),
+        ("1_1/2 : 2", "= 3 : 4"),
+        ("3/4 : 6 = x : 10", "x = 1_1/4"),
+        ("solve { x + y = 3/2; x - y = 1/4 }", "x = 7/8, y = 5/8"),
+        ("[[1/2, 1/3], [1_1/4, -1/5]]", "= [   1/2   1/3 ]\n  [ 1_1/4  -1/5 ]"),
+        ("poly(x^2 + 1) / poly(2x)", "= 1/2 x remainder 1"),
+        ("3/4 in * 2", "= 1_1/2 in"),
+        ("egyptian(5/121)", "= 1/25 + 1/757 + 1/763309 + 1/873960180913 + 1/1527612795642093418846225")
+    ]);
+}
+
+#[test]
+fn format_as_latex() {
+    test_format_evaluation(Format::Latex, &[
+        ("2_3/8 + 9/8", "2\\tfrac{3}{8} + \\frac{9}{8} = 3\\tfrac{1}{2}"),
+        ("1_3/7 - 5_2/4", "1\\tfrac{3}{7} - 5\\tfrac{2}{4} = -4\\tfrac{1}{14}"),
+        ("-1/2 * -3", "-\\frac{1}{2} \\times \\left(-3\\right) = 1\\tfrac{1}{2}"),
+        ("7 / -3_1/4", "7 \\div \\left(-3\\tfrac{1}{4}\\right) = -2\\tfrac{2}{13}"),
+        ("1/3 - 1/3", "\\frac{1}{3} - \\frac{1}{3} = 0"),
+        ("(1/2 + 1/4) * max(1, 3/2)", "\\left(\\frac{1}{2} + \\frac{1}{4}\\right) \\times \\operatorname{max}\\left(1, \\frac{3}{2}\\right) = 1\\tfrac{1}{8}"),
+        ("5_3/8 >= 43/8", "5\\tfrac{3}{8} \\ge \\frac{43}{8} \\text{ is true}"),
+        ("factor(-360)", "\\operatorname{factor}\\left(-360\\right) = -1 \\cdot 2^{3} \\cdot 3^{2} \\cdot 5"),
+        ("lcd(1/6, 1_3/8)", "\\operatorname{lcd}\\left(\\frac{1}{6}, 1\\tfrac{3}{8}\\right) = 24 \\quad \\left(\\frac{1}{6} = \\frac{4}{24}, 1\\tfrac{3}{8} = \\frac{33}{24}\\right)"),
+        ("cf(9/2)", "\\operatorname{cf}\\left(\\frac{9}{2}\\right) = \\left[4; 2\\right]"),
+        ("convergents([4; 2])", "\\operatorname{convergents}\\left(\\left[4; 2\\right]\\right) = \\left[4, 4\\tfrac{1}{2}\\right]"),
+        ("egyptian_short(4/13)", "\\operatorname{egyptian\\_short}\\left(\\frac{4}{13}\\right) = \\frac{1}{4} + \\frac{1}{26} + \\frac{1}{52}"),
+        ("1_1/2 : 2", "1\\tfrac{1}{2} : 2 = 3 : 4"),
+        ("3/4 : 6 = x : 10", "\\frac{3}{4} : 6 = x : 10 \\Rightarrow x = 1\\tfrac{1}{4}"),
+        ("solve { 2x + y = 3/2; x - y = 0 }", "\\begin{cases} 2 x + y = 1\\tfrac{1}{2} \\\\ x - y = 0 \\end{cases} \\Rightarrow x = \\frac{1}{2}, y = \\frac{1}{2}"),
+        ("det([[1, 2], [3, 4]])", "\\operatorname{det}\\left(\\begin{bmatrix} 1 & 2 \\\\ 3 & 4 \\end{bmatrix}\\right) = -2"),
+        ("deriv(poly(1/2 x^3 - x))", "\\operatorname{deriv}\\left(\\left(\\frac{1}{2} x^{3} - x\\right)\\right) = 1\\tfrac{1}{2} x^{2} - 1"),
+        ("poly(x^2 + 1) / poly(x - 1)", "\\left(x^{2} + 1\\right) \\div \\left(x - 1\\right) = x + 1 \\text{ remainder } 2"),
+        ("1_1/2 ft to in", "1\\tfrac{1}{2}\\,\\text{ft} \\rightarrow \\text{in} = 18\\,\\text{in}"),
+        ("1:23:45 + 2:50:30", "\\text{1:23:45} + \\text{2:50:30} = \\text{4:14:15}"),
+        ("bar(7/8, 1/4., 1/4)", "\\operatorname{bar}\\left(\\frac{7}{8}, \\frac{1}{4}., \\frac{1}{4}\\right) \\text{ is underfull by } \\frac{1}{4}"),
+        ("mean([1/2, 1_1/2])", "\\operatorname{mean}\\left(\\left[\\frac{1}{2}, 1\\tfrac{1}{2}\\right]\\right) = 1"),
+        ("P(not 2d6 = 7)", "P\\left(\\lnot 2\\text{d}6 = 7\\right) = \\frac{5}{6} \\quad \\left(\\text{odds } 5 : 1\\right)"),
+        ("2_1/2 ± 1/64 + 3/4 +/- 1/32", "2\\tfrac{1}{2} \\pm \\frac{1}{64} + \\frac{3}{4} \\pm \\frac{1}{32} = 3\\tfrac{1}{4} \\pm \\frac{3}{64} = \\left[3\\tfrac{13}{64}, 3\\tfrac{19}{64}\\right]"),
+        ("1 - -3/4i", "1 - \\left(-\\frac{3}{4}i\\right) = 1 + \\frac{3}{4}i"),
+        ("15% of 2_1/2", "15\\% \\text{ of } 2\\tfrac{1}{2} = \\frac{3}{8}"),
+        ("3/8 as %", "\\frac{3}{8} \\rightarrow \\% = 37\\tfrac{1}{2}\\%"),
+        ("$129.99 * 2/3 rounded half_up", "\\left(\\$129.99 \\times \\frac{2}{3}\\right) \\text{ rounded half\\_up} = \\$86.66"),
+        ("x = 1/2", "x = \\frac{1}{2} = \\frac{1}{2}"),
+        ("total_cost = 1/2 + 1/4", "total\\_cost = \\frac{1}{2} + \\frac{1}{4} = \\frac{3}{4}")
+    ]);
+}
+
+#[test]
+fn format_as_mathml() {
+    test_format_evaluation(Format::MathML, &[
+        ("2_3/8 + 9/8", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mn>2</mn><mfrac><mn>3</mn><mn>8</mn></mfrac></mrow><mo>+</mo>\
+            <mrow><mfrac><mn>9</mn><mn>8</mn></mfrac></mrow><mo>=</mo>\
+            <mrow><mn>3</mn><mfrac><mn>1</mn><mn>2</mn></mfrac></mrow></math>"),
+        ("1/4 - -2", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mfrac><mn>1</mn><mn>4</mn></mfrac></mrow><mo>&#x2212;</mo>\
+            <mrow><mo>(</mo><mrow><mo>&#x2212;</mo><mn>2</mn></mrow><mo>)</mo></mrow><mo>=</mo>\
+            <mrow><mn>2</mn><mfrac><mn>1</mn><mn>4</mn></mfrac></mrow></math>"),
+        ("min(1, 2) != 1", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mi>min</mi><mo>(</mo><mrow><mn>1</mn></mrow><mo>,</mo><mrow><mn>2</mn></mrow><mo>)</mo></mrow>\
+            <mo>&#x2260;</mo><mrow><mn>1</mn></mrow><mtext>&#xA0;is false</mtext></math>"),
+        ("factor(12)", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mi>factor</mi><mo>(</mo><mrow><mn>12</mn></mrow><mo>)</mo></mrow><mo>=</mo>\
+            <mrow><msup><mn>2</mn><mn>2</mn></msup><mo>&#x22C5;</mo><mn>3</mn></mrow></math>"),
+        ("lcd(1/2, 1/3)", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mi>lcd</mi><mo>(</mo><mrow><mfrac><mn>1</mn><mn>2</mn></mfrac></mrow><mo>,</mo>\
+            <mrow><mfrac><mn>1</mn><mn>3</mn></mfrac></mrow><mo>)</mo></mrow><mo>=</mo><mn>6</mn><mspace width=\"1em\"/>\
+            <mrow><mo>(</mo><mrow><mfrac><mn>1</mn><mn>2</mn></mfrac></mrow><mo>=</mo><mrow><mfrac><mn>3</mn><mn>6</mn></mfrac></mrow>\
+            <mo>,</mo><mrow><mfrac><mn>1</mn><mn>3</mn></mfrac></mrow><mo>=</mo><mrow><mfrac><mn>2</mn><mn>6</mn></mfrac></mrow>\
+            <mo>)</mo></mrow></math>"),
+        ("cf(-9/2)", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mi>cf</mi><mo>(</mo><mrow><mo>&#x2212;</mo><mfrac><mn>9</mn><mn>2</mn></mfrac></mrow><mo>)</mo></mrow>\
+            <mo>=</mo><mrow><mo>[</mo><mrow><mo>&#x2212;</mo><mn>5</mn></mrow><mo>;</mo><mrow><mn>2</mn></mrow><mo>]</mo></mrow></math>"),
+        ("1 : 2 = x : 4", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mrow><mn>1</mn></mrow><mo>:</mo><mrow><mn>2</mn></mrow></mrow><mo>=</mo>\
+            <mrow><mi>x</mi><mo>:</mo><mrow><mn>4</mn></mrow></mrow><mo>&#x21D2;</mo><mi>x</mi><mo>=</mo>\
+            <mrow><mn>2</mn></mrow></math>"),
+        ("transpose([[1, 2]])", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mi>transpose</mi><mo>(</mo><mrow><mo>[</mo><mtable><mtr><mtd><mrow><mn>1</mn></mrow></mtd>\
+            <mtd><mrow><mn>2</mn></mrow></mtd></mtr></mtable><mo>]</mo></mrow><mo>)</mo></mrow><mo>=</mo>\
+            <mrow><mo>[</mo><mtable><mtr><mtd><mrow><mn>1</mn></mrow></mtd></mtr><mtr><mtd><mrow><mn>2</mn></mrow></mtd></mtr>\
+            </mtable><mo>]</mo></mrow></math>"),
+        ("solve 2x - 1 = 0", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mrow><mn>2</mn></mrow><mo>&#x2062;</mo><mi>x</mi><mo>&#x2212;</mo><mrow><mn>1</mn></mrow>\
+            <mo>=</mo><mrow><mn>0</mn></mrow></mrow><mo>&#x21D2;</mo><mi>x</mi><mo>=</mo>\
+            <mrow><mfrac><mn>1</mn><mn>2</mn></mfrac></mrow></math>"),
+        ("poly(-x^2) + 1", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mo>(</mo><mrow><mo>&#x2212;</mo><msup><mi>x</mi><mn>2</mn></msup></mrow><mo>)</mo></mrow>\
+            <mo>+</mo><mrow><mn>1</mn></mrow><mo>=</mo><mrow><mo>&#x2212;</mo><msup><mi>x</mi><mn>2</mn></msup>\
+            <mo>+</mo><mrow><mn>1</mn></mrow></mrow></math>"),
+        ("0:00:10 * 3/4", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mn>0:00:10</mn><mo>&#xD7;</mo><mrow><mfrac><mn>3</mn><mn>4</mn></mfrac></mrow><mo>=</mo>\
+            <mn>0:00:07.5</mn></math>"),
+        ("bar(4/4, 1/2., 1/4)", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mi>bar</mi><mo>(</mo><mfrac><mn>4</mn><mn>4</mn></mfrac><mo>,</mo>\
+            <mrow><mrow><mfrac><mn>1</mn><mn>2</mn></mfrac></mrow><mo>.</mo></mrow><mo>,</mo>\
+            <mrow><mfrac><mn>1</mn><mn>4</mn></mfrac></mrow><mo>)</mo></mrow><mtext>&#xA0;is full</mtext></math>"),
+        ("P(1d2 = 1 and 1d2 = 2)", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mi>P</mi><mo>(</mo><mrow><mn>1</mn><mi mathvariant=\"normal\">d</mi><mn>2</mn></mrow><mo>=</mo>\
+            <mrow><mn>1</mn></mrow><mo>&#x2227;</mo><mrow><mn>1</mn><mi mathvariant=\"normal\">d</mi><mn>2</mn></mrow>\
+            <mo>=</mo><mrow><mn>2</mn></mrow><mo>)</mo></mrow><mo>=</mo><mrow><mfrac><mn>1</mn><mn>4</mn></mfrac></mrow>\
+            <mspace width=\"1em\"/><mrow><mo>(</mo><mtext>odds&#xA0;</mtext><mn>1</mn><mo>:</mo><mn>3</mn><mo>)</mo></mrow></math>"),
+        ("40 + -10%", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mrow><mn>40</mn></mrow><mo>+</mo><mrow><mo>(</mo><mrow><mrow><mo>&#x2212;</mo><mn>10</mn></mrow><mo>%</mo></mrow>\
+            <mo>)</mo></mrow><mo>=</mo><mrow><mn>36</mn></mrow></math>"),
+        ("x = 1/2 + 1/4", "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+            <mi>x</mi><mo>=</mo><mrow><mfrac><mn>1</mn><mn>2</mn></mfrac></mrow><mo>+</mo>\
+            <mrow><mfrac><mn>1</mn><mn>4</mn></mfrac></mrow><mo>=</mo><mrow><mfrac><mn>3</mn><mn>4</mn></mfrac></mrow></math>")
+    ]);
+}
+
+#[test]
+fn format_as_pretty() {
+    test_format_evaluation(Format::Pretty, &[
+        ("1/2 * 3_3/4", "    7\n= 1 ─\n    8"),
+        ("1_3/7 - 5_2/4", "     1\n= -4 ──\n     14"),
+        ("1/3 - 2/3", "   1\n= -─\n   3"),
+        ("1/2 + 1/2", "= 1"),
+        ("[[1], [2], [3]] * 1/2", "  ⎡   1/2 ⎤\n= ⎢     1 ⎥\n  ⎣ 1_1/2 ⎦")
+    ]);
+}
+
+#[test]
+fn format_as_ascii() {
+    test_format_evaluation(Format::Ascii, &[
+        ("25/13 * 4", "    9\n= 7 --\n    13"),
+        ("-3 - 2", "= -5"),
+        ("1/2 < 2/3", "= true"),
+        ("[[1, 2]]", "= [ 1  2 ]")
+    ]);
+}
@@ -1,15 +1,24 @@
 use std::fmt;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use std::str::FromStr;
 use regex::Regex;
 use lazy_static::*;
 use crate::math;
+use crate::mixed_number;
 
 // This ensures the regexes are compiled only once
 lazy_static! {
     static ref FRACTION_RE: Regex = Regex::new(r"^(\-?\d+)/(\-?\d+)$").unwrap();
 }
 
-/// Models the elements of a fraction
-#[derive(Debug, PartialEq)]
+/// Models the elements of a fraction.
+/// Fractions built through the factory methods are always simplified and have a positive denominator,
+/// which makes derived equality & hashing match the value of the fraction.
+/// Their numerator & denominator are never `i32::MIN` either, so they can always be negated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Fraction {
     pub numerator: i32,
     pub denominator: i32
@@ -23,14 +32,14 @@ impl Fraction {
     }
 
     /// Parses a fraction in the form `1/2` with support for negative numbers
-    pub fn parse_fraction(fraction_exp: &str) -> Result<Fraction, &str> {
+    pub fn parse_fraction(fraction_exp: &str) -> Result<Fraction, &'static str> {
         if !Fraction::is_fraction(fraction_exp) {
             Err("Unparseable fraction!")
         } else {
             let captures = FRACTION_RE.captures(fraction_exp).unwrap();
             Fraction::new(
-                captures.get(1).unwrap().as_str().parse().unwrap(), 
-                captures.get(2).unwrap().as_str().parse().unwrap()
+                mixed_number::parse_part(captures.get(1).unwrap().as_str())?,
+                mixed_number::parse_part(captures.get(2).unwrap().as_str())?
             )
         }
     }
@@ -41,15 +50,7 @@ impl Fraction {
         if denominator == 0 {
             Err("Fraction with zero denominator!")
         } else {
-            let (signed_numerator, signed_denominator) = if numerator < 0 && denominator < 0 {
-                (math::abs(numerator), math::abs(denominator))
-            } else {
-                (numerator, denominator)
-            };
-            Ok(Fraction::simplify(&Fraction {
-                numerator: signed_numerator,
-                denominator: signed_denominator
-            }))
+            Fraction::reduce(i128::from(numerator), i128::from(denominator))
         }
     }
 
@@ -57,14 +58,12 @@ impl Fraction {
     /// The resulting `Fraction` will be the improper fraction equivalent to the mixed number.
     /// It leverages to `Fraction::new` to get simplification and sign handling.
     pub fn new_mixed(whole: i32, numerator: i32, denominator: i32) -> Result<Fraction, &'static str> {
-        let whole_abs = math::abs(whole);
-        let numerator = (whole_abs * denominator) + numerator;
-
-        if whole < 0 {
-            Fraction::new(-numerator, denominator)
-        } else {
-            Fraction::new(numerator, denominator)
+        if denominator == 0 {
+            return Err("Fraction with zero denominator!");
         }
+        let numerator = i128::from(whole).abs() * i128::from(denominator) + i128::from(numerator);
+
+        Fraction::reduce(if whole < 0 { -numerator } else { numerator }, i128::from(denominator))
     }
 
     /// Factory method that MUST be used to build a `Fraction` from a whole number.
@@ -74,55 +73,128 @@ impl Fraction {
         Fraction::new(whole, 1)
     }
 
+    /// Like the `+` operator, it panics if the result doesn't fit in an `i32`. Use `Fraction::checked_add` to get an error instead
     pub fn add(&self, fraction: &Fraction) -> Fraction {
-        let numerator = (self.numerator * fraction.denominator) + (self.denominator * fraction.numerator);
-        let denominator = self.denominator * fraction.denominator;
-
-        Fraction::new(numerator, denominator).unwrap()
+        self.checked_add(fraction).expect("Integer overflow!")
     }
 
+    /// Like the `-` operator, it panics if the result doesn't fit in an `i32`. Use `Fraction::checked_substract` to get an error instead
     pub fn substract(&self, fraction: &Fraction) -> Fraction {
-        let negative_fraction = Fraction::new(fraction.numerator * -1, fraction.denominator).unwrap();
-
-        self.add(&negative_fraction)
+        self.checked_substract(fraction).expect("Integer overflow!")
     }
 
+    /// Like the `*` operator, it panics if the result doesn't fit in an `i32`. Use `Fraction::checked_multiply` to get an error instead
     pub fn multiply(&self, fraction: &Fraction) -> Fraction {
-        let numerator = self.numerator * fraction.numerator;
-        let denominator = self.denominator * fraction.denominator;
+        self.checked_multiply(fraction).expect("Integer overflow!")
+    }
+
+    pub fn checked_add(&self, fraction: &Fraction) -> Result<Fraction, &'static str> {
+        let numerator = i128::from(self.numerator) * i128::from(fraction.denominator)
+            + i128::from(self.denominator) * i128::from(fraction.numerator);
+
+        Fraction::reduce(numerator, i128::from(self.denominator) * i128::from(fraction.denominator))
+    }
+
+    pub fn checked_substract(&self, fraction: &Fraction) -> Result<Fraction, &'static str> {
+        let numerator = i128::from(self.numerator) * i128::from(fraction.denominator)
+            - i128::from(self.denominator) * i128::from(fraction.numerator);
+
+        Fraction::reduce(numerator, i128::from(self.denominator) * i128::from(fraction.denominator))
+    }
 
-        Fraction::new(numerator, denominator).unwrap()
+    pub fn checked_multiply(&self, fraction: &Fraction) -> Result<Fraction, &'static str> {
+        Fraction::reduce(
+            i128::from(self.numerator) * i128::from(fraction.numerator),
+            i128::from(self.denominator) * i128::from(fraction.denominator)
+        )
     }
 
-    pub fn divide(&self, fraction: &Fraction) -> Result<Fraction, &str> {
+    pub fn divide(&self, fraction: &Fraction) -> Result<Fraction, &'static str> {
         if fraction.is_zero() {
             Err("Division by zero!")
         } else {
-            let numerator = self.numerator * fraction.denominator;
-            let denominator = self.denominator * fraction.numerator;
-
-            Fraction::new(numerator, denominator)
+            Fraction::reduce(
+                i128::from(self.numerator) * i128::from(fraction.denominator),
+                i128::from(self.denominator) * i128::from(fraction.numerator)
+            )
         }
     }
 
-    fn simplify(fraction: &Fraction) -> Fraction {
-        let gcd = math::gcd(math::abs(fraction.numerator), math::abs(fraction.denominator));
+    /// Computes the remainder of the truncated division, so the result has the sign of `self`
+    /// like the `%` operator on integers, e.g. `7/2 % 1 = 1/2` and `-7/2 % 1 = -1/2`
+    pub fn remainder(&self, fraction: &Fraction) -> Result<Fraction, &'static str> {
+        let quotient = self.divide(fraction)?;
+        let truncated_quotient = Fraction::new_whole(quotient.numerator / quotient.denominator)?;
+
+        self.checked_substract(&fraction.checked_multiply(&truncated_quotient)?)
+    }
+
+    /// Computes the absolute value, e.g. `|-3/4| = 3/4`
+    pub fn abs(&self) -> Fraction {
+        Fraction { numerator: math::abs(self.numerator), denominator: self.denominator }
+    }
+
+    /// Computes `1/self`, e.g. the reciprocal of `2_1/2` is `2/5`
+    pub fn recip(&self) -> Result<Fraction, &'static str> {
+        Fraction::from(1).divide(self)
+    }
 
-        Fraction {
-            numerator: fraction.numerator / gcd,
-            denominator: fraction.denominator / gcd
+    /// Returns `-1`, `0` or `1` depending on the sign of the fraction
+    pub fn signum(&self) -> Fraction {
+        Fraction::from(self.numerator.signum())
+    }
+
+    /// Returns the largest whole number less than or equal to the fraction, e.g. `floor(-2_1/4) = -3`
+    pub fn floor(&self) -> Fraction {
+        Fraction::from(self.numerator.div_euclid(self.denominator))
+    }
+
+    /// Returns the smallest whole number greater than or equal to the fraction, e.g. `ceil(-2_1/4) = -2`
+    pub fn ceil(&self) -> Fraction {
+        -(-self).floor()
+    }
+
+    /// Returns the nearest whole number, rounding halves away from zero like `f64::round`
+    pub fn round(&self) -> Fraction {
+        let (whole, part) = (self.trunc(), self.fract());
+
+        // Compares the part against its distance to the next whole, as doubling it may overflow
+        if math::abs(part.numerator) >= part.denominator - math::abs(part.numerator) {
+            whole + self.signum()
+        } else {
+            whole
         }
     }
 
-    fn is_proper(&self) -> bool {
+    /// Returns the whole part of the fraction, i.e. the whole number of its mixed number form
+    pub fn trunc(&self) -> Fraction {
+        Fraction::from(self.numerator / self.denominator)
+    }
+
+    /// Returns the fractional part, which has the sign of the fraction, e.g. `fract(-2_1/4) = -1/4`
+    pub fn fract(&self) -> Fraction {
+        Fraction { numerator: self.numerator % self.denominator, denominator: self.denominator }
+    }
+
+    /// Builds a simplified fraction from wide numbers, e.g. the result of an operation, failing if it doesn't fit in an `i32`.
+    /// The denominator must not be zero
+    pub(crate) fn reduce(numerator: i128, denominator: i128) -> Result<Fraction, &'static str> {
+        let a = math::wide_gcd(numerator, denominator);
+        let sign = denominator.signum();
+        let part = |value: i128| i32::try_from(value).ok().filter(|&value| value != i32::MIN).ok_or("Integer overflow!");
+
+        Ok(Fraction { numerator: part(sign * numerator / a)?, denominator: part(sign * denominator / a)? })
+    }
+
+    pub(crate) fn is_proper(&self) -> bool {
         math::abs(self.numerator) < math::abs(self.denominator)
     }
 
-    fn is_whole(&self) -> bool {
+    pub(crate) fn is_whole(&self) -> bool {
         (self.numerator % self.denominator) == 0
     }
 
-    fn is_zero(&self) -> bool {
+    pub(crate) fn is_zero(&self) -> bool {
         self.numerator == 0
     }
 }
@@ -146,5 +218,152 @@ impl fmt::Display for Fraction {
     }
 }
 
+/// The default fraction is zero, i.e. `0/1`
+impl Default for Fraction {
+    fn default() -> Fraction {
+        Fraction::from(0)
+    }
+}
+
+/// Like the arithmetic operators, it panics on `i32::MIN`, which can't be negated.
+/// Use `Fraction::new_whole` to get an error instead
+impl From<i32> for Fraction {
+    fn from(whole: i32) -> Fraction {
+        assert!(whole != i32::MIN, "Integer overflow!");
+        Fraction { numerator: whole, denominator: 1 }
+    }
+}
+
+/// Parses any of the forms supported by `mixed_number::parse_as_fraction`, e.g. `2_3/8`, `19/8` or `2`
+impl FromStr for Fraction {
+    type Err = &'static str;
+
+    fn from_str(mixed_number_exp: &str) -> Result<Fraction, &'static str> {
+        mixed_number::parse_as_fraction(mixed_number_exp)
+    }
+}
+
+/// Fractions are ordered by value, comparing cross products that can't overflow
+impl Ord for Fraction {
+    fn cmp(&self, fraction: &Fraction) -> Ordering {
+        let left = i64::from(self.numerator) * i64::from(fraction.denominator);
+        let right = i64::from(fraction.numerator) * i64::from(self.denominator);
+
+        left.cmp(&right)
+    }
+}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, fraction: &Fraction) -> Option<Ordering> {
+        Some(self.cmp(fraction))
+    }
+}
+
+impl Neg for Fraction {
+    type Output = Fraction;
+
+    fn neg(self) -> Fraction {
+        Fraction { numerator: -self.numerator, denominator: self.denominator }
+    }
+}
+
+impl Neg for &Fraction {
+    type Output = Fraction;
+
+    fn neg(self) -> Fraction {
+        -*self
+    }
+}
+
+// Implements a binary operator & its assignment variant for every combination of owned & borrowed operands,
+// all of them delegating to the given arithmetic method
+macro_rules! impl_binary_operator {
+    ($operator:ident, $method:ident, $assign_operator:ident, $assign_method:ident, $compute:expr) => {
+        impl $operator<&Fraction> for &Fraction {
+            type Output = Fraction;
+
+            fn $method(self, fraction: &Fraction) -> Fraction {
+                $compute(self, fraction)
+            }
+        }
+
+        impl $operator<Fraction> for &Fraction {
+            type Output = Fraction;
+
+            fn $method(self, fraction: Fraction) -> Fraction {
+                $compute(self, &fraction)
+            }
+        }
+
+        impl $operator<&Fraction> for Fraction {
+            type Output = Fraction;
+
+            fn $method(self, fraction: &Fraction) -> Fraction {
+                $compute(&self, fraction)
+            }
+        }
+
+        impl $operator<Fraction> for Fraction {
+            type Output = Fraction;
+
+            fn $method(self, fraction: Fraction) -> Fraction {
+                $compute(&self, &fraction)
+            }
+        }
+
+        impl $assign_operator<&Fraction> for Fraction {
+            fn $assign_method(&mut self, fraction: &Fraction) {
+                *self = $compute(self, fraction);
+            }
+        }
+
+        impl $assign_operator<Fraction> for Fraction {
+            fn $assign_method(&mut self, fraction: Fraction) {
+                *self = $compute(self, &fraction);
+            }
+        }
+    };
+}
+
+// Like integer operators, `/` & `%` panic on a zero divisor & every operator panics on overflow, with the message
+// of the error. Use `Fraction::divide` or the `checked_*` methods to get an error instead
+impl_binary_operator!(Add, add, AddAssign, add_assign, |x: &Fraction, y: &Fraction| Fraction::add(x, y));
+impl_binary_operator!(Sub, sub, SubAssign, sub_assign, |x: &Fraction, y: &Fraction| x.substract(y));
+impl_binary_operator!(Mul, mul, MulAssign, mul_assign, |x: &Fraction, y: &Fraction| x.multiply(y));
+impl_binary_operator!(Div, div, DivAssign, div_assign, |x: &Fraction, y: &Fraction| x.divide(y).unwrap_or_else(|error| panic!("{}", error)));
+impl_binary_operator!(Rem, rem, RemAssign, rem_assign, |x: &Fraction, y: &Fraction| x.remainder(y).unwrap_or_else(|error| panic!("{}", error)));
+
+impl Sum for Fraction {
+    fn sum<I: Iterator<Item = Fraction>>(fractions: I) -> Fraction {
+        fractions.fold(Fraction::from(0), |sum, fraction| sum + fraction)
+    }
+}
+
+impl<'a> Sum<&'a Fraction> for Fraction {
+    fn sum<I: Iterator<Item = &'a Fraction>>(fractions: I) -> Fraction {
+        fractions.fold(Fraction::from(0), |sum, fraction| sum + fraction)
+    }
+}
+
+impl Product for Fraction {
+    fn product<I: Iterator<Item = Fraction>>(fractions: I) -> Fraction {
+        fractions.fold(Fraction::from(1), |product, fraction| product * fraction)
+    }
+}
+
+impl<'a> Product<&'a Fraction> for Fraction {
+    fn product<I: Iterator<Item = &'a Fraction>>(fractions: I) -> Fraction {
+        fractions.fold(Fraction::from(1), |product, fraction| product * fraction)
+    }
+}
+
+/// Parses a fraction written in any form `FromStr` supports, so tests can write their fractions as text
+#[cfg(test)]
+pub(crate) fn fraction(text: &str) -> Fraction {
+    text.parse().unwrap()
+}
+
+// These tests compare against borrowed expected values, which spectral accepts as well
 #[cfg(test)]
+#[allow(clippy::needless_borrows_for_generic_args)]
 mod tests;
@@ -109,6 +109,19 @@ fn divide_fractions() {
         .is_equal_to(&expected);
 }
 
+#[test]
+fn checked_arithmetic_on_overflow() {
+    let x = Fraction::new(100000, 3).unwrap();
+    let y = Fraction::new(100000, 7).unwrap();
+
+    assert_that!(&x.checked_multiply(&y)).is_equal_to(&Err("Integer overflow!"));
+    assert_that!(&x.divide(&y.recip().unwrap())).is_equal_to(&Err("Integer overflow!"));
+    assert_that!(&Fraction::from(i32::MAX).checked_add(&Fraction::from(1))).is_equal_to(&Err("Integer overflow!"));
+    assert_that!(&Fraction::from(-i32::MAX).checked_substract(&Fraction::from(1))).is_equal_to(&Err("Integer overflow!"));
+    assert_that!(&Fraction::new(1, 6).unwrap().checked_add(&Fraction::new(-1, 3).unwrap()))
+        .is_equal_to(&Ok(Fraction::new(-1, 6).unwrap()));
+}
+
 #[test]
 #[should_panic(expected = "Division by zero!")]
 fn divide_fractions_by_zero() {
@@ -127,12 +140,30 @@ fn new_simplifies_fraction() {
         .is_equal_to(&expected);
 }
 
+#[test]
+fn new_moves_sign_to_numerator() {
+    let actual = Fraction::new(3, -12).unwrap();
+
+    assert_that!(&actual.numerator)
+        .is_equal_to(&-1);
+    assert_that!(&actual.denominator)
+        .is_equal_to(&4);
+}
+
 #[test]
 #[should_panic(expected = "Fraction with zero denominator!")]
 fn new_with_zero_denominator() {
     Fraction::new(3, 0).unwrap();
 }
 
+#[test]
+fn new_rejects_parts_that_cannot_be_negated() {
+    assert_that!(&Fraction::new(i32::MIN, 1)).is_equal_to(&Err("Integer overflow!"));
+    assert_that!(&Fraction::new(1, i32::MIN)).is_equal_to(&Err("Integer overflow!"));
+    assert_that!(&Fraction::new(i32::MIN, 2)).is_equal_to(&Fraction::new(-1073741824, 1));
+    assert_that!(&Fraction::new_mixed(-2147483647, 1, 2)).is_equal_to(&Err("Integer overflow!"));
+}
+
 #[test]
 fn display_proper_fraction() {
     let fraction = Fraction::new(1, 2).unwrap();
@@ -182,3 +213,174 @@ fn display_negative_proper_fraction() {
     assert_that!(actual.as_str())
         .is_equal_to(&"-1_2/3");
 }
+
+#[test]
+fn remainder_has_sign_of_dividend() {
+    let x = Fraction::new(-7, 2).unwrap();
+    let y = Fraction::new(1, 1).unwrap();
+
+    let actual = x.remainder(&y).unwrap();
+
+    let expected = Fraction::new(-1, 2).unwrap();
+    assert_that!(&actual)
+        .is_equal_to(&expected);
+}
+
+// This function will help test rounding in tests below, each case being the input & the expected floor, ceil, round & trunc
+fn test_rounding(rounding_test_cases: &[(&str, [i32; 4])]) {
+    for (fraction_exp, expected) in rounding_test_cases {
+        println!("Rounding fraction {}", fraction_exp);
+        let fraction: Fraction = fraction_exp.parse().unwrap();
+
+        let actual = [fraction.floor(), fraction.ceil(), fraction.round(), fraction.trunc()];
+
+        let expected = expected.map(Fraction::from);
+        assert_that!(&actual)
+            .is_equal_to(&expected);
+    }
+}
+
+#[test]
+fn round_fractions() {
+    test_rounding(&[
+        ("2_1/4", [2, 3, 2, 2]),
+        ("2_1/2", [2, 3, 3, 2]),
+        ("-2_1/4", [-3, -2, -2, -2]),
+        ("-2_1/2", [-3, -2, -3, -2]),
+        ("3", [3, 3, 3, 3]),
+        ("0", [0, 0, 0, 0]),
+        ("2147483646/2147483647", [0, 1, 1, 0]),
+        ("-1073741823/2147483647", [-1, 0, 0, 0])
+    ]);
+}
+
+#[test]
+fn whole_and_fractional_parts() {
+    let fraction: Fraction = "-2_1/4".parse().unwrap();
+
+    assert_that!(&fraction.trunc()).is_equal_to(&Fraction::from(-2));
+    assert_that!(&fraction.fract()).is_equal_to(&Fraction::new(-1, 4).unwrap());
+    assert_that!(&(fraction.trunc() + fraction.fract())).is_equal_to(&fraction);
+}
+
+#[test]
+fn abs_signum_and_recip() {
+    let fraction = Fraction::new(-5, 2).unwrap();
+
+    assert_that!(&fraction.abs()).is_equal_to(&Fraction::new(5, 2).unwrap());
+    assert_that!(&fraction.signum()).is_equal_to(&Fraction::from(-1));
+    assert_that!(&Fraction::default().signum()).is_equal_to(&Fraction::from(0));
+    assert_that!(&fraction.recip()).is_equal_to(&Ok(Fraction::new(-2, 5).unwrap()));
+    assert_that!(&Fraction::default().recip()).is_equal_to(&Err("Division by zero!"));
+}
+
+#[test]
+fn arithmetic_operators() {
+    let x: Fraction = "2_3/8".parse().unwrap();
+    let y = Fraction::new(9, 8).unwrap();
+    let (x_ref, y_ref) = (&x, &y);
+
+    assert_that!(&(x + y)).is_equal_to(&Fraction::new(7, 2).unwrap());
+    assert_that!(&(x_ref - y_ref)).is_equal_to(&Fraction::new(5, 4).unwrap());
+    assert_that!(&(x * y_ref)).is_equal_to(&Fraction::new(171, 64).unwrap());
+    assert_that!(&(x_ref / y)).is_equal_to(&Fraction::new(19, 9).unwrap());
+    assert_that!(&(x % y)).is_equal_to(&Fraction::new(1, 8).unwrap());
+    assert_that!(&-x).is_equal_to(&Fraction::new(-19, 8).unwrap());
+}
+
+#[test]
+fn assignment_operators() {
+    let mut x = Fraction::from(3);
+
+    x += Fraction::new(1, 2).unwrap();
+    x -= &Fraction::new(1, 4).unwrap();
+    x *= Fraction::from(4);
+    x /= Fraction::new(2, 3).unwrap();
+    x %= Fraction::from(5);
+
+    assert_that!(&x)
+        .is_equal_to(&Fraction::new(9, 2).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Division by zero!")]
+fn divide_operator_by_zero() {
+    let _ = Fraction::from(1) / Fraction::default();
+}
+
+#[test]
+fn order_fractions_by_value() {
+    let mut fractions: Vec<Fraction> = ["5_3/8", "-1/2", "43/8", "0", "5_1/4"].iter()
+        .map(|exp| exp.parse().unwrap())
+        .collect();
+
+    fractions.sort();
+
+    let expected: Vec<Fraction> = ["-1/2", "0", "5_1/4", "43/8", "5_3/8"].iter()
+        .map(|exp| exp.parse().unwrap())
+        .collect();
+    assert_that!(&fractions)
+        .is_equal_to(&expected);
+    assert_that!(&(Fraction::new(43, 8).unwrap() < Fraction::new(-1, -2).unwrap()))
+        .is_false();
+}
+
+#[test]
+fn equal_fractions_hash_equally() {
+    use std::collections::HashSet;
+
+    let fractions: HashSet<Fraction> = vec![
+        Fraction::new(1, 2).unwrap(),
+        Fraction::new(2, 4).unwrap(),
+        Fraction::new(-3, -6).unwrap()
+    ].into_iter().collect();
+
+    assert_that!(&fractions.len())
+        .is_equal_to(&1);
+}
+
+#[test]
+fn default_and_from_whole() {
+    assert_that!(&Fraction::default()).is_equal_to(&Fraction::new(0, 1).unwrap());
+    assert_that!(&Fraction::from(-3)).is_equal_to(&Fraction::new(-3, 1).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Integer overflow!")]
+fn from_whole_rejects_a_number_that_cannot_be_negated() {
+    let _ = Fraction::from(i32::MIN);
+}
+
+#[test]
+#[should_panic(expected = "Integer overflow!")]
+fn divide_operator_on_overflow() {
+    let _ = Fraction::from(i32::MAX) / Fraction::new(1, 2).unwrap();
+}
+
+#[test]
+fn sum_and_product_of_fractions() {
+    let fractions = vec![
+        Fraction::new(1, 2).unwrap(),
+        Fraction::new(1, 3).unwrap(),
+        Fraction::new(1, 6).unwrap()
+    ];
+
+    let sum: Fraction = fractions.iter().sum();
+    let product: Fraction = fractions.into_iter().product();
+
+    assert_that!(&sum).is_equal_to(&Fraction::from(1));
+    assert_that!(&product).is_equal_to(&Fraction::new(1, 36).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Unparseable mixed number!")]
+fn from_str_with_invalid_expression() {
+    "2__1/2".parse::<Fraction>().unwrap();
+}
+
+#[test]
+fn from_str_with_too_big_numbers() {
+    for fraction_exp in &["99999999999", "1/99999999999", "99999999999_1/2", "-2147483648"] {
+        assert_that!(&fraction_exp.parse::<Fraction>()).is_equal_to(&Err("Integer overflow!"));
+    }
+}
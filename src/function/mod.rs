@@ -0,0 +1,318 @@
+use crate::fraction::Fraction;
+use crate::math;
+use crate::continued_fraction;
+use crate::egyptian;
+use crate::statistics;
+use crate::matrix::Matrix;
+use crate::polynomial::Polynomial;
+use crate::music;
+use crate::value::Value;
+use crate::complex::Complex;
+use crate::money::{self, Rounding};
+
+/// How many arguments a function accepts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Arity {
+    Exactly(usize),
+    AtLeast(usize),
+    /// Any amount of numbers, where lists are taken as their numbers, e.g. `sum([1/2, 3/4], 1)` sums 3 numbers
+    Numbers
+}
+
+impl Arity {
+
+    fn accepts(self, count: usize) -> bool {
+        match self {
+            Arity::Exactly(arity) => count == arity,
+            Arity::AtLeast(arity) => count >= arity,
+            Arity::Numbers => true
+        }
+    }
+}
+
+/// Kinds of values functions can take besides numbers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Number,
+    Matrix,
+    Polynomial,
+    Ratio,
+    Distribution,
+    /// A complex number, or a number taken as one without imaginary part
+    Complex,
+    Money
+}
+
+impl Kind {
+
+    /// Checks whether a value is of this kind
+    pub fn matches(self, value: &Value) -> bool {
+        match self {
+            Kind::Number => value.as_number().is_some(),
+            Kind::Matrix => matches!(value, Value::Matrix(_)),
+            Kind::Polynomial => matches!(value, Value::Polynomial(_)),
+            Kind::Ratio => matches!(value, Value::Ratio(_)),
+            Kind::Distribution => matches!(value, Value::Distribution(_)),
+            Kind::Complex => matches!(value, Value::Complex(_)) || value.as_number().is_some(),
+            Kind::Money => matches!(value, Value::Money(_))
+        }
+    }
+
+    /// The error for values that aren't of this kind
+    pub fn error(self) -> &'static str {
+        match self {
+            Kind::Number => "Expected a number!",
+            Kind::Matrix => "Expected a matrix!",
+            Kind::Polynomial => "Expected a polynomial!",
+            Kind::Ratio => "Expected a ratio!",
+            Kind::Distribution => "Expected a distribution!",
+            Kind::Complex => "Expected a complex number!",
+            Kind::Money => "Expected an amount of money!"
+        }
+    }
+}
+
+/// What a function computes its result from
+#[derive(Debug, Clone, Copy)]
+enum Compute {
+    Numbers(fn(&[Fraction]) -> Result<Value, &'static str>),
+    /// Values of the given kinds, e.g. a matrix for `det([[1, 2], [3, 4]])`
+    Values(&'static [Kind], fn(&[Value]) -> Result<Value, &'static str>)
+}
+
+/// Models a built-in function that expressions can call, e.g. `abs(-3/4)`
+#[derive(Debug)]
+pub struct Function {
+    pub name: &'static str,
+    pub arity: Arity,
+    compute: Compute
+}
+
+impl Function {
+
+    /// The kinds of the arguments the function takes, or `None` if it takes numbers
+    pub fn parameters(&self) -> Option<&'static [Kind]> {
+        match self.compute {
+            Compute::Numbers(_) => None,
+            Compute::Values(parameters, _) => Some(parameters)
+        }
+    }
+
+    /// Applies the function to the given arguments, checking first there are as many as it accepts
+    pub fn apply(&self, arguments: &[Fraction]) -> Result<Value, &'static str> {
+        match self.compute {
+            _ if !self.arity.accepts(arguments.len()) => Err("Wrong number of arguments!"),
+            Compute::Numbers(compute) => compute(arguments),
+            Compute::Values(parameters, _) => Err(parameters[0].error())
+        }
+    }
+
+    /// Applies the function to values of the kinds it takes, checking first they are
+    pub fn apply_to_values(&self, arguments: &[Value]) -> Result<Value, &'static str> {
+        match self.compute {
+            _ if !self.arity.accepts(arguments.len()) => Err("Wrong number of arguments!"),
+            Compute::Values(parameters, compute) => {
+                if let Some(kind) = parameters.iter().zip(arguments).find(|(kind, value)| !kind.matches(value)).map(|(kind, _)| kind) {
+                    Err(kind.error())
+                } else {
+                    compute(arguments)
+                }
+            },
+            Compute::Numbers(_) => Err("Expected a number!")
+        }
+    }
+}
+
+/// The registry of built-in functions. The tokenizer, the evaluator & the REPL completion all look names up here,
+/// so a function added to this list is available everywhere
+static FUNCTIONS: [Function; 44] = [
+    Function { name: "abs", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| number(x[0].abs())) },
+    Function { name: "recip", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| x[0].recip().map(Value::Number)) },
+    Function { name: "floor", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| number(x[0].floor())) },
+    Function { name: "ceil", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| number(x[0].ceil())) },
+    Function { name: "round", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| number(x[0].round())) },
+    Function { name: "trunc", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| number(x[0].trunc())) },
+    Function { name: "frac", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| number(x[0].fract())) },
+    Function { name: "sign", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| number(x[0].signum())) },
+    Function { name: "num", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| number(Fraction::from(x[0].numerator))) },
+    Function { name: "den", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| number(Fraction::from(x[0].denominator))) },
+    Function { name: "whole", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| number(x[0].trunc())) },
+    Function { name: "min", arity: Arity::Numbers, compute: Compute::Numbers(|x| extreme(x.iter().min())) },
+    Function { name: "max", arity: Arity::Numbers, compute: Compute::Numbers(|x| extreme(x.iter().max())) },
+    Function { name: "clamp", arity: Arity::Exactly(3), compute: Compute::Numbers(clamp) },
+    Function { name: "gcd", arity: Arity::AtLeast(2), compute: Compute::Numbers(gcd) },
+    Function { name: "lcm", arity: Arity::AtLeast(2), compute: Compute::Numbers(lcm) },
+    Function { name: "lcd", arity: Arity::AtLeast(1), compute: Compute::Numbers(lcd) },
+    Function { name: "factor", arity: Arity::Exactly(1), compute: Compute::Numbers(factor) },
+    Function { name: "cf", arity: Arity::Exactly(1), compute: Compute::Numbers(cf) },
+    Function { name: "convergents", arity: Arity::Exactly(1), compute: Compute::Numbers(convergents) },
+    Function { name: "egyptian", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| unit_fractions(x[0], egyptian::greedy(&x[0]))) },
+    Function { name: "egyptian_short", arity: Arity::Exactly(1), compute: Compute::Numbers(|x| unit_fractions(x[0], egyptian::shortest(&x[0]))) },
+    Function { name: "sum", arity: Arity::Numbers, compute: Compute::Numbers(|x| statistics::sum(x).map(Value::Number)) },
+    Function { name: "product", arity: Arity::Numbers, compute: Compute::Numbers(|x| statistics::product(x).map(Value::Number)) },
+    Function { name: "mean", arity: Arity::Numbers, compute: Compute::Numbers(|x| statistics::mean(x).map(Value::Number)) },
+    Function { name: "median", arity: Arity::Numbers, compute: Compute::Numbers(|x| statistics::median(x).map(Value::Number)) },
+    Function { name: "mode", arity: Arity::Numbers, compute: Compute::Numbers(mode) },
+    Function { name: "range", arity: Arity::Numbers, compute: Compute::Numbers(|x| statistics::range(x).map(Value::Number)) },
+    Function { name: "variance", arity: Arity::Numbers, compute: Compute::Numbers(|x| statistics::variance(x).map(Value::Number)) },
+    Function { name: "sample_variance", arity: Arity::Numbers, compute: Compute::Numbers(|x| statistics::sample_variance(x).map(Value::Number)) },
+    Function { name: "transpose", arity: Arity::Exactly(1), compute: Compute::Values(&[Kind::Matrix], |v| Ok(Value::Matrix(matrix(&v[0])?.transpose()))) },
+    Function { name: "det", arity: Arity::Exactly(1), compute: Compute::Values(&[Kind::Matrix], |v| matrix(&v[0])?.determinant().map(Value::Number)) },
+    Function { name: "inverse", arity: Arity::Exactly(1), compute: Compute::Values(&[Kind::Matrix], |v| matrix(&v[0])?.inverse().map(Value::Matrix)) },
+    Function { name: "rref", arity: Arity::Exactly(1), compute: Compute::Values(&[Kind::Matrix], |v| matrix(&v[0])?.rref().map(Value::Matrix)) },
+    Function { name: "eval", arity: Arity::Exactly(2), compute: Compute::Values(&[Kind::Polynomial, Kind::Number], evaluate) },
+    Function { name: "deriv", arity: Arity::Exactly(1), compute: Compute::Values(&[Kind::Polynomial], |v| polynomial(&v[0])?.derivative().map(Value::Polynomial)) },
+    Function { name: "poly_gcd", arity: Arity::Exactly(2), compute: Compute::Values(&[Kind::Polynomial, Kind::Polynomial], |v| polynomial(&v[0])?.gcd(polynomial(&v[1])?).map(Value::Polynomial)) },
+    Function { name: "roots", arity: Arity::Exactly(1), compute: Compute::Values(&[Kind::Polynomial], |v| polynomial(&v[0])?.rational_roots().map(Value::List)) },
+    Function { name: "tuplet", arity: Arity::Exactly(2), compute: Compute::Values(&[Kind::Ratio, Kind::Number], tuplet) },
+    Function { name: "E", arity: Arity::Exactly(1), compute: Compute::Values(&[Kind::Distribution], expected_value) },
+    Function { name: "conj", arity: Arity::Exactly(1), compute: Compute::Values(&[Kind::Complex], |v| Ok(Value::from(complex(&v[0])?.conjugate()))) },
+    Function { name: "norm", arity: Arity::Exactly(1), compute: Compute::Values(&[Kind::Complex], |v| number(complex(&v[0])?.norm()?)) },
+    Function { name: "pct_change", arity: Arity::Exactly(2), compute: Compute::Numbers(percent_change) },
+    Function { name: "split", arity: Arity::Exactly(2), compute: Compute::Values(&[Kind::Money, Kind::Number], split) }
+];
+
+/// Finds a built-in function by name
+pub fn lookup(name: &str) -> Option<&'static Function> {
+    FUNCTIONS.iter().find(|function| function.name == name)
+}
+
+/// Lists the names of the built-in functions, in the order they're registered
+pub fn names() -> impl Iterator<Item = &'static str> {
+    FUNCTIONS.iter().map(|function| function.name)
+}
+
+fn matrix(value: &Value) -> Result<&Matrix, &'static str> {
+    match value {
+        Value::Matrix(matrix) => Ok(matrix),
+        _ => Err(Kind::Matrix.error())
+    }
+}
+
+fn polynomial(value: &Value) -> Result<&Polynomial, &'static str> {
+    match value {
+        Value::Polynomial(polynomial) => Ok(polynomial),
+        _ => Err(Kind::Polynomial.error())
+    }
+}
+
+fn complex(value: &Value) -> Result<Complex, &'static str> {
+    match value {
+        Value::Complex(complex) => Ok(*complex),
+        value => value.as_number().map(|number| Complex::new(number, Fraction::default())).ok_or(Kind::Complex.error())
+    }
+}
+
+/// The change from a number to another as a percentage of the first one, e.g. `16_2/3%` from `3/4` to `7/8`
+fn percent_change(x: &[Fraction]) -> Result<Value, &'static str> {
+    Value::percent(x[1].checked_substract(&x[0])?.divide(&x[0].abs())?)
+}
+
+fn evaluate(arguments: &[Value]) -> Result<Value, &'static str> {
+    let x = arguments[1].as_number().ok_or(Kind::Number.error())?;
+
+    number(polynomial(&arguments[0])?.evaluate(&x)?)
+}
+
+fn tuplet(arguments: &[Value]) -> Result<Value, &'static str> {
+    let duration = arguments[1].as_number().ok_or(Kind::Number.error())?;
+
+    match &arguments[0] {
+        Value::Ratio(ratio) => music::tuplet(ratio, &duration).map(Value::Number),
+        _ => Err(Kind::Ratio.error())
+    }
+}
+
+fn expected_value(arguments: &[Value]) -> Result<Value, &'static str> {
+    match &arguments[0] {
+        Value::Distribution(distribution) => distribution.expected_value().map(Value::Number),
+        _ => Err(Kind::Distribution.error())
+    }
+}
+
+/// Splits an amount of money into a whole number of parts, rounding it to whole cents with banker's rounding first
+fn split(arguments: &[Value]) -> Result<Value, &'static str> {
+    let parts = arguments[1].as_number().ok_or(Kind::Number.error())?;
+    if !parts.is_whole() {
+        return Err("Expected a whole number!");
+    }
+
+    match &arguments[0] {
+        Value::Money(amount) => money::split(amount, parts.numerator, Rounding::default()).map(Value::Shares),
+        _ => Err(Kind::Money.error())
+    }
+}
+
+fn number(result: Fraction) -> Result<Value, &'static str> {
+    Ok(Value::Number(result))
+}
+
+fn extreme(number: Option<&Fraction>) -> Result<Value, &'static str> {
+    number.map(|number| Value::Number(*number)).ok_or("Expected at least one number!")
+}
+
+/// Number-theory functions only work with whole numbers
+fn whole_numbers(arguments: &[Fraction]) -> Result<Vec<i32>, &'static str> {
+    arguments.iter()
+        .map(|argument| if argument.is_whole() { Ok(argument.numerator) } else { Err("Expected a whole number!") })
+        .collect()
+}
+
+fn clamp(arguments: &[Fraction]) -> Result<Value, &'static str> {
+    let (x, lower, upper) = (arguments[0], arguments[1], arguments[2]);
+
+    if lower > upper {
+        Err("Lower bound greater than upper bound!")
+    } else {
+        number(x.max(lower).min(upper))
+    }
+}
+
+fn gcd(arguments: &[Fraction]) -> Result<Value, &'static str> {
+    number(Fraction::from(math::gcd_of(&whole_numbers(arguments)?)?))
+}
+
+fn lcm(arguments: &[Fraction]) -> Result<Value, &'static str> {
+    number(Fraction::from(math::lcm_of(&whole_numbers(arguments)?)?))
+}
+
+fn lcd(arguments: &[Fraction]) -> Result<Value, &'static str> {
+    let denominator = math::lcd(arguments)?;
+    // Rewriting the fractions over the denominator must not overflow either
+    for argument in arguments {
+        argument.numerator.checked_mul(denominator / argument.denominator).ok_or("Integer overflow!")?;
+    }
+
+    Ok(Value::CommonDenominator { denominator, fractions: arguments.to_vec() })
+}
+
+fn factor(arguments: &[Fraction]) -> Result<Value, &'static str> {
+    let number = whole_numbers(arguments)?[0];
+
+    Ok(Value::Factors { number, factors: math::factor(number)? })
+}
+
+fn cf(arguments: &[Fraction]) -> Result<Value, &'static str> {
+    let value = arguments[0];
+
+    Ok(Value::ContinuedFraction { value, terms: continued_fraction::expand(&value) })
+}
+
+fn convergents(arguments: &[Fraction]) -> Result<Value, &'static str> {
+    Ok(Value::List(continued_fraction::convergents(&arguments[0])))
+}
+
+fn unit_fractions(value: Fraction, denominators: Result<Vec<i128>, &'static str>) -> Result<Value, &'static str> {
+    Ok(Value::UnitFractions { value, denominators: denominators? })
+}
+
+/// Ties give all the modes as a list, so nothing is left out
+fn mode(arguments: &[Fraction]) -> Result<Value, &'static str> {
+    match statistics::mode(arguments)?.as_slice() {
+        [mode] => number(*mode),
+        modes => Ok(Value::List(modes.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod tests;
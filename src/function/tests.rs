@@ -0,0 +1,222 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+// This function will help test the built-in functions in tests below
+fn test_apply_functions(function_test_cases: &[(&str, &[&str], &str)]) {
+    for (name, arguments, result) in function_test_cases {
+        println!("Applying {} to {:?}", name, arguments);
+        let arguments: Vec<Fraction> = arguments.iter().map(|argument| fraction(argument)).collect();
+
+        let actual = lookup(name).unwrap().apply(&arguments).unwrap();
+
+        let expected = fraction(result);
+        assert_that!(&actual)
+            .is_equal_to(Value::Number(expected));
+    }
+}
+
+#[test]
+fn apply_single_argument_functions() {
+    test_apply_functions(&[
+        ("abs", &["-2_1/4"], "2_1/4"),
+        ("recip", &["2_1/2"], "2/5"),
+        ("floor", &["-2_1/4"], "-3"),
+        ("ceil", &["-2_1/4"], "-2"),
+        ("round", &["2_1/2"], "3"),
+        ("trunc", &["-2_3/4"], "-2"),
+        ("frac", &["-2_3/4"], "-3/4"),
+        ("sign", &["-2_3/4"], "-1"),
+        ("num", &["6/8"], "3"),
+        ("den", &["6/8"], "4"),
+        ("whole", &["19/8"], "2")
+    ]);
+}
+
+#[test]
+fn apply_functions_with_several_arguments() {
+    test_apply_functions(&[
+        ("min", &["1/2", "2/3", "-1/4"], "-1/4"),
+        ("max", &["1/2", "2/3"], "2/3"),
+        ("clamp", &["5/4", "0", "1"], "1"),
+        ("clamp", &["-1", "0", "1"], "0"),
+        ("gcd", &["12", "18", "-27"], "3"),
+        ("lcm", &["4", "6", "10"], "60")
+    ]);
+}
+
+#[test]
+fn apply_with_wrong_number_of_arguments() {
+    let actual = lookup("abs").unwrap().apply(&[Fraction::from(1), Fraction::from(2)]);
+
+    assert_that!(&actual)
+        .is_equal_to(Err("Wrong number of arguments!"));
+}
+
+#[test]
+fn apply_to_no_numbers() {
+    assert_that!(&lookup("max").unwrap().apply(&[]))
+        .is_equal_to(Err("Expected at least one number!"));
+    assert_that!(&lookup("sum").unwrap().apply(&[]))
+        .is_equal_to(Ok(Value::Number(Fraction::from(0))));
+}
+
+#[test]
+fn apply_with_failing_computation() {
+    assert_that!(&lookup("recip").unwrap().apply(&[Fraction::default()]))
+        .is_equal_to(Err("Division by zero!"));
+    assert_that!(&lookup("clamp").unwrap().apply(&[Fraction::from(1), Fraction::from(1), Fraction::from(0)]))
+        .is_equal_to(Err("Lower bound greater than upper bound!"));
+}
+
+#[test]
+fn lookup_unknown_function() {
+    assert_that!(&lookup("sqrt").is_none())
+        .is_true();
+}
+
+#[test]
+fn names_of_registered_functions() {
+    let names: Vec<&str> = names().collect();
+
+    assert_that!(&names)
+        .contains("recip");
+    assert_that!(&names)
+        .has_length(44);
+}
+
+#[test]
+fn apply_lcd_rewrites_fractions() {
+    let arguments: Vec<Fraction> = ["1/6", "3/8", "5/12"].iter().map(|argument| fraction(argument)).collect();
+
+    let actual = lookup("lcd").unwrap().apply(&arguments).unwrap();
+
+    assert_that!(actual.to_string().as_str())
+        .is_equal_to("24 (1/6 = 4/24, 3/8 = 9/24, 5/12 = 10/24)");
+}
+
+#[test]
+fn apply_factor() {
+    let actual = lookup("factor").unwrap().apply(&[Fraction::from(-360)]).unwrap();
+
+    assert_that!(actual.to_string().as_str())
+        .is_equal_to("-1 * 2^3 * 3^2 * 5");
+    assert_that!(&actual.as_number())
+        .is_equal_to(Some(Fraction::from(-360)));
+}
+
+#[test]
+fn apply_number_theory_functions_to_fractions() {
+    let actual = lookup("gcd").unwrap().apply(&[Fraction::new(1, 2).unwrap(), Fraction::from(4)]);
+
+    assert_that!(&actual)
+        .is_equal_to(Err("Expected a whole number!"));
+}
+
+#[test]
+fn apply_continued_fraction_functions() {
+    let x = Fraction::new(415, 93).unwrap();
+
+    let expansion = lookup("cf").unwrap().apply(&[x]).unwrap();
+    let convergents = lookup("convergents").unwrap().apply(&[x]).unwrap();
+
+    assert_that!(expansion.to_string().as_str())
+        .is_equal_to("[4; 2, 6, 7]");
+    assert_that!(&expansion.as_number())
+        .is_equal_to(Some(x));
+    assert_that!(convergents.to_string().as_str())
+        .is_equal_to("[4, 4_1/2, 4_6/13, 4_43/93]");
+}
+
+#[test]
+fn apply_egyptian_functions() {
+    let x = Fraction::new(4, 13).unwrap();
+
+    let greedy = lookup("egyptian").unwrap().apply(&[x]).unwrap();
+    let shortest = lookup("egyptian_short").unwrap().apply(&[x]).unwrap();
+
+    assert_that!(greedy.to_string().as_str())
+        .is_equal_to("1/4 + 1/18 + 1/468");
+    assert_that!(shortest.to_string().as_str())
+        .is_equal_to("1/4 + 1/26 + 1/52");
+    assert_that!(&shortest.as_number())
+        .is_equal_to(Some(x));
+}
+
+#[test]
+fn apply_statistics_functions() {
+    test_apply_functions(&[
+        ("sum", &["1/2", "3_1/4", "7/8"], "4_5/8"),
+        ("product", &["1/2", "3_1/4", "7/8"], "1_27/64"),
+        ("mean", &["1/2", "3_1/4", "7/8"], "1_13/24"),
+        ("median", &["1/2", "3_1/4", "7/8"], "7/8"),
+        ("mode", &["1/2", "2/4", "7/8"], "1/2"),
+        ("range", &["1/2", "3_1/4", "7/8"], "2_3/4"),
+        ("variance", &["1", "2", "3", "4"], "1_1/4"),
+        ("sample_variance", &["1", "2", "3", "4"], "1_2/3")
+    ]);
+}
+
+#[test]
+fn apply_mode_with_tie() {
+    let actual = lookup("mode").unwrap().apply(&[Fraction::from(1), Fraction::from(2)]).unwrap();
+
+    assert_that!(&actual)
+        .is_equal_to(Value::List(vec![Fraction::from(1), Fraction::from(2)]));
+}
+
+#[test]
+fn apply_matrix_functions() {
+    let matrix = Matrix::new(vec![vec![Fraction::from(1), Fraction::from(2)], vec![Fraction::from(3), Fraction::from(4)]]).unwrap();
+
+    assert_that!(lookup("det").unwrap().parameters())
+        .is_equal_to(Some(&[Kind::Matrix][..]));
+    assert_that!(&lookup("det").unwrap().apply_to_values(&[Value::Matrix(matrix)]))
+        .is_equal_to(Ok(Value::Number(Fraction::from(-2))));
+    assert_that!(&lookup("det").unwrap().apply(&[Fraction::from(2)]))
+        .is_equal_to(Err("Expected a matrix!"));
+}
+
+#[test]
+fn apply_expected_value() {
+    let roll = crate::probability::Distribution::dice(1, 8).unwrap();
+
+    assert_that!(&lookup("E").unwrap().apply_to_values(&[Value::Distribution(roll)]))
+        .is_equal_to(Ok(Value::Number(Fraction::new(9, 2).unwrap())));
+    assert_that!(&lookup("E").unwrap().apply(&[Fraction::from(2)]))
+        .is_equal_to(Err("Expected a distribution!"));
+}
+
+#[test]
+fn apply_complex_functions() {
+    let z = || Value::Complex(Complex::new(Fraction::new(1, 2).unwrap(), Fraction::new(3, 4).unwrap()));
+
+    assert_that!(&lookup("conj").unwrap().apply_to_values(&[z()]))
+        .is_equal_to(Ok(Value::Complex(Complex::new(Fraction::new(1, 2).unwrap(), Fraction::new(-3, 4).unwrap()))));
+    assert_that!(&lookup("norm").unwrap().apply_to_values(&[z()]))
+        .is_equal_to(Ok(Value::Number(Fraction::new(13, 16).unwrap())));
+    assert_that!(&lookup("conj").unwrap().apply_to_values(&[Value::Number(Fraction::from(2))]))
+        .is_equal_to(Ok(Value::Number(Fraction::from(2))));
+}
+
+#[test]
+fn apply_percent_change() {
+    let percent_change = |old: &str, new: &str| lookup("pct_change").unwrap().apply(&[fraction(old), fraction(new)]);
+
+    assert_that!(&percent_change("3/4", "7/8")).is_equal_to(Ok(Value::Percent(Fraction::new(1, 6).unwrap())));
+    assert_that!(&percent_change("-2", "-3")).is_equal_to(Ok(Value::Percent(Fraction::new(-1, 2).unwrap())));
+    assert_that!(&percent_change("0", "1")).is_equal_to(Err("Division by zero!"));
+}
+
+#[test]
+fn apply_split() {
+    let split = lookup("split").unwrap();
+    let cents = |cents: i32| Fraction::new(cents, 100).unwrap();
+
+    assert_that!(&split.apply_to_values(&[Value::Money(Fraction::from(100)), Value::Number(Fraction::from(3))]))
+        .is_equal_to(Ok(Value::Shares(vec![cents(3334), cents(3333), cents(3333)])));
+    assert_that!(&split.apply_to_values(&[Value::Money(Fraction::from(1)), Value::Number(Fraction::new(3, 2).unwrap())]))
+        .is_equal_to(Err("Expected a whole number!"));
+    assert_that!(&split.apply(&[Fraction::from(100), Fraction::from(3)]))
+        .is_equal_to(Err("Expected an amount of money!"));
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+use crate::fraction::Fraction;
+
+/// Models a closed interval of fractions, e.g. a length machined within a tolerance like `2_1/2 ± 1/64`.
+/// Arithmetic on intervals gives the interval of every result of the operation on values within them,
+/// so tolerances stack up exactly
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    pub lower: Fraction,
+    pub upper: Fraction
+}
+
+impl Interval {
+
+    /// The interval between two bounds, in any order
+    pub fn new(a: Fraction, b: Fraction) -> Interval {
+        Interval { lower: a.min(b), upper: a.max(b) }
+    }
+
+    /// The interval of a value give or take a tolerance, e.g. `2_1/2 ± 1/64` is `[2_31/64, 2_33/64]`
+    pub fn with_tolerance(center: Fraction, tolerance: Fraction) -> Result<Interval, &'static str> {
+        if tolerance < Fraction::default() {
+            return Err("Expected a non-negative tolerance!");
+        }
+
+        Interval::checked(center.checked_substract(&tolerance)?, center.checked_add(&tolerance)?)
+    }
+
+    /// The midpoint of the interval. It's worked out with wide integers, as the bounds may add up to more than fits
+    pub fn center(&self) -> Result<Fraction, &'static str> {
+        let (lower, upper) = (&self.lower, &self.upper);
+        let numerator = i128::from(lower.numerator) * i128::from(upper.denominator)
+            + i128::from(upper.numerator) * i128::from(lower.denominator);
+
+        Fraction::reduce(numerator, 2 * i128::from(lower.denominator) * i128::from(upper.denominator))
+    }
+
+    /// How far the bounds are from the center, i.e. the tolerance
+    pub fn half_width(&self) -> Result<Fraction, &'static str> {
+        let (lower, upper) = (&self.lower, &self.upper);
+        let numerator = i128::from(upper.numerator) * i128::from(lower.denominator)
+            - i128::from(lower.numerator) * i128::from(upper.denominator);
+
+        Fraction::reduce(numerator, 2 * i128::from(lower.denominator) * i128::from(upper.denominator))
+    }
+
+    pub fn contains(&self, x: &Fraction) -> bool {
+        self.lower <= *x && *x <= self.upper
+    }
+
+    pub fn add(&self, other: &Interval) -> Result<Interval, &'static str> {
+        Interval::checked(self.lower.checked_add(&other.lower)?, self.upper.checked_add(&other.upper)?)
+    }
+
+    pub fn substract(&self, other: &Interval) -> Result<Interval, &'static str> {
+        Interval::checked(self.lower.checked_substract(&other.upper)?, self.upper.checked_substract(&other.lower)?)
+    }
+
+    /// The bounds of the product are the smallest & largest products of bounds, as signs may flip them
+    pub fn multiply(&self, other: &Interval) -> Result<Interval, &'static str> {
+        let products = [
+            self.lower.checked_multiply(&other.lower)?, self.lower.checked_multiply(&other.upper)?,
+            self.upper.checked_multiply(&other.lower)?, self.upper.checked_multiply(&other.upper)?
+        ];
+
+        Interval::checked(
+            products.iter().copied().min().unwrap_or_default(),
+            products.iter().copied().max().unwrap_or_default()
+        )
+    }
+
+    /// Divides by multiplying by the reciprocal of the divisor, which only exists if it doesn't contain zero
+    pub fn divide(&self, other: &Interval) -> Result<Interval, &'static str> {
+        if other.lower.is_zero() && other.upper.is_zero() {
+            Err("Division by zero!")
+        } else if other.contains(&Fraction::default()) {
+            Err("Division by an interval containing zero!")
+        } else {
+            self.multiply(&Interval::new(other.lower.recip()?, other.upper.recip()?))
+        }
+    }
+
+    /// The interval between two ordered bounds, failing if it can't be shown as a center & a tolerance
+    fn checked(lower: Fraction, upper: Fraction) -> Result<Interval, &'static str> {
+        let interval = Interval { lower, upper };
+        interval.center()?;
+        interval.half_width()?;
+
+        Ok(interval)
+    }
+}
+
+/// Implementation to make `Interval` displayable both ways, e.g. `3_1/4 ± 3/64 [3_13/64, 3_19/64]`.
+/// Intervals worked out by arithmetic always have a center & a tolerance that fit
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (center, tolerance) = (self.center().unwrap_or_default(), self.half_width().unwrap_or_default());
+
+        write!(f, "{} ± {} [{}, {}]", center, tolerance, self.lower, self.upper)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,63 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+fn interval(lower: &str, upper: &str) -> Interval {
+    Interval::new(fraction(lower), fraction(upper))
+}
+
+#[test]
+fn create_with_tolerance() {
+    let actual = Interval::with_tolerance(fraction("2_1/2"), fraction("1/64")).unwrap();
+
+    assert_that!(&actual).is_equal_to(interval("2_31/64", "2_33/64"));
+    assert_that!(&actual.center()).is_equal_to(Ok(fraction("2_1/2")));
+    assert_that!(&actual.half_width()).is_equal_to(Ok(fraction("1/64")));
+    assert_that!(&Interval::with_tolerance(fraction("1"), fraction("-1/8")))
+        .is_equal_to(Err("Expected a non-negative tolerance!"));
+}
+
+#[test]
+fn stack_tolerances() {
+    let a = Interval::with_tolerance(fraction("2_1/2"), fraction("1/64")).unwrap();
+    let b = Interval::with_tolerance(fraction("3/4"), fraction("1/32")).unwrap();
+
+    assert_that!(&a.add(&b).unwrap().to_string()).is_equal_to("3_1/4 ± 3/64 [3_13/64, 3_19/64]".to_string());
+    assert_that!(&a.substract(&b).unwrap().to_string()).is_equal_to("1_3/4 ± 3/64 [1_45/64, 1_51/64]".to_string());
+}
+
+#[test]
+fn multiply_intervals() {
+    let multiply_test_cases = [
+        (("1", "2"), ("3", "4"), ("3", "8")),
+        (("-1", "2"), ("3", "4"), ("-4", "8")),
+        (("-2", "-1"), ("-1/2", "3"), ("-6", "1"))
+    ];
+
+    for ((a, b), (c, d), (lower, upper)) in &multiply_test_cases {
+        println!("Multiplying [{}, {}] by [{}, {}]", a, b, c, d);
+
+        assert_that!(&interval(a, b).multiply(&interval(c, d))).is_equal_to(Ok(interval(lower, upper)));
+    }
+}
+
+#[test]
+fn operations_on_overflow() {
+    let a = interval("2147483646", "2147483647");
+
+    assert_that!(&Interval::with_tolerance(fraction("2147483647"), fraction("1"))).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&a.add(&interval("1", "1"))).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&interval("-2147483647", "-1").substract(&a)).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&a.multiply(&interval("2", "2"))).is_equal_to(Err("Integer overflow!"));
+    // The center of the bounds is beyond integers even though both bounds fit
+    assert_that!(&interval("1/2147483647", "1/2147483646").center()).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&interval("2147483645", "2147483647").center()).is_equal_to(Ok(fraction("2147483646")));
+}
+
+#[test]
+fn divide_intervals() {
+    assert_that!(&interval("1", "2").divide(&interval("4", "8"))).is_equal_to(Ok(interval("1/8", "1/2")));
+    assert_that!(&interval("1", "2").divide(&interval("-1/2", "-1/4"))).is_equal_to(Ok(interval("-8", "-2")));
+    assert_that!(&interval("1", "2").divide(&interval("-1", "1"))).is_equal_to(Err("Division by an interval containing zero!"));
+    assert_that!(&interval("1", "2").divide(&interval("0", "0"))).is_equal_to(Err("Division by zero!"));
+}
@@ -0,0 +1,229 @@
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Minimal JSON document model, just enough to emit machine-readable outputs & read requests
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>)
+}
+
+impl Json {
+
+    /// Convenience factory to build objects out of `(&str, Json)` pairs, keeping the given order
+    pub fn object(members: Vec<(&str, Json)>) -> Json {
+        Json::Object(members.into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect())
+    }
+
+    /// Parses a JSON text. Trailing characters other than whitespace are rejected
+    pub fn parse(text: &str) -> Result<Json, &'static str> {
+        let mut chars = text.chars().peekable();
+        let json = parse_value(&mut chars, 0)?;
+
+        skip_whitespace(&mut chars);
+        if chars.peek().is_some() {
+            Err("Unexpected trailing characters in JSON!")
+        } else {
+            Ok(json)
+        }
+    }
+
+    /// Returns the value of the given member if this is an object having it
+    pub fn get(&self, name: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.iter()
+                .find(|(member_name, _)| member_name == name)
+                .map(|(_, value)| value),
+            _ => None
+        }
+    }
+
+    /// Returns the string if this is a JSON string
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(string) => Some(string),
+            _ => None
+        }
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.peek().is_some_and(|c| c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn expect_keyword(chars: &mut Peekable<Chars>, keyword: &str, json: Json) -> Result<Json, &'static str> {
+    for expected in keyword.chars() {
+        if chars.next() != Some(expected) {
+            return Err("Unparseable JSON!");
+        }
+    }
+    Ok(json)
+}
+
+/// Arrays & objects nested deeper than this are rejected, so a hostile text can't exhaust the stack
+const MAX_DEPTH: usize = 256;
+
+fn parse_value(chars: &mut Peekable<Chars>, depth: usize) -> Result<Json, &'static str> {
+    skip_whitespace(chars);
+    match chars.peek() {
+        Some('n') => expect_keyword(chars, "null", Json::Null),
+        Some('t') => expect_keyword(chars, "true", Json::Bool(true)),
+        Some('f') => expect_keyword(chars, "false", Json::Bool(false)),
+        Some('"') => parse_string(chars).map(Json::String),
+        Some('[') | Some('{') if depth == MAX_DEPTH => Err("Unparseable JSON!"),
+        Some('[') => parse_array(chars, depth + 1),
+        Some('{') => parse_object(chars, depth + 1),
+        Some(c) if *c == '-' || c.is_ascii_digit() => parse_number(chars),
+        _ => Err("Unparseable JSON!")
+    }
+}
+
+fn parse_number(chars: &mut Peekable<Chars>) -> Result<Json, &'static str> {
+    let mut number = String::new();
+    while let Some(&c) = chars.peek() {
+        if c.is_ascii_digit() || "+-.eE".contains(c) {
+            number.push(c);
+            chars.next();
+        } else {
+            break;
+        }
+    }
+    number.parse().map(Json::Number).map_err(|_| "Unparseable JSON number!")
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Result<String, &'static str> {
+    chars.next(); // Opening quote
+    let mut string = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(string),
+            Some('\\') => match chars.next() {
+                Some('n') => string.push('\n'),
+                Some('r') => string.push('\r'),
+                Some('t') => string.push('\t'),
+                Some('b') => string.push('\u{8}'),
+                Some('f') => string.push('\u{c}'),
+                Some('u') => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    let character = u32::from_str_radix(&code, 16).ok()
+                        .and_then(std::char::from_u32)
+                        .ok_or("Unparseable JSON string!")?;
+                    string.push(character);
+                },
+                Some(c) => string.push(c),
+                None => return Err("Unterminated JSON string!")
+            },
+            Some(c) => string.push(c),
+            None => return Err("Unterminated JSON string!")
+        }
+    }
+}
+
+fn parse_array(chars: &mut Peekable<Chars>, depth: usize) -> Result<Json, &'static str> {
+    chars.next(); // Opening bracket
+    let mut elements = Vec::new();
+    skip_whitespace(chars);
+    if chars.peek() == Some(&']') {
+        chars.next();
+        return Ok(Json::Array(elements));
+    }
+    loop {
+        elements.push(parse_value(chars, depth)?);
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',') => continue,
+            Some(']') => return Ok(Json::Array(elements)),
+            _ => return Err("Unparseable JSON array!")
+        }
+    }
+}
+
+fn parse_object(chars: &mut Peekable<Chars>, depth: usize) -> Result<Json, &'static str> {
+    chars.next(); // Opening brace
+    let mut members = Vec::new();
+    skip_whitespace(chars);
+    if chars.peek() == Some(&'}') {
+        chars.next();
+        return Ok(Json::Object(members));
+    }
+    loop {
+        skip_whitespace(chars);
+        if chars.peek() != Some(&'"') {
+            return Err("Unparseable JSON object!");
+        }
+        let name = parse_string(chars)?;
+        skip_whitespace(chars);
+        if chars.next() != Some(':') {
+            return Err("Unparseable JSON object!");
+        }
+        members.push((name, parse_value(chars, depth)?));
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',') => continue,
+            Some('}') => return Ok(Json::Object(members)),
+            _ => return Err("Unparseable JSON object!")
+        }
+    }
+}
+
+/// Implementation to make `Json` displayable as compact JSON text.
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(boolean) => write!(f, "{}", boolean),
+            Json::Number(number) => write!(f, "{}", number),
+            Json::String(string) => write_string(f, string),
+            Json::Array(elements) => {
+                write!(f, "[")?;
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, "]")
+            },
+            Json::Object(members) => {
+                write!(f, "{{")?;
+                for (index, (name, value)) in members.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, name)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// Writes a JSON string literal escaping quotes, backslashes & control characters
+fn write_string(f: &mut fmt::Formatter, string: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for character in string.chars() {
+        match character {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?
+        }
+    }
+    write!(f, "\"")
+}
+
+#[cfg(test)]
+mod tests;
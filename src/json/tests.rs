@@ -0,0 +1,92 @@
+use spectral::prelude::*;
+use super::*;
+
+#[test]
+fn display_numbers() {
+    let numbers = Json::Array(vec![Json::Number(7.0), Json::Number(-1.875)]);
+
+    let actual = format!("{}", numbers);
+
+    assert_that!(actual.as_str())
+        .is_equal_to("[7,-1.875]");
+}
+
+#[test]
+fn display_object_keeps_member_order() {
+    let object = Json::object(vec![
+        ("input", Json::String("1/2 * 3".to_string())),
+        ("numerator", Json::Number(3.0))
+    ]);
+
+    let actual = format!("{}", object);
+
+    assert_that!(actual.as_str())
+        .is_equal_to(r#"{"input":"1/2 * 3","numerator":3}"#);
+}
+
+#[test]
+fn display_string_escapes_special_characters() {
+    let string = Json::String("say \"1/2\"\\\n\u{1}".to_string());
+
+    let actual = format!("{}", string);
+
+    assert_that!(actual.as_str())
+        .is_equal_to(r#""say \"1/2\"\\\n\u0001""#);
+}
+
+#[test]
+fn parse_nested_document() {
+    let text = r#" {"id": 1, "params": ["1/2 * 3", true, null], "ok": false} "#;
+
+    let actual = Json::parse(text).unwrap();
+
+    let expected = Json::object(vec![
+        ("id", Json::Number(1.0)),
+        ("params", Json::Array(vec![Json::String("1/2 * 3".to_string()), Json::Bool(true), Json::Null])),
+        ("ok", Json::Bool(false))
+    ]);
+    assert_that!(&actual)
+        .is_equal_to(&expected);
+}
+
+#[test]
+fn parse_and_display_roundtrip() {
+    let text = r#"{"input":"say \"hi\"\n","decimal":-4.5,"list":[]}"#;
+
+    let actual = Json::parse(text).unwrap().to_string();
+
+    assert_that!(actual.as_str())
+        .is_equal_to(text);
+}
+
+#[test]
+fn get_object_member() {
+    let object = Json::parse(r#"{"expression": "1/2 + 1/4"}"#).unwrap();
+
+    assert_that!(&object.get("expression").and_then(Json::as_str))
+        .is_equal_to(Some("1/2 + 1/4"));
+    assert_that!(&object.get("missing"))
+        .is_equal_to(None);
+}
+
+#[test]
+#[should_panic(expected = "Unexpected trailing characters in JSON!")]
+fn parse_with_trailing_characters() {
+    Json::parse("{} {}").unwrap();
+}
+
+#[test]
+fn parse_nested_up_to_the_depth_limit() {
+    let text = format!("{}{}", "[".repeat(256), "]".repeat(256));
+
+    assert_that!(Json::parse(&text)).is_ok();
+}
+
+#[test]
+fn parse_nested_beyond_the_depth_limit() {
+    let text = format!("{}{}", "[".repeat(257), "]".repeat(257));
+
+    assert_that!(Json::parse(&text)).is_equal_to(Err("Unparseable JSON!"));
+    assert_that!(Json::parse(&"[".repeat(200000))).is_equal_to(Err("Unparseable JSON!"));
+    assert_that!(Json::parse(&"{\"a\":".repeat(200000))).is_equal_to(Err("Unparseable JSON!"));
+}
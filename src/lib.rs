@@ -1,27 +1,74 @@
+use std::fs::File;
 use std::io;
 use std::io::prelude::*;
+use std::path::Path;
 use std::process;
 
-mod operation;
 mod mixed_number;
 mod fraction;
-mod math;
+pub mod math;
+pub mod continued_fraction;
+pub mod egyptian;
+pub mod statistics;
+pub mod matrix;
+pub mod polynomial;
+pub mod quantity;
+pub mod timecode;
+pub mod music;
+pub mod probability;
+pub mod interval;
+pub mod complex;
+pub mod money;
+pub mod ratio;
+pub mod linear;
+pub mod recipe;
+mod explanation;
+mod format;
+mod error;
+mod json;
+mod output;
+mod session;
+mod server;
+mod tokenizer;
+mod expression;
+mod evaluator;
+mod function;
+mod value;
+
+pub use crate::fraction::Fraction;
+pub use crate::format::Format;
+pub use crate::output::Output;
+
+/// Settings that tweak how evaluations are reported
+#[derive(Debug, Default)]
+pub struct Settings {
+    /// Shows the step-by-step working before each result
+    pub explain: bool,
+    /// How results get rendered: plain, LaTeX, MathML or stacked fractions
+    pub format: Format,
+    /// Whether evaluations are written as text or as JSON
+    pub output: Output
+}
 
 /// Single evaluation mode evaluates the given expression and terminates
-pub fn run_single_evaluation(expression: &str) {
-    let result = evaluate_expression(&expression);
+pub fn run_single_evaluation(expression: &str, settings: &Settings) {
+    let result = evaluate_expression(expression, &mut session::Session::new(), settings);
     if result.is_err() {
         process::exit(1);
     }
 }
 
-/// REPL evaluation runs in a loop than terminates only when the user enters 'q'
-pub fn run_repl_evaluation() {
+/// REPL evaluation runs in a loop than terminates only when the user enters 'q'.
+/// Lines starting with ':' are REPL commands, e.g. `:explain` or `:format latex` change the settings & `:complete ro`
+/// lists the functions & variables starting with `ro`.
+/// Variables assigned, e.g. `x = 1/2 + 1/4`, are kept until the REPL terminates
+pub fn run_repl_evaluation(mut settings: Settings) {
     println!("Starting repl mode. Type 'q' to quit\n");
+    let mut session = session::Session::new();
 
     loop {
         print!("? ");
-        io::stdout().flush().ok().expect("Unable to flush stdout!");
+        io::stdout().flush().expect("Unable to flush stdout!");
 
         let mut expression = String::new();
         io::stdin()
@@ -32,25 +79,148 @@ pub fn run_repl_evaluation() {
             break;
         }
 
-        evaluate_expression(&expression).ok();
+        if expression.trim().starts_with(':') {
+            run_repl_command(expression.trim(), &session, &mut settings).ok();
+        } else {
+            evaluate_expression(strip_line_ending(&expression), &mut session, &settings).ok();
+        }
+    }
+}
+
+/// Batch evaluation reads one expression per line from stdin & evaluates them all without prompting.
+/// Blank lines are skipped. It terminates with failure if any of the expressions couldn't be evaluated
+pub fn run_batch_evaluation(settings: &Settings) {
+    let stdin = io::stdin();
+    let mut session = session::Session::new();
+    let mut failed = false;
+    let mut evaluations = Vec::new();
+
+    for line in stdin.lock().lines() {
+        let expression = line.expect("Failed to read expression");
+        if expression.trim().is_empty() {
+            continue;
+        }
+
+        if settings.output == Output::Json {
+            let evaluation = session.evaluate(&expression).map(|(_, result)| result);
+            failed |= evaluation.is_err();
+            evaluations.push(output::to_json(&expression, &evaluation));
+        } else {
+            failed |= evaluate_expression(&expression, &mut session, settings).is_err();
+        }
+    }
+
+    if settings.output == Output::Json {
+        println!("{}", json::Json::Array(evaluations));
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Server mode serves evaluations over JSON-RPC on the given address until the program gets killed.
+/// Every connection gets its own variables
+pub fn run_server(address: &str) {
+    if let Err(e) = server::serve(address) {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+/// Recipe scaling mode multiplies every quantity of a recipe, read from the given file or from stdin, by the given factor.
+/// Lines like `1 1/2 cup flour` are ingredients, whose quantities are written in readable units & rounded to what can be
+/// measured. Other lines are written as they are. It terminates with failure if an ingredient couldn't be scaled
+pub fn run_recipe_scaling(factor: &Fraction, file: Option<&Path>) {
+    if factor.numerator <= 0 {
+        eprintln!("Error: Expected a positive factor!");
+        process::exit(1);
+    }
+    let reader: Box<dyn BufRead> = match file {
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(io::BufReader::new(file)),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        },
+        None => Box::new(io::BufReader::new(io::stdin()))
+    };
+
+    let mut failed = false;
+    for line in reader.lines() {
+        let line = line.expect("Failed to read recipe");
+        match recipe::scale_line(&line, factor) {
+            Ok(scaled) => println!("{}", scaled),
+            Err(e) => {
+                failed = true;
+                eprintln!("Error: {} ({})", e, line.trim());
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run_repl_command(command: &str, session: &session::Session, settings: &mut Settings) -> Result<(), &'static str> {
+    match command {
+        ":explain" => {
+            settings.explain = !settings.explain;
+            println!("Explain mode {}", if settings.explain { "on" } else { "off" });
+            Ok(())
+        },
+        _ if command.starts_with(":format ") => {
+            match command[":format ".len()..].trim().parse() {
+                Ok(format) => {
+                    settings.format = format;
+                    println!("Format set to {:?}", format);
+                    Ok(())
+                },
+                Err(e) => log_and_propagate_error(e)
+            }
+        },
+        _ if command.starts_with(":complete") => {
+            for name in session.complete(command[":complete".len()..].trim()) {
+                println!("{}", name);
+            }
+            Ok(())
+        },
+        _ => log_and_propagate_error("Unknown command!")
     }
 }
 
-fn evaluate_expression(expression: &str) -> Result<(), &str> {
-    let result = operation::Operation::parse_operation(expression);
-    match result {
-        Ok(operation) => process_operation(operation),
-        Err(e) => log_and_propagate_error(e)
+fn evaluate_expression(expression: &str, session: &mut session::Session, settings: &Settings) -> Result<(), &'static str> {
+    match settings.output {
+        Output::Text => match expression::parse(expression) {
+            Ok(statement) => process_statement(statement, session, settings),
+            Err(e) => log_and_propagate_error(e.message)
+        },
+        Output::Json | Output::JsonLines => {
+            let evaluation = session.evaluate(expression).map(|(_, value)| value);
+            println!("{}", output::to_json(expression, &evaluation));
+            evaluation.map(|_| ()).map_err(|_| "Application error!")
+        }
     }
 }
 
-fn process_operation(operation: operation::Operation) -> Result<(), &'static str> {
-    match operation.compute() {
-        Ok(result) => println!("= {}", result),
-        Err(e) => log_and_propagate_error(e)?
+fn process_statement(statement: expression::Statement, session: &mut session::Session, settings: &Settings) -> Result<(), &'static str> {
+    // Steps are worked out before running, as an assignment may change the variables they use
+    let steps = if settings.explain { session.explain(&statement).unwrap_or_default() } else { Vec::new() };
+
+    match session.run(&statement) {
+        Ok(value) => {
+            for (index, step) in steps.iter().enumerate() {
+                println!("{}. {}", index + 1, step);
+            }
+            println!("{}", format::format_evaluation(settings.format, &statement, &value));
+            Ok(())
+        },
+        Err(e) => log_and_propagate_error(e.message)
     }
+}
 
-    Ok(())
+fn strip_line_ending(line: &str) -> &str {
+    line.trim_end_matches(['\n', '\r'])
 }
 
 fn log_and_propagate_error(error: &str) -> Result<(), &'static str> {
@@ -0,0 +1,164 @@
+use std::fmt;
+use crate::fraction::Fraction;
+
+/// Models a term of a linear equation: a coefficient times an unknown, or a constant if there's no unknown
+#[derive(Debug, Clone, PartialEq)]
+pub struct Term {
+    pub coefficient: Fraction,
+    pub unknown: Option<String>
+}
+
+/// Models a linear equation like `2/3 x + 1_1/4 = 5/6`, as the terms on each side of it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equation {
+    pub left: Vec<Term>,
+    pub right: Vec<Term>
+}
+
+impl Equation {
+
+    /// The unknowns of the equation, in the order they first appear
+    pub fn unknowns(&self) -> Vec<String> {
+        let mut unknowns: Vec<String> = Vec::new();
+        for term in self.left.iter().chain(&self.right) {
+            if let Some(unknown) = &term.unknown {
+                if !unknowns.contains(unknown) {
+                    unknowns.push(unknown.clone());
+                }
+            }
+        }
+
+        unknowns
+    }
+
+    /// Rewrites the equation as a row of the coefficients of the given unknowns followed by the constant,
+    /// i.e. with the unknowns moved to the left side & the constants to the right one
+    fn row(&self, unknowns: &[String]) -> Result<Vec<Fraction>, &'static str> {
+        let mut row = vec![Fraction::default(); unknowns.len() + 1];
+        let terms = self.left.iter().map(|term| (term, Fraction::from(1)))
+            .chain(self.right.iter().map(|term| (term, Fraction::from(-1))));
+
+        for (term, side) in terms {
+            match &term.unknown {
+                Some(unknown) => {
+                    let index = unknowns.iter().position(|name| name == unknown).unwrap_or_default();
+                    row[index] = row[index].checked_add(&term.coefficient.checked_multiply(&side)?)?;
+                },
+                None => row[unknowns.len()] = row[unknowns.len()].checked_substract(&term.coefficient.checked_multiply(&side)?)?
+            }
+        }
+        Ok(row)
+    }
+}
+
+/// Implementation to make `Equation` displayable, e.g. `2/3 x + 1_1/4 = 5/6`
+impl fmt::Display for Equation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} = {}", format_terms(&self.left), format_terms(&self.right))
+    }
+}
+
+/// Formats a side of an equation. Coefficients of `1` are left out & negative ones are substracted, e.g. `x - 1/2 y`
+fn format_terms(terms: &[Term]) -> String {
+    let mut text = String::new();
+
+    for (index, term) in terms.iter().enumerate() {
+        let negative = term.coefficient.numerator < 0;
+        match (index, negative) {
+            (0, true) => text.push('-'),
+            (0, false) => {},
+            (_, true) => text.push_str(" - "),
+            (_, false) => text.push_str(" + ")
+        }
+
+        let coefficient = term.coefficient.abs();
+        match &term.unknown {
+            Some(unknown) if coefficient == Fraction::from(1) => text.push_str(unknown),
+            Some(unknown) => text.push_str(&format!("{} {}", coefficient, unknown)),
+            None => text.push_str(&coefficient.to_string())
+        }
+    }
+    text
+}
+
+/// Solves a system of linear equations exactly by Gauss-Jordan elimination, giving the value of each unknown
+/// in the order they first appear, e.g. `x + y = 3/2` & `x - y = 1/4` give `x = 7/8` & `y = 5/8`
+pub fn solve(equations: &[Equation]) -> Result<Vec<(String, Fraction)>, &'static str> {
+    eliminate(equations, &mut Vec::new())
+}
+
+/// Explains how `solve` gets to its result, giving the equations left after eliminating each unknown
+pub fn steps(equations: &[Equation]) -> Vec<String> {
+    let mut steps = Vec::new();
+    let _ = eliminate(equations, &mut steps);
+
+    steps
+}
+
+fn eliminate(equations: &[Equation], steps: &mut Vec<String>) -> Result<Vec<(String, Fraction)>, &'static str> {
+    let mut unknowns: Vec<String> = Vec::new();
+    for unknown in equations.iter().flat_map(Equation::unknowns) {
+        if !unknowns.contains(&unknown) {
+            unknowns.push(unknown);
+        }
+    }
+    let mut rows = equations.iter().map(|equation| equation.row(&unknowns)).collect::<Result<Vec<Vec<Fraction>>, &'static str>>()?;
+    steps.push(format!("Collect the unknowns on the left: {}", format_rows(&rows, &unknowns)));
+
+    let zero = Fraction::default();
+    let mut pivot_row = 0;
+    for column in 0..unknowns.len() {
+        let pivot = match (pivot_row..rows.len()).find(|&row| rows[row][column] != zero) {
+            Some(pivot) => pivot,
+            None => continue
+        };
+        rows.swap(pivot_row, pivot);
+
+        let divisor = rows[pivot_row][column];
+        for value in rows[pivot_row].iter_mut() {
+            *value = value.divide(&divisor)?;
+        }
+        let pivot_values = rows[pivot_row].clone();
+        for (index, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if index != pivot_row && factor != zero {
+                for (value, pivot_value) in row.iter_mut().zip(&pivot_values) {
+                    *value = value.checked_substract(&pivot_value.checked_multiply(&factor)?)?;
+                }
+            }
+        }
+        steps.push(format!("Eliminate {}: {}", unknowns[column], format_rows(&rows, &unknowns)));
+        pivot_row += 1;
+    }
+
+    // Rows left without unknowns either say `0 = 0` or contradict the others
+    if rows[pivot_row..].iter().any(|row| row[unknowns.len()] != zero) {
+        Err("No solution!")
+    } else if pivot_row < unknowns.len() {
+        Err("Infinitely many solutions!")
+    } else {
+        Ok(unknowns.into_iter().zip(rows.iter().map(|row| row[row.len() - 1])).collect())
+    }
+}
+
+/// Formats the rows of a system as equations, e.g. `x - 1/2 y = 3/4; y = 5/8`
+fn format_rows(rows: &[Vec<Fraction>], unknowns: &[String]) -> String {
+    let equations: Vec<String> = rows.iter()
+        .map(|row| {
+            let mut left: Vec<Term> = unknowns.iter().zip(row)
+                .filter(|(_, coefficient)| coefficient.numerator != 0)
+                .map(|(unknown, coefficient)| Term { coefficient: *coefficient, unknown: Some(unknown.clone()) })
+                .collect();
+            if left.is_empty() {
+                left.push(Term { coefficient: Fraction::default(), unknown: None });
+            }
+            let right = vec![Term { coefficient: row[unknowns.len()], unknown: None }];
+            Equation { left, right }.to_string()
+        })
+        .collect();
+
+    equations.join("; ")
+}
+
+#[cfg(test)]
+mod tests;
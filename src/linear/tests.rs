@@ -0,0 +1,126 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+// This function will help build the equations used in tests below
+fn term(coefficient: &str, unknown: Option<&str>) -> Term {
+    Term { coefficient: fraction(coefficient), unknown: unknown.map(|unknown| unknown.to_string()) }
+}
+
+#[test]
+fn solve_single_equation() {
+    // 2/3 x + 1_1/4 = 5/6
+    let equation = Equation {
+        left: vec![term("2/3", Some("x")), term("1_1/4", None)],
+        right: vec![term("5/6", None)]
+    };
+
+    assert_that!(&solve(&[equation]))
+        .is_equal_to(Ok(vec![("x".to_string(), fraction("-5/8"))]));
+}
+
+#[test]
+fn solve_unknowns_on_both_sides() {
+    // 3 x - 1 = x + 1/2
+    let equation = Equation {
+        left: vec![term("3", Some("x")), term("-1", None)],
+        right: vec![term("1", Some("x")), term("1/2", None)]
+    };
+
+    assert_that!(&solve(&[equation]))
+        .is_equal_to(Ok(vec![("x".to_string(), fraction("3/4"))]));
+}
+
+#[test]
+fn solve_system() {
+    // x + y = 3/2; x - y = 1/4
+    let equations = [
+        Equation { left: vec![term("1", Some("x")), term("1", Some("y"))], right: vec![term("3/2", None)] },
+        Equation { left: vec![term("1", Some("x")), term("-1", Some("y"))], right: vec![term("1/4", None)] }
+    ];
+
+    assert_that!(&solve(&equations))
+        .is_equal_to(Ok(vec![("x".to_string(), fraction("7/8")), ("y".to_string(), fraction("5/8"))]));
+}
+
+#[test]
+fn solve_system_needing_row_swap() {
+    // y = 2; x + y = 5; z - x = 1/2
+    let equations = [
+        Equation { left: vec![term("1", Some("y"))], right: vec![term("2", None)] },
+        Equation { left: vec![term("1", Some("x")), term("1", Some("y"))], right: vec![term("5", None)] },
+        Equation { left: vec![term("1", Some("z")), term("-1", Some("x"))], right: vec![term("1/2", None)] }
+    ];
+
+    assert_that!(&solve(&equations)).is_equal_to(Ok(vec![
+        ("y".to_string(), fraction("2")),
+        ("x".to_string(), fraction("3")),
+        ("z".to_string(), fraction("3_1/2"))
+    ]));
+}
+
+#[test]
+fn solve_inconsistent_system() {
+    // x + y = 1; 2 x + 2 y = 3
+    let equations = [
+        Equation { left: vec![term("1", Some("x")), term("1", Some("y"))], right: vec![term("1", None)] },
+        Equation { left: vec![term("2", Some("x")), term("2", Some("y"))], right: vec![term("3", None)] }
+    ];
+
+    assert_that!(&solve(&equations))
+        .is_equal_to(Err("No solution!"));
+}
+
+#[test]
+fn solve_underdetermined_system() {
+    // x + y = 1; 2 x + 2 y = 2
+    let equations = [
+        Equation { left: vec![term("1", Some("x")), term("1", Some("y"))], right: vec![term("1", None)] },
+        Equation { left: vec![term("2", Some("x")), term("2", Some("y"))], right: vec![term("2", None)] }
+    ];
+
+    assert_that!(&solve(&equations))
+        .is_equal_to(Err("Infinitely many solutions!"));
+}
+
+#[test]
+fn solve_overflowing_system() {
+    // 2147483647 x = 1 - 2147483647 x
+    let equation = Equation {
+        left: vec![term("2147483647", Some("x"))],
+        right: vec![term("1", None), term("-2147483647", Some("x"))]
+    };
+    // 2147483647 x + y = 1; x + 2147483647 y = 1
+    let equations = [
+        Equation { left: vec![term("2147483647", Some("x")), term("1", Some("y"))], right: vec![term("1", None)] },
+        Equation { left: vec![term("1", Some("x")), term("2147483647", Some("y"))], right: vec![term("1", None)] }
+    ];
+
+    assert_that!(&solve(&[equation])).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&solve(&equations)).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn explain_elimination() {
+    let equations = [
+        Equation { left: vec![term("1", Some("x")), term("1", Some("y"))], right: vec![term("3/2", None)] },
+        Equation { left: vec![term("1", Some("x")), term("-1", Some("y"))], right: vec![term("1/4", None)] }
+    ];
+
+    assert_that!(&steps(&equations)).is_equal_to(vec![
+        "Collect the unknowns on the left: x + y = 1_1/2; x - y = 1/4".to_string(),
+        "Eliminate x: x + y = 1_1/2; -2 y = -1_1/4".to_string(),
+        "Eliminate y: x = 7/8; y = 5/8".to_string()
+    ]);
+}
+
+#[test]
+fn display_equation() {
+    let equation = Equation {
+        left: vec![term("-2/3", Some("x")), term("-1", Some("y")), term("1_1/4", None)],
+        right: vec![term("0", None)]
+    };
+
+    assert_that!(equation.to_string().as_str())
+        .is_equal_to("-2/3 x - y + 1_1/4 = 0");
+}
@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use structopt::StructOpt;
 
 /// This struct models the command line options
@@ -5,17 +6,59 @@ use structopt::StructOpt;
 #[structopt(name = "mncalc", about = "Simple Mixed Numbers Calculator")]
 pub struct Config {
     #[structopt(short = "e", long = "eval", help = "The expression to evaluate")]
-    expression: Option<String>
+    expression: Option<String>,
+    #[structopt(long = "explain", help = "Shows the step-by-step working of each evaluation")]
+    explain: bool,
+    #[structopt(long = "format", default_value = "plain", help = "The output format: plain, latex, mathml, pretty or ascii")]
+    format: mncalc::Format,
+    #[structopt(long = "output", default_value = "text", help = "How evaluations are written: text, json or jsonl")]
+    output: mncalc::Output,
+    #[structopt(long = "batch", help = "Evaluates every line read from stdin without prompting")]
+    batch: bool,
+    #[structopt(subcommand)]
+    command: Option<Command>
 }
 
-/// The program can run in 2 modes: single evaluation & repl
+/// This enum models the subcommands
+#[derive(Debug, StructOpt)]
+pub enum Command {
+    #[structopt(name = "serve", about = "Serves evaluations over JSON-RPC, one request per line")]
+    Serve {
+        #[structopt(long = "listen", default_value = "127.0.0.1:7878", help = "The address to listen on")]
+        listen: String
+    },
+    #[structopt(name = "scale", about = "Scales the quantities of a recipe read from a file or stdin")]
+    Scale {
+        #[structopt(long = "by", help = "The factor to multiply quantities by, e.g. 1_1/2")]
+        factor: mncalc::Fraction,
+        #[structopt(parse(from_os_str), help = "The recipe file. Stdin is read if it's not given")]
+        file: Option<PathBuf>
+    }
+}
+
+/// The program can run in 5 modes: server, recipe scaling, single evaluation, batch & repl
+/// Server mode is run if the serve subcommand is provided
+/// Recipe scaling mode is run if the scale subcommand is provided
 /// Single mode is run if an expression is provided through command line option
-/// REPL mode is run if no options are provided
+/// Batch mode is run if the batch flag is provided
+/// REPL mode is run otherwise
 fn main() {
     let config = Config::from_args();
+    let settings = mncalc::Settings {
+        explain: config.explain,
+        format: config.format,
+        output: config.output
+    };
+
+    match config.command {
+        Some(Command::Serve { listen }) => return mncalc::run_server(&listen),
+        Some(Command::Scale { factor, file }) => return mncalc::run_recipe_scaling(&factor, file.as_deref()),
+        None => {}
+    }
 
     match config.expression {
-        Some(expression) => mncalc::run_single_evaluation(&expression),
-        None => mncalc::run_repl_evaluation()
+        Some(expression) => mncalc::run_single_evaluation(&expression, &settings),
+        None if config.batch => mncalc::run_batch_evaluation(&settings),
+        None => mncalc::run_repl_evaluation(settings)
     }
 }
@@ -1,18 +1,94 @@
-/// Computes absolute value of a number
+use std::convert::TryFrom;
+use crate::fraction::Fraction;
+
+/// Computes absolute value of a number. Like `i32::abs`, it overflows for `i32::MIN`, which fractions never hold
 pub fn abs(number: i32) -> i32 {
     if number < 0 {
-        number * -1
+        -number
     } else {
         number
     }
 }
 
-/// Computes the GCD of 2 numbers using well-known Euclidean Algorithm. 
+/// Computes the GCD of 2 numbers using well-known Euclidean Algorithm. The result is never negative.
+/// It fails if the GCD doesn't fit in an `i32`, which only happens for `i32::MIN` & either zero or itself.
 /// See: https://en.wikipedia.org/wiki/Euclidean_algorithm
-pub fn gcd(a: i32, b: i32) -> i32 {
-    if b == 0 {
-       return a;
+pub fn gcd(a: i32, b: i32) -> Result<i32, &'static str> {
+    narrow(wide_gcd(i128::from(a), i128::from(b)))
+}
+
+/// Computes the LCM of 2 numbers, which is never negative. It fails if the LCM doesn't fit in an `i32`
+pub fn lcm(a: i32, b: i32) -> Result<i32, &'static str> {
+    if a == 0 || b == 0 {
+        Ok(0)
+    } else {
+        let (a, b) = (i128::from(a), i128::from(b));
+        narrow((a / wide_gcd(a, b) * b).abs())
+    }
+}
+
+/// Computes the GCD of several numbers, e.g. `gcd(12, 18, 27) = 3`
+pub fn gcd_of(numbers: &[i32]) -> Result<i32, &'static str> {
+    narrow(numbers.iter().fold(0, |result, &number| wide_gcd(result, i128::from(number))))
+}
+
+/// Computes the LCM of several numbers, e.g. `lcm(4, 6, 10) = 60`
+pub fn lcm_of(numbers: &[i32]) -> Result<i32, &'static str> {
+    numbers.iter().try_fold(1, |result, &number| lcm(result, number))
+}
+
+/// Computes the GCD of 2 wide numbers, like the intermediate results of operations that don't fit in an `i32`
+pub(crate) fn wide_gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn narrow(number: i128) -> Result<i32, &'static str> {
+    i32::try_from(number).map_err(|_| "Integer overflow!")
+}
+
+/// Computes the least common denominator of several fractions, i.e. the LCM of their denominators.
+/// E.g. `1/6`, `3/8` & `5/12` can all be written over `24`
+pub fn lcd(fractions: &[Fraction]) -> Result<i32, &'static str> {
+    let denominators: Vec<i32> = fractions.iter().map(|fraction| fraction.denominator).collect();
+
+    lcm_of(&denominators)
+}
+
+/// Decomposes a number into its prime factors & their exponents, e.g. `360 = 2^3 * 3^2 * 5` gives
+/// `[(2, 3), (3, 2), (5, 1)]`. Negative numbers get a leading `(-1, 1)` factor and `1` has no factors
+pub fn factor(number: i32) -> Result<Vec<(i32, u32)>, &'static str> {
+    if number == 0 {
+        return Err("Cannot factor zero!");
     }
-    
-    gcd(b, a % b)
+
+    let mut factors = if number < 0 { vec![(-1, 1)] } else { Vec::new() };
+    // Works on an i64 as the absolute value of i32::MIN doesn't fit in an i32. Expressions can't get here with it,
+    // as it can't be the numerator of a fraction, but library callers can
+    let mut remaining = i64::from(number).abs();
+    let mut prime = 2;
+
+    while prime * prime <= remaining {
+        let mut exponent = 0;
+        while remaining % prime == 0 {
+            remaining /= prime;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((prime as i32, exponent));
+        }
+        prime += 1;
+    }
+    if remaining > 1 {
+        factors.push((remaining as i32, 1));
+    }
+
+    Ok(factors)
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,59 @@
+use spectral::prelude::*;
+use super::*;
+
+#[test]
+fn gcd_of_two_numbers() {
+    assert_that!(&gcd(12, 18)).is_equal_to(Ok(6));
+    assert_that!(&gcd(-4, 6)).is_equal_to(Ok(2));
+    assert_that!(&gcd(7, 0)).is_equal_to(Ok(7));
+    assert_that!(&gcd(0, 0)).is_equal_to(Ok(0));
+    assert_that!(&gcd(i32::MIN, 6)).is_equal_to(Ok(2));
+}
+
+#[test]
+fn gcd_and_lcm_of_the_smallest_number() {
+    assert_that!(&gcd(i32::MIN, 1)).is_equal_to(Ok(1));
+    assert_that!(&gcd(i32::MIN, 0)).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&lcm(i32::MIN, 1)).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&gcd_of(&[i32::MIN, 12])).is_equal_to(Ok(4));
+    assert_that!(&lcm_of(&[2, i32::MIN])).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn lcm_of_two_numbers() {
+    assert_that!(&lcm(4, 6)).is_equal_to(Ok(12));
+    assert_that!(&lcm(-3, 5)).is_equal_to(Ok(15));
+    assert_that!(&lcm(0, 5)).is_equal_to(Ok(0));
+}
+
+#[test]
+fn lcm_overflow() {
+    assert_that!(&lcm(i32::MAX, 2)).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn gcd_and_lcm_of_several_numbers() {
+    assert_that!(&gcd_of(&[12, 18, 27])).is_equal_to(Ok(3));
+    assert_that!(&lcm_of(&[4, 6, 10])).is_equal_to(Ok(60));
+}
+
+#[test]
+fn lcd_of_fractions() {
+    let fractions: Vec<Fraction> = ["1/6", "3/8", "5/12"].iter().map(|exp| exp.parse().unwrap()).collect();
+
+    assert_that!(&lcd(&fractions)).is_equal_to(Ok(24));
+}
+
+#[test]
+fn factor_numbers() {
+    assert_that!(&factor(360)).is_equal_to(Ok(vec![(2, 3), (3, 2), (5, 1)]));
+    assert_that!(&factor(97)).is_equal_to(Ok(vec![(97, 1)]));
+    assert_that!(&factor(-12)).is_equal_to(Ok(vec![(-1, 1), (2, 2), (3, 1)]));
+    assert_that!(&factor(1)).is_equal_to(Ok(vec![]));
+    assert_that!(&factor(i32::MIN)).is_equal_to(Ok(vec![(-1, 1), (2, 31)]));
+}
+
+#[test]
+fn factor_zero() {
+    assert_that!(&factor(0)).is_equal_to(Err("Cannot factor zero!"));
+}
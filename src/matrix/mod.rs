@@ -0,0 +1,245 @@
+use std::fmt;
+use crate::fraction::Fraction;
+
+/// Models a matrix of fractions. Matrices built through `Matrix::new` always have at least a row & a column,
+/// and all their rows are as long. Operations fail with an error rather than overflow
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: Vec<Vec<Fraction>>
+}
+
+impl Matrix {
+
+    /// Creates a matrix from its rows, e.g. `[[1/2, 1/3], [1/4, 1/5]]`
+    pub fn new(rows: Vec<Vec<Fraction>>) -> Result<Matrix, &'static str> {
+        let columns = rows.first().map_or(0, |row| row.len());
+
+        if columns == 0 || rows.iter().any(|row| row.len() != columns) {
+            Err("Expected a rectangular matrix!")
+        } else {
+            Ok(Matrix { rows })
+        }
+    }
+
+    /// Creates the identity matrix of the given size
+    pub fn identity(size: usize) -> Matrix {
+        let rows = (0..size)
+            .map(|row| (0..size).map(|column| Fraction::from(if row == column { 1 } else { 0 })).collect())
+            .collect();
+
+        Matrix { rows }
+    }
+
+    pub fn rows(&self) -> &[Vec<Fraction>] {
+        &self.rows
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn column_count(&self) -> usize {
+        self.rows[0].len()
+    }
+
+    pub fn add(&self, matrix: &Matrix) -> Result<Matrix, &'static str> {
+        self.combine(matrix, Fraction::checked_add)
+    }
+
+    pub fn substract(&self, matrix: &Matrix) -> Result<Matrix, &'static str> {
+        self.combine(matrix, Fraction::checked_substract)
+    }
+
+    /// Applies an operation to the entries found at the same place in both matrices
+    fn combine(&self, matrix: &Matrix, operation: fn(&Fraction, &Fraction) -> Result<Fraction, &'static str>) -> Result<Matrix, &'static str> {
+        if self.row_count() != matrix.row_count() || self.column_count() != matrix.column_count() {
+            return Err("Matrix dimensions don't match!");
+        }
+
+        let rows = self.rows.iter().zip(&matrix.rows)
+            .map(|(row, other)| combine_vectors(row, other, operation))
+            .collect::<Result<Vec<Vec<Fraction>>, &'static str>>()?;
+        Ok(Matrix { rows })
+    }
+
+    /// Multiplies every entry by the given number
+    pub fn scale(&self, factor: &Fraction) -> Result<Matrix, &'static str> {
+        let rows = self.rows.iter()
+            .map(|row| row.iter().map(|entry| entry.checked_multiply(factor)).collect())
+            .collect::<Result<Vec<Vec<Fraction>>, &'static str>>()?;
+
+        Ok(Matrix { rows })
+    }
+
+    /// Computes the matrix product, which needs as many columns in `self` as rows in the given matrix
+    pub fn multiply(&self, matrix: &Matrix) -> Result<Matrix, &'static str> {
+        if self.column_count() != matrix.row_count() {
+            return Err("Matrix dimensions don't match!");
+        }
+
+        let columns = matrix.transpose();
+        let rows = self.rows.iter()
+            .map(|row| columns.rows.iter().map(|column| dot(row, column)).collect::<Result<Vec<Fraction>, &'static str>>())
+            .collect::<Result<Vec<Vec<Fraction>>, &'static str>>()?;
+        Ok(Matrix { rows })
+    }
+
+    /// Multiplies the matrix by a column vector, e.g. `[[1, 2], [3, 4]] * [1, 1] = [3, 7]`
+    pub fn apply(&self, vector: &[Fraction]) -> Result<Vec<Fraction>, &'static str> {
+        if self.column_count() != vector.len() {
+            return Err("Matrix dimensions don't match!");
+        }
+
+        self.rows.iter().map(|row| dot(row, vector)).collect()
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let rows = (0..self.column_count())
+            .map(|column| self.rows.iter().map(|row| row[column]).collect())
+            .collect();
+
+        Matrix { rows }
+    }
+
+    /// Computes the determinant by reducing the matrix to an upper triangular one, whose determinant is the
+    /// product of its diagonal. Every row swap along the way flips the sign
+    pub fn determinant(&self) -> Result<Fraction, &'static str> {
+        if self.row_count() != self.column_count() {
+            return Err("Expected a square matrix!");
+        }
+
+        let zero = Fraction::default();
+        let mut rows = self.rows.clone();
+        let mut determinant = Fraction::from(1);
+        for column in 0..rows.len() {
+            let pivot = match (column..rows.len()).find(|&row| rows[row][column] != zero) {
+                Some(pivot) => pivot,
+                None => return Ok(zero)
+            };
+            if pivot != column {
+                rows.swap(pivot, column);
+                determinant = -determinant;
+            }
+
+            let pivot_row = rows[column].clone();
+            determinant = determinant.checked_multiply(&pivot_row[column])?;
+            for row in rows.iter_mut().skip(column + 1) {
+                let factor = row[column].divide(&pivot_row[column])?;
+                eliminate(row, &pivot_row, &factor)?;
+            }
+        }
+        Ok(determinant)
+    }
+
+    /// Computes the inverse by reducing the matrix augmented with the identity, which turns the identity into the inverse
+    pub fn inverse(&self) -> Result<Matrix, &'static str> {
+        if self.row_count() != self.column_count() {
+            return Err("Expected a square matrix!");
+        }
+
+        let size = self.row_count();
+        let augmented = self.rows.iter().zip(Matrix::identity(size).rows)
+            .map(|(row, identity)| row.iter().cloned().chain(identity).collect())
+            .collect();
+        let reduced = Matrix { rows: augmented }.rref()?;
+
+        if reduced.rows.iter().any(|row| row[..size].iter().all(|entry| entry.numerator == 0)) {
+            Err("Singular matrix!")
+        } else {
+            Ok(Matrix { rows: reduced.rows.into_iter().map(|row| row[size..].to_vec()).collect() })
+        }
+    }
+
+    /// Computes the reduced row echelon form using Gauss-Jordan elimination
+    pub fn rref(&self) -> Result<Matrix, &'static str> {
+        let zero = Fraction::default();
+        let mut rows = self.rows.clone();
+        let mut pivot_row = 0;
+
+        for column in 0..self.column_count() {
+            let pivot = match (pivot_row..rows.len()).find(|&row| rows[row][column] != zero) {
+                Some(pivot) => pivot,
+                None => continue
+            };
+            rows.swap(pivot_row, pivot);
+
+            let divisor = rows[pivot_row][column];
+            for entry in rows[pivot_row].iter_mut() {
+                *entry = entry.divide(&divisor)?;
+            }
+            let pivot_entries = rows[pivot_row].clone();
+            for (index, row) in rows.iter_mut().enumerate() {
+                let factor = row[column];
+                if index != pivot_row && factor != zero {
+                    eliminate(row, &pivot_entries, &factor)?;
+                }
+            }
+
+            pivot_row += 1;
+            if pivot_row == rows.len() {
+                break;
+            }
+        }
+        Ok(Matrix { rows })
+    }
+}
+
+/// Substracts the pivot row times the given factor from a row, the elimination step of Gaussian elimination
+fn eliminate(row: &mut [Fraction], pivot_row: &[Fraction], factor: &Fraction) -> Result<(), &'static str> {
+    for (entry, pivot_entry) in row.iter_mut().zip(pivot_row) {
+        *entry = entry.checked_substract(&pivot_entry.checked_multiply(factor)?)?;
+    }
+    Ok(())
+}
+
+/// Implementation to make `Matrix` displayable in a single line like it's typed, e.g. `[[1/2, 1/3], [1/4, 1/5]]`.
+/// Formats can lay the rows out aligned instead
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<String> = self.rows.iter().map(|row| format_vector(row)).collect();
+
+        write!(f, "[{}]", rows.join(", "))
+    }
+}
+
+/// Formats a vector like a list, e.g. `[1/2, 1_1/3]`
+pub fn format_vector(vector: &[Fraction]) -> String {
+    let entries: Vec<String> = vector.iter().map(|entry| entry.to_string()).collect();
+
+    format!("[{}]", entries.join(", "))
+}
+
+pub fn add_vectors(u: &[Fraction], v: &[Fraction]) -> Result<Vec<Fraction>, &'static str> {
+    same_length(u, v)?;
+
+    combine_vectors(u, v, Fraction::checked_add)
+}
+
+pub fn substract_vectors(u: &[Fraction], v: &[Fraction]) -> Result<Vec<Fraction>, &'static str> {
+    same_length(u, v)?;
+
+    combine_vectors(u, v, Fraction::checked_substract)
+}
+
+/// Computes the dot product of two vectors, e.g. `[1/2, 2] * [4, 1/4] = 2_1/2`
+pub fn dot(u: &[Fraction], v: &[Fraction]) -> Result<Fraction, &'static str> {
+    same_length(u, v)?;
+
+    u.iter().zip(v).try_fold(Fraction::default(), |sum, (x, y)| sum.checked_add(&x.checked_multiply(y)?))
+}
+
+fn combine_vectors(u: &[Fraction], v: &[Fraction], operation: fn(&Fraction, &Fraction) -> Result<Fraction, &'static str>)
+    -> Result<Vec<Fraction>, &'static str> {
+    u.iter().zip(v).map(|(x, y)| operation(x, y)).collect()
+}
+
+fn same_length(u: &[Fraction], v: &[Fraction]) -> Result<(), &'static str> {
+    if u.len() == v.len() {
+        Ok(())
+    } else {
+        Err("Vector lengths don't match!")
+    }
+}
+
+#[cfg(test)]
+mod tests;
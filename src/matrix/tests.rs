@@ -0,0 +1,156 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+// These functions will help build the matrices used in tests below
+fn matrix(rows: &[&[&str]]) -> Matrix {
+    Matrix::new(rows.iter().map(|row| vector(row)).collect()).unwrap()
+}
+
+fn vector(entries: &[&str]) -> Vec<Fraction> {
+    entries.iter().map(|entry| fraction(entry)).collect()
+}
+
+#[test]
+fn new_with_ragged_rows() {
+    let rows = vec![vector(&["1", "2"]), vector(&["3"])];
+
+    assert_that!(&Matrix::new(rows))
+        .is_equal_to(Err("Expected a rectangular matrix!"));
+}
+
+#[test]
+fn new_without_entries() {
+    assert_that!(&Matrix::new(vec![vec![]]))
+        .is_equal_to(Err("Expected a rectangular matrix!"));
+}
+
+#[test]
+fn add_and_substract_matrices() {
+    let a = matrix(&[&["1/2", "1/3"], &["1/4", "1/5"]]);
+    let b = matrix(&[&["1/2", "2/3"], &["3/4", "4/5"]]);
+
+    assert_that!(&a.add(&b))
+        .is_equal_to(Ok(matrix(&[&["1", "1"], &["1", "1"]])));
+    assert_that!(&a.substract(&b))
+        .is_equal_to(Ok(matrix(&[&["0", "-1/3"], &["-1/2", "-3/5"]])));
+}
+
+#[test]
+fn add_matrices_with_different_dimensions() {
+    let a = matrix(&[&["1", "2"]]);
+    let b = matrix(&[&["1"], &["2"]]);
+
+    assert_that!(&a.add(&b))
+        .is_equal_to(Err("Matrix dimensions don't match!"));
+}
+
+#[test]
+fn multiply_matrices() {
+    let a = matrix(&[&["1/2", "1/3"], &["1/4", "1/5"]]);
+    let b = matrix(&[&["2", "0", "1"], &["3", "6", "-1"]]);
+
+    assert_that!(&a.multiply(&b))
+        .is_equal_to(Ok(matrix(&[&["2", "2", "1/6"], &["1_1/10", "1_1/5", "1/20"]])));
+    assert_that!(&b.multiply(&a))
+        .is_equal_to(Err("Matrix dimensions don't match!"));
+}
+
+#[test]
+fn apply_to_vector() {
+    let a = matrix(&[&["1", "2"], &["3", "4"]]);
+
+    assert_that!(&a.apply(&vector(&["1/2", "1"])))
+        .is_equal_to(Ok(vector(&["2_1/2", "5_1/2"])));
+}
+
+#[test]
+fn scale_matrix() {
+    let a = matrix(&[&["1/2", "1/3"]]);
+
+    assert_that!(&a.scale(&Fraction::from(6)))
+        .is_equal_to(Ok(matrix(&[&["3", "2"]])));
+}
+
+#[test]
+fn transpose_matrix() {
+    let a = matrix(&[&["1", "2", "3"], &["4", "5", "6"]]);
+
+    assert_that!(&a.transpose())
+        .is_equal_to(matrix(&[&["1", "4"], &["2", "5"], &["3", "6"]]));
+}
+
+#[test]
+fn compute_determinant() {
+    let determinant_test_cases: [(&[&[&str]], &str); 4] = [
+        (&[&["1/2", "1/3"], &["1/4", "1/5"]], "1/60"),
+        (&[&["0", "1"], &["1", "0"]], "-1"),
+        (&[&["2", "0", "1"], &["1", "3", "2"], &["1", "1", "2"]], "6"),
+        (&[&["1", "2"], &["2", "4"]], "0")
+    ];
+
+    for (rows, expected) in &determinant_test_cases {
+        println!("Computing determinant of {:?}", rows);
+
+        assert_that!(&matrix(rows).determinant())
+            .is_equal_to(Ok(fraction(expected)));
+    }
+}
+
+#[test]
+fn determinant_of_non_square_matrix() {
+    assert_that!(&matrix(&[&["1", "2"]]).determinant())
+        .is_equal_to(Err("Expected a square matrix!"));
+}
+
+#[test]
+fn invert_matrix() {
+    let a = matrix(&[&["1/2", "1/3"], &["1/4", "1/5"]]);
+
+    assert_that!(&a.inverse())
+        .is_equal_to(Ok(matrix(&[&["12", "-20"], &["-15", "30"]])));
+    assert_that!(&a.multiply(&a.inverse().unwrap()))
+        .is_equal_to(Ok(Matrix::identity(2)));
+}
+
+#[test]
+fn invert_singular_matrix() {
+    assert_that!(&matrix(&[&["1", "2"], &["2", "4"]]).inverse())
+        .is_equal_to(Err("Singular matrix!"));
+}
+
+#[test]
+fn reduce_to_row_echelon_form() {
+    let a = matrix(&[&["0", "2", "4"], &["1", "1", "1"], &["2", "4", "6"]]);
+
+    assert_that!(&a.rref())
+        .is_equal_to(Ok(matrix(&[&["1", "0", "-1"], &["0", "1", "2"], &["0", "0", "0"]])));
+}
+
+#[test]
+fn overflowing_operations() {
+    let a = matrix(&[&["100000", "3"], &["7", "100000"]]);
+
+    assert_that!(&a.determinant()).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&a.inverse()).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&a.multiply(&a)).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&a.scale(&Fraction::from(100000))).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&dot(&vector(&["100000", "100000"]), &vector(&["100000", "1"]))).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn combine_vectors() {
+    let u = vector(&["1/2", "2"]);
+    let v = vector(&["4", "1/4"]);
+
+    assert_that!(&add_vectors(&u, &v)).is_equal_to(Ok(vector(&["4_1/2", "2_1/4"])));
+    assert_that!(&substract_vectors(&u, &v)).is_equal_to(Ok(vector(&["-3_1/2", "1_3/4"])));
+    assert_that!(&dot(&u, &v)).is_equal_to(Ok(fraction("2_1/2")));
+    assert_that!(&dot(&u, &vector(&["1"]))).is_equal_to(Err("Vector lengths don't match!"));
+}
+
+#[test]
+fn display_matrix() {
+    assert_that!(matrix(&[&["1/2", "1/3"], &["1_1/4", "1/5"]]).to_string().as_str())
+        .is_equal_to("[[1/2, 1/3], [1_1/4, 1/5]]");
+}
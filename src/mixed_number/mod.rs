@@ -6,27 +6,52 @@ use crate::fraction::*;
 lazy_static! {
     static ref MIXED_NUMBER_RE: Regex = Regex::new(r"^(\-?\d+)_(\d+/\d+)$").unwrap();
     static ref NUMBER_RE: Regex = Regex::new(r"^(\-?\d+)$").unwrap();
+    static ref MIXED_NUMBER_PARTS_RE: Regex = Regex::new(r"^(\-?\d+)_(\d+)/(\d+)$").unwrap();
 }
 
 /// Parses the given expression as a Fraction
 /// Using fractions directly makes easier to implement the arithmetic
-pub fn parse_as_fraction(mixed_number_exp: &str) -> Result<Fraction, &str> {
+pub fn parse_as_fraction(mixed_number_exp: &str) -> Result<Fraction, &'static str> {
     if MIXED_NUMBER_RE.is_match(mixed_number_exp) {
         let captures = MIXED_NUMBER_RE.captures(mixed_number_exp).unwrap();
         let fraction = Fraction::parse_fraction(captures.get(2).unwrap().as_str())?;
         Fraction::new_mixed(
-            captures.get(1).unwrap().as_str().parse().unwrap(), 
+            parse_part(captures.get(1).unwrap().as_str())?,
             fraction.numerator,
             fraction.denominator
         )
     } else if Fraction::is_fraction(mixed_number_exp) {
         Fraction::parse_fraction(mixed_number_exp)
     } else if NUMBER_RE.is_match(mixed_number_exp) {
-        Fraction::new_whole(mixed_number_exp.parse().unwrap())
+        Fraction::new_whole(parse_part(mixed_number_exp)?)
     } else {
         Err("Unparseable mixed number!")
     }
 }
 
+/// Parses a whole number, numerator or denominator already matched by the regexes, which only fails if it's too big
+pub(crate) fn parse_part(part_exp: &str) -> Result<i32, &'static str> {
+    part_exp.parse().map_err(|_| "Integer overflow!")
+}
+
+/// Splits a full mixed number like `2_3/8` into its whole, numerator & denominator parts as typed.
+/// Returns `None` if the expression is not a full mixed number.
+pub fn split_mixed_number(mixed_number_exp: &str) -> Option<(i32, i32, i32)> {
+    let captures = MIXED_NUMBER_PARTS_RE.captures(mixed_number_exp)?;
+
+    Some((
+        captures.get(1).unwrap().as_str().parse().ok()?,
+        captures.get(2).unwrap().as_str().parse().ok()?,
+        captures.get(3).unwrap().as_str().parse().ok()?
+    ))
+}
+
+/// Returns `true` if given expression is a whole number like `7`
+pub fn is_whole_number(mixed_number_exp: &str) -> bool {
+    NUMBER_RE.is_match(mixed_number_exp)
+}
+
+// These tests compare against borrowed expected values, which spectral accepts as well
 #[cfg(test)]
+#[allow(clippy::needless_borrows_for_generic_args)]
 mod tests;
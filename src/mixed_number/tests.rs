@@ -53,3 +53,23 @@ fn parse_as_fraction_with_invalid_expresion() {
 
     parse_as_fraction(mixed_number_exp).unwrap();
 }
+
+#[test]
+fn split_full_mixed_number() {
+    let mixed_number_exp = "-2_6/8";
+
+    let actual = split_mixed_number(mixed_number_exp);
+
+    assert_that!(&actual)
+        .is_equal_to(&Some((-2, 6, 8)));
+}
+
+#[test]
+fn split_fraction_only_mixed_number() {
+    let mixed_number_exp = "6/8";
+
+    let actual = split_mixed_number(mixed_number_exp);
+
+    assert_that!(&actual)
+        .is_equal_to(&None);
+}
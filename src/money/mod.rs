@@ -0,0 +1,116 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+use crate::fraction::Fraction;
+
+/// The most parts an amount can be split into
+const MAX_PARTS: i32 = 1000;
+
+/// Rules to round amounts of money to whole cents. Halves go to the even cent by default, i.e. banker's rounding
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Rounding {
+    /// Halves of a cent are rounded away from zero, e.g. `$0.125` is `$0.13`
+    HalfUp,
+    /// Halves of a cent are rounded to the even cent, e.g. `$0.125` is `$0.12` but `$0.135` is `$0.14`
+    #[default]
+    HalfEven,
+    /// Fractions of a cent are dropped, e.g. `$0.129` is `$0.12`
+    Truncate
+}
+
+impl Rounding {
+
+    /// The name the rule is typed with, e.g. `half_up`
+    pub fn name(self) -> &'static str {
+        match self {
+            Rounding::HalfUp => "half_up",
+            Rounding::HalfEven => "half_even",
+            Rounding::Truncate => "truncate"
+        }
+    }
+}
+
+impl FromStr for Rounding {
+    type Err = &'static str;
+
+    fn from_str(rounding: &str) -> Result<Rounding, &'static str> {
+        match rounding {
+            "half_up" => Ok(Rounding::HalfUp),
+            "half_even" => Ok(Rounding::HalfEven),
+            "truncate" => Ok(Rounding::Truncate),
+            _ => Err("Unknown rounding!")
+        }
+    }
+}
+
+/// Parses a currency literal like `$129.99` or `-$3.5` into the exact amount it stands for
+pub fn parse(text: &str) -> Result<Fraction, &'static str> {
+    let (negative, amount) = match text.strip_prefix('-') {
+        Some(amount) => (true, amount),
+        None => (false, text)
+    };
+    let amount = amount.strip_prefix('$').ok_or("Unparseable operation!")?;
+    let (whole, decimals) = amount.split_once('.').unwrap_or((amount, ""));
+    if whole.is_empty() || !whole.chars().chain(decimals.chars()).all(|c| c.is_ascii_digit()) {
+        return Err("Unparseable operation!");
+    }
+
+    let numerator: i32 = format!("{}{}", whole, decimals).parse().map_err(|_| "Integer overflow!")?;
+    let denominator = u32::try_from(decimals.len()).ok()
+        .and_then(|decimals| 10_i32.checked_pow(decimals))
+        .ok_or("Integer overflow!")?;
+    Fraction::new(if negative { -numerator } else { numerator }, denominator)
+}
+
+/// Rounds an amount to whole cents following the given rule
+pub fn round(amount: &Fraction, rounding: Rounding) -> Result<Fraction, &'static str> {
+    let cents = i32::try_from(cents(amount, rounding)).map_err(|_| "Integer overflow!")?;
+
+    Fraction::new(cents, 100)
+}
+
+/// Splits an amount, rounded to whole cents, into parts of whole cents that add up to it exactly.
+/// The cents left over are given one each to the first parts, e.g. `$100` is split in 3 as `$33.34, $33.33, $33.33`
+pub fn split(amount: &Fraction, parts: i32, rounding: Rounding) -> Result<Vec<Fraction>, &'static str> {
+    if !(1..=MAX_PARTS).contains(&parts) {
+        return Err("Expected 1 to 1000 parts!");
+    }
+
+    let cents = cents(amount, rounding);
+    let (share, left_over) = (cents.abs() / i64::from(parts), cents.abs() % i64::from(parts));
+    (0..i64::from(parts))
+        .map(|part| {
+            let share = (share + i64::from(part < left_over)) * cents.signum();
+            Fraction::new(i32::try_from(share).map_err(|_| "Integer overflow!")?, 100)
+        })
+        .collect()
+}
+
+/// Formats an amount rounded to whole cents, with its thousands separated, e.g. `$1,234.57` or `-$3.50`
+pub fn format(amount: &Fraction, rounding: Rounding) -> String {
+    let cents = cents(amount, rounding);
+    let dollars = (cents.abs() / 100).to_string();
+    let groups: Vec<&str> = dollars.as_bytes()
+        .rchunks(3)
+        .rev()
+        .map(|group| std::str::from_utf8(group).unwrap_or_default())
+        .collect();
+
+    format!("{}${}.{:02}", if cents < 0 { "-" } else { "" }, groups.join(","), cents.abs() % 100)
+}
+
+/// The whole cents of an amount. They're worked out with 64 bit integers so the largest amounts don't overflow
+fn cents(amount: &Fraction, rounding: Rounding) -> i64 {
+    let numerator = i64::from(amount.numerator) * 100;
+    let denominator = i64::from(amount.denominator);
+    let (cents, remainder) = (numerator / denominator, (numerator % denominator).abs());
+
+    let away_from_zero = match rounding {
+        Rounding::HalfUp => 2 * remainder >= denominator,
+        Rounding::HalfEven => 2 * remainder > denominator || (2 * remainder == denominator && cents % 2 != 0),
+        Rounding::Truncate => false
+    };
+    if away_from_zero { cents + numerator.signum() } else { cents }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,66 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+#[test]
+fn parse_currency_literals() {
+    let parse_test_cases = [
+        ("$129.99", "129_99/100"),
+        ("$5", "5"),
+        ("-$3.5", "-3_1/2"),
+        ("$0.125", "1/8")
+    ];
+
+    for (text, expected) in &parse_test_cases {
+        println!("Parsing {}", text);
+
+        assert_that!(&parse(text)).is_equal_to(Ok(fraction(expected)));
+    }
+    assert_that!(&parse("$99999999999")).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn round_to_cents() {
+    let round_test_cases = [
+        ("1/8", Rounding::HalfUp, "13/100"),
+        ("1/8", Rounding::HalfEven, "3/25"),
+        ("27/200", Rounding::HalfEven, "7/50"),
+        ("129/1000", Rounding::Truncate, "3/25"),
+        ("-1/8", Rounding::HalfUp, "-13/100"),
+        ("-129/1000", Rounding::Truncate, "-3/25")
+    ];
+
+    for (amount, rounding, expected) in &round_test_cases {
+        println!("Rounding {} {}", amount, rounding.name());
+
+        assert_that!(&round(&fraction(amount), *rounding)).is_equal_to(Ok(fraction(expected)));
+    }
+}
+
+#[test]
+fn split_amounts_exactly() {
+    let parts = split(&fraction("100"), 3, Rounding::HalfEven).unwrap();
+
+    assert_that!(&parts).is_equal_to(vec![fraction("33_17/50"), fraction("33_33/100"), fraction("33_33/100")]);
+    assert_that!(&parts.iter().copied().sum::<Fraction>()).is_equal_to(fraction("100"));
+    assert_that!(&split(&fraction("-1/10"), 4, Rounding::HalfEven))
+        .is_equal_to(Ok(vec![fraction("-3/100"), fraction("-3/100"), fraction("-1/50"), fraction("-1/50")]));
+    assert_that!(&split(&fraction("1"), 0, Rounding::HalfEven)).is_equal_to(Err("Expected 1 to 1000 parts!"));
+}
+
+#[test]
+fn format_amounts() {
+    let format_test_cases = [
+        ("86_33/50", "$86.66"),
+        ("1234567_1/2", "$1,234,567.50"),
+        ("-7/2", "-$3.50"),
+        ("1/300", "$0.00"),
+        ("100", "$100.00")
+    ];
+
+    for (amount, expected) in &format_test_cases {
+        println!("Formatting {}", amount);
+
+        assert_that!(&format(&fraction(amount), Rounding::HalfEven)).is_equal_to(expected.to_string());
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+use std::cmp::Ordering;
+use crate::fraction::Fraction;
+use crate::statistics;
+
+/// Models a time signature like `7/8`. It's kept as written rather than as a fraction, as `4/4` & `2/2` are different
+/// time signatures even though both last a whole note
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeSignature {
+    pub beats: i32,
+    pub note: i32
+}
+
+impl TimeSignature {
+
+    /// Parses a time signature written as a number of beats over the note they're counted in, which must be a power of 2
+    pub fn parse(text: &str) -> Result<TimeSignature, &'static str> {
+        let (beats, note) = text.split_once('/').ok_or("Expected a time signature!")?;
+        let beats: i32 = beats.parse().map_err(|_| "Expected a time signature!")?;
+        let note: i32 = note.parse().map_err(|_| "Expected a time signature!")?;
+
+        if beats <= 0 || note <= 0 || note.count_ones() != 1 {
+            return Err("Expected a time signature!");
+        }
+        Ok(TimeSignature { beats, note })
+    }
+
+    /// How long a bar lasts in whole notes, e.g. `7/8` lasts `7/8` & `4/4` lasts `1`
+    pub fn length(&self) -> Fraction {
+        Fraction::new(self.beats, self.note).unwrap_or_default()
+    }
+
+    /// Fills a bar with notes of the given durations, failing if they add up to more than fits in a fraction,
+    /// or so much that how much they overflow the bar doesn't
+    pub fn bar(&self, durations: &[Fraction]) -> Result<Bar, &'static str> {
+        let bar = Bar { signature: *self, total: statistics::sum(durations)? };
+        bar.difference()?;
+
+        Ok(bar)
+    }
+}
+
+/// Implementation to make `TimeSignature` displayable as written, e.g. `4/4`
+impl fmt::Display for TimeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.beats, self.note)
+    }
+}
+
+/// Models a bar of a time signature along with the total duration of the notes in it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+    pub signature: TimeSignature,
+    pub total: Fraction
+}
+
+impl Bar {
+
+    /// Whether the notes fall short of the bar, fill it exactly or overflow it
+    pub fn fill(&self) -> Ordering {
+        self.total.cmp(&self.signature.length())
+    }
+
+    /// How much the notes fall short of the bar or overflow it
+    pub fn difference(&self) -> Result<Fraction, &'static str> {
+        self.total.checked_substract(&self.signature.length()).map(|difference| difference.abs())
+    }
+}
+
+/// Implementation to make `Bar` displayable as how full it is, e.g. `full 4/4 bar` or `7/8 bar underfull by 1/8`.
+/// Bars filled through `TimeSignature::bar` always have a difference that fits
+impl fmt::Display for Bar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let difference = self.difference().unwrap_or_default();
+
+        match self.fill() {
+            Ordering::Equal => write!(f, "full {} bar", self.signature),
+            Ordering::Less => write!(f, "{} bar underfull by {}", self.signature, difference),
+            Ordering::Greater => write!(f, "{} bar overfull by {}", self.signature, difference)
+        }
+    }
+}
+
+/// Splits the dots off a dotted note like `1/4.`, giving the note as written & how many dots it has
+pub fn split_dots(text: &str) -> (&str, u32) {
+    let note = text.trim_end_matches('.');
+
+    (note, (text.len() - note.len()) as u32)
+}
+
+/// The duration of a dotted note. Each dot adds half the duration the previous one added, so `1/4.` lasts `3/8`
+/// & `1/4..` lasts `7/16`
+pub fn dotted(duration: &Fraction, dots: u32) -> Result<Fraction, &'static str> {
+    let power = 2_i32.checked_pow(dots + 1).ok_or("Integer overflow!")?;
+
+    duration.checked_multiply(&Fraction::new(power - 1, power / 2)?)
+}
+
+/// The duration of each note of a tuplet playing some notes in the time of others, e.g. `3:2` of `1/8` are triplet
+/// eighths lasting `1/12` each
+pub fn tuplet(ratio: &[i32], duration: &Fraction) -> Result<Fraction, &'static str> {
+    match *ratio {
+        [notes, time] if notes > 0 && time > 0 => duration.checked_multiply(&Fraction::new(time, notes)?),
+        [_, _] => Err("Expected a positive ratio!"),
+        _ => Err("Expected a ratio of two terms!")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,68 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+#[test]
+fn parse_time_signatures() {
+    assert_that!(&TimeSignature::parse("4/4")).is_equal_to(Ok(TimeSignature { beats: 4, note: 4 }));
+    assert_that!(&TimeSignature::parse("7/8").map(|signature| signature.to_string()))
+        .is_equal_to(Ok("7/8".to_string()));
+
+    for text in &["4/3", "0/4", "3", "1_1/2"] {
+        println!("Parsing {}", text);
+
+        assert_that!(&TimeSignature::parse(text)).is_equal_to(Err("Expected a time signature!"));
+    }
+}
+
+#[test]
+fn fill_bars() {
+    let bar_test_cases = [
+        ("4/4", vec!["1/4", "1/8", "1/8", "1/2"], "full 4/4 bar"),
+        ("7/8", vec!["1/4", "1/4", "1/4"], "7/8 bar underfull by 1/8"),
+        ("3/4", vec!["1/2", "3/8"], "3/4 bar overfull by 1/8"),
+        ("2/2", vec!["1"], "full 2/2 bar")
+    ];
+
+    for (signature, durations, expected) in &bar_test_cases {
+        println!("Filling a {} bar with {:?}", signature, durations);
+        let durations: Vec<Fraction> = durations.iter().map(|duration| fraction(duration)).collect();
+
+        let bar = TimeSignature::parse(signature).unwrap().bar(&durations).unwrap();
+
+        assert_that!(&bar.to_string()).is_equal_to(expected.to_string());
+    }
+}
+
+#[test]
+fn fill_bars_on_overflow() {
+    let signature = TimeSignature::parse("4/4").unwrap();
+
+    assert_that!(&signature.bar(&[fraction("2147483647"), fraction("1")])).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&signature.bar(&[fraction("-2147483647")])).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn split_dotted_notes() {
+    assert_that!(&split_dots("1/4.")).is_equal_to(("1/4", 1));
+    assert_that!(&split_dots("1/2..")).is_equal_to(("1/2", 2));
+    assert_that!(&split_dots("1/8")).is_equal_to(("1/8", 0));
+}
+
+#[test]
+fn compute_dotted_notes() {
+    assert_that!(&dotted(&fraction("1/4"), 1)).is_equal_to(Ok(fraction("3/8")));
+    assert_that!(&dotted(&fraction("1/4"), 2)).is_equal_to(Ok(fraction("7/16")));
+    assert_that!(&dotted(&fraction("1/2"), 0)).is_equal_to(Ok(fraction("1/2")));
+    assert_that!(&dotted(&fraction("1/2"), 31)).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&dotted(&fraction("2147483647/2"), 1)).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn compute_tuplets() {
+    assert_that!(&tuplet(&[3, 2], &fraction("1/8"))).is_equal_to(Ok(fraction("1/12")));
+    assert_that!(&tuplet(&[5, 4], &fraction("1/16"))).is_equal_to(Ok(fraction("1/20")));
+    assert_that!(&tuplet(&[3, 0], &fraction("1/8"))).is_equal_to(Err("Expected a positive ratio!"));
+    assert_that!(&tuplet(&[1, 2147483647], &fraction("2"))).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&tuplet(&[3, 2, 1], &fraction("1/8"))).is_equal_to(Err("Expected a ratio of two terms!"));
+}
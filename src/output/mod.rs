@@ -0,0 +1,200 @@
+use std::str::FromStr;
+use std::cmp::Ordering;
+use crate::fraction::Fraction;
+use crate::value::{percentage, Value};
+use crate::polynomial::Polynomial;
+use crate::probability;
+use crate::money::{self, Rounding};
+use crate::error::Error;
+use crate::json::Json;
+
+/// Supported ways to write evaluations out
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Output {
+    /// Human-readable text, e.g. `= 1_7/8` or `Error: Division by zero!`
+    #[default]
+    Text,
+    /// A JSON object per evaluation. Batch mode wraps all of them in a single array
+    Json,
+    /// A JSON object per evaluation, one per line, also in batch mode
+    JsonLines
+}
+
+impl FromStr for Output {
+    type Err = &'static str;
+
+    fn from_str(output: &str) -> Result<Output, &'static str> {
+        match output {
+            "text" => Ok(Output::Text),
+            "json" => Ok(Output::Json),
+            "jsonl" => Ok(Output::JsonLines),
+            _ => Err("Unsupported output!")
+        }
+    }
+}
+
+/// Builds the JSON object describing the evaluation of the given input.
+/// Successful evaluations describe numbers in several ways so consumers can pick the one they need, then add the details
+/// of other values. Booleans are given as is. Failed evaluations describe the error & the span of the input that caused it
+pub fn to_json(input: &str, evaluation: &Result<Value, Error>) -> Json {
+    match evaluation {
+        Ok(Value::Boolean(result)) => Json::object(vec![
+            ("input", Json::String(input.to_string())),
+            ("boolean", Json::Bool(*result))
+        ]),
+        Ok(value) => {
+            let mut members = vec![("input", Json::String(input.to_string()))];
+            if let Some(result) = value.as_number() {
+                members.extend(number_members(&result));
+            }
+            members.extend(details(value));
+            Json::object(members)
+        },
+        Err(error) => Json::object(vec![
+            ("input", Json::String(input.to_string())),
+            ("error", Json::object(vec![
+                ("kind", Json::String(error.kind.name().to_string())),
+                ("message", Json::String(error.message.to_string())),
+                ("span", Json::object(vec![
+                    ("start", Json::Number(error.span.start as f64)),
+                    ("end", Json::Number(error.span.end as f64))
+                ]))
+            ]))
+        ])
+    }
+}
+
+/// Numbers are described as a fraction, a mixed number & a decimal
+fn number_members(result: &Fraction) -> Vec<(&'static str, Json)> {
+    vec![
+        ("numerator", Json::Number(f64::from(result.numerator))),
+        ("denominator", Json::Number(f64::from(result.denominator))),
+        ("whole", Json::Number(f64::from(result.numerator / result.denominator))),
+        ("mixed", Json::String(result.to_string())),
+        ("decimal", Json::Number(f64::from(result.numerator) / f64::from(result.denominator)))
+    ]
+}
+
+/// Polynomials are given as text along with their coefficients from the lowest degree up
+fn polynomial_members(polynomial: &Polynomial) -> Vec<(&'static str, Json)> {
+    vec![
+        ("polynomial", Json::String(polynomial.to_string())),
+        ("variable", Json::String(polynomial.variable().to_string())),
+        ("coefficients", Json::Array(polynomial.coefficients().iter()
+            .map(|coefficient| Json::object(number_members(coefficient)))
+            .collect()))
+    ]
+}
+
+/// The members values add to the number they stand for, if any
+fn details(value: &Value) -> Vec<(&'static str, Json)> {
+    match value {
+        // Factorisations, common denominators & continued fractions detail the number they work out
+        Value::Factors { factors, .. } => vec![("factors", Json::Array(factors.iter()
+            .map(|&(prime, exponent)| Json::object(vec![
+                ("prime", Json::Number(f64::from(prime))),
+                ("exponent", Json::Number(f64::from(exponent)))
+            ]))
+            .collect()))],
+        Value::CommonDenominator { denominator, fractions } => vec![("rewritten", Json::Array(fractions.iter()
+            .map(|fraction| Json::String(format!(
+                "{}/{}", fraction.numerator * (denominator / fraction.denominator), denominator
+            )))
+            .collect()))],
+        Value::ContinuedFraction { terms, .. } => vec![("terms", Json::Array(terms.iter()
+            .map(|&term| Json::Number(f64::from(term)))
+            .collect()))],
+        // Denominators can be too large for JSON numbers to hold them exactly
+        Value::UnitFractions { denominators, .. } => vec![("unit_fractions", Json::Array(denominators.iter()
+            .map(|denominator| Json::String(format!("1/{}", denominator)))
+            .collect()))],
+        // Lists & matrices describe each of their numbers
+        Value::List(numbers) => vec![("list", Json::Array(numbers.iter()
+            .map(|number| Json::object(number_members(number)))
+            .collect()))],
+        Value::Ratio(terms) => vec![("ratio", Json::Array(terms.iter()
+            .map(|&term| Json::Number(f64::from(term)))
+            .collect()))],
+        Value::Matrix(matrix) => vec![("matrix", Json::Array(matrix.rows().iter()
+            .map(|row| Json::Array(row.iter().map(|entry| Json::object(number_members(entry))).collect()))
+            .collect()))],
+        Value::Polynomial(polynomial) => polynomial_members(polynomial),
+        // Quantities, times & bars describe their amount like numbers, along with what it measures
+        Value::Quantity(quantity) => {
+            let mut members = number_members(&quantity.amount);
+            members.push(("unit", Json::String(quantity.unit.name.to_string())));
+            members.push(("dimension", Json::String(quantity.unit.dimension.name().to_string())));
+            members
+        },
+        Value::Time(seconds) => {
+            let mut members = number_members(seconds);
+            members.push(("unit", Json::String("s".to_string())));
+            members
+        },
+        Value::Bar(bar) => {
+            let mut members = number_members(&bar.total);
+            members.push(("signature", Json::String(bar.signature.to_string())));
+            members.push(("fill", Json::String(match bar.fill() {
+                Ordering::Less => "underfull",
+                Ordering::Equal => "full",
+                Ordering::Greater => "overfull"
+            }.to_string())));
+            members
+        },
+        Value::Distribution(distribution) => vec![("distribution", Json::Array(distribution.outcomes().iter()
+            .map(|(outcome, probability)| {
+                let mut members = vec![("outcome", Json::Number(f64::from(*outcome)))];
+                members.extend(number_members(probability));
+                Json::object(members)
+            })
+            .collect()))],
+        // Probabilities are described as numbers, with their odds added
+        Value::Event(probability) => {
+            let (favourable, unfavourable) = probability::odds(probability);
+            vec![("odds", Json::String(format!("{}:{}", favourable, unfavourable)))]
+        },
+        Value::Interval(interval) => vec![
+            ("center", Json::object(number_members(&interval.center().unwrap_or_default()))),
+            ("tolerance", Json::object(number_members(&interval.half_width().unwrap_or_default()))),
+            ("lower", Json::object(number_members(&interval.lower))),
+            ("upper", Json::object(number_members(&interval.upper)))
+        ],
+        Value::Complex(complex) => vec![
+            ("real", Json::object(number_members(&complex.real))),
+            ("imaginary", Json::object(number_members(&complex.imaginary)))
+        ],
+        // Amounts of money are exact, `money` is what they're written as once rounded to whole cents
+        Value::Money(amount) => {
+            let mut members = number_members(amount);
+            members.push(("money", Json::String(money::format(amount, Rounding::default()))));
+            members
+        },
+        Value::Shares(shares) => vec![("shares", Json::Array(shares.iter()
+            .map(|share| {
+                let mut members = vec![("money", Json::String(money::format(share, Rounding::default())))];
+                members.extend(number_members(share));
+                Json::object(members)
+            })
+            .collect()))],
+        Value::Percent(fraction) => vec![("percent", Json::String(
+            percentage(fraction).map_or_else(|message| message.to_string(), |percent| percent.to_string())
+        ))],
+        Value::PolynomialDivision { quotient, remainder } => vec![
+            ("quotient", Json::object(polynomial_members(quotient))),
+            ("remainder", Json::object(polynomial_members(remainder)))
+        ],
+        // A single unknown is described as the number it's solved for, a system describes each of its unknowns
+        Value::Solution(solution) if solution.len() == 1 => vec![("variable", Json::String(solution[0].0.clone()))],
+        Value::Solution(solution) => vec![("solution", Json::Array(solution.iter()
+            .map(|(variable, value)| {
+                let mut members = vec![("variable", Json::String(variable.clone()))];
+                members.extend(number_members(value));
+                Json::object(members)
+            })
+            .collect()))],
+        _ => Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,268 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::Fraction;
+use crate::matrix::Matrix;
+use crate::polynomial::Polynomial;
+use crate::quantity::{self, Quantity};
+use crate::music::TimeSignature;
+use crate::probability::Distribution;
+use crate::interval::Interval;
+use crate::complex::Complex;
+
+#[test]
+fn parse_supported_outputs() {
+    assert_that!(&"text".parse::<Output>()).is_equal_to(Ok(Output::Text));
+    assert_that!(&"json".parse::<Output>()).is_equal_to(Ok(Output::Json));
+    assert_that!(&"jsonl".parse::<Output>()).is_equal_to(Ok(Output::JsonLines));
+}
+
+#[test]
+fn successful_evaluation_to_json() {
+    let evaluation = Ok(Value::Number(Fraction::new(-57, 14).unwrap()));
+
+    let actual = to_json("1_3/7 - 5_2/4", &evaluation).to_string();
+
+    assert_that!(actual.as_str())
+        .is_equal_to(r#"{"input":"1_3/7 - 5_2/4","numerator":-57,"denominator":14,"whole":-4,"mixed":"-4_1/14","decimal":-4.071428571428571}"#);
+}
+
+#[test]
+fn boolean_evaluation_to_json() {
+    let evaluation = Ok(Value::Boolean(false));
+
+    let actual = to_json("5_3/8 > 43/8", &evaluation).to_string();
+
+    assert_that!(actual.as_str())
+        .is_equal_to(r#"{"input":"5_3/8 > 43/8","boolean":false}"#);
+}
+
+#[test]
+fn factorisation_to_json() {
+    let evaluation = Ok(Value::Factors { number: 12, factors: vec![(2, 2), (3, 1)] });
+
+    let actual = to_json("factor(12)", &evaluation).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"factor(12)","numerator":12,"denominator":1,"whole":12,"mixed":"12","decimal":12,"#,
+        r#""factors":[{"prime":2,"exponent":2},{"prime":3,"exponent":1}]}"#
+    ));
+}
+
+#[test]
+fn common_denominator_to_json() {
+    let fractions = vec![Fraction::new(1, 6).unwrap(), Fraction::new(3, 8).unwrap()];
+    let evaluation = Ok(Value::CommonDenominator { denominator: 24, fractions });
+
+    let actual = to_json("lcd(1/6, 3/8)", &evaluation).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"lcd(1/6, 3/8)","numerator":24,"denominator":1,"whole":24,"mixed":"24","decimal":24,"#,
+        r#""rewritten":["4/24","9/24"]}"#
+    ));
+}
+
+#[test]
+fn continued_fraction_to_json() {
+    let evaluation = Ok(Value::ContinuedFraction { value: Fraction::new(9, 2).unwrap(), terms: vec![4, 2] });
+
+    let actual = to_json("cf(9/2)", &evaluation).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"cf(9/2)","numerator":9,"denominator":2,"whole":4,"mixed":"4_1/2","decimal":4.5,"#,
+        r#""terms":[4,2]}"#
+    ));
+}
+
+#[test]
+fn list_to_json() {
+    let evaluation = Ok(Value::List(vec![Fraction::from(4), Fraction::new(9, 2).unwrap()]));
+
+    let actual = to_json("convergents(9/2)", &evaluation).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"convergents(9/2)","list":["#,
+        r#"{"numerator":4,"denominator":1,"whole":4,"mixed":"4","decimal":4},"#,
+        r#"{"numerator":9,"denominator":2,"whole":4,"mixed":"4_1/2","decimal":4.5}]}"#
+    ));
+}
+
+#[test]
+fn unit_fractions_to_json() {
+    let evaluation = Ok(Value::UnitFractions { value: Fraction::new(3, 4).unwrap(), denominators: vec![2, 4] });
+
+    let actual = to_json("egyptian(3/4)", &evaluation).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"egyptian(3/4)","numerator":3,"denominator":4,"whole":0,"mixed":"3/4","decimal":0.75,"#,
+        r#""unit_fractions":["1/2","1/4"]}"#
+    ));
+}
+
+#[test]
+fn failed_evaluation_to_json() {
+    let evaluation = Err(Error::new("Division by zero!", 8, 12));
+
+    let actual = to_json("25/13 / 0/11", &evaluation).to_string();
+
+    assert_that!(actual.as_str())
+        .is_equal_to(r#"{"input":"25/13 / 0/11","error":{"kind":"division_by_zero","message":"Division by zero!","span":{"start":8,"end":12}}}"#);
+}
+
+#[test]
+fn ratio_to_json() {
+    let evaluation = Ok(Value::Ratio(vec![3, 4]));
+
+    let actual = to_json("1_1/2 : 2", &evaluation).to_string();
+
+    assert_that!(actual.as_str())
+        .is_equal_to(r#"{"input":"1_1/2 : 2","ratio":[3,4]}"#);
+}
+
+#[test]
+fn solution_to_json() {
+    let evaluation = Ok(Value::Solution(vec![("x".to_string(), Fraction::new(5, 4).unwrap())]));
+
+    let actual = to_json("3/4 : 6 = x : 10", &evaluation).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"3/4 : 6 = x : 10","numerator":5,"denominator":4,"whole":1,"mixed":"1_1/4","decimal":1.25,"#,
+        r#""variable":"x"}"#
+    ));
+}
+
+#[test]
+fn matrix_to_json() {
+    let matrix = Matrix::new(vec![vec![Fraction::from(1), Fraction::new(1, 2).unwrap()]]).unwrap();
+    let evaluation = Ok(Value::Matrix(matrix));
+
+    let actual = to_json("[[1, 1/2]]", &evaluation).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"[[1, 1/2]]","matrix":[["#,
+        r#"{"numerator":1,"denominator":1,"whole":1,"mixed":"1","decimal":1},"#,
+        r#"{"numerator":1,"denominator":2,"whole":0,"mixed":"1/2","decimal":0.5}]]}"#
+    ));
+}
+
+#[test]
+fn polynomial_to_json() {
+    let polynomial = Polynomial::new(vec![Fraction::from(1), Fraction::new(-1, 2).unwrap()], "t");
+    let evaluation = Ok(Value::Polynomial(polynomial));
+
+    let actual = to_json("poly(1 - 1/2 t)", &evaluation).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"poly(1 - 1/2 t)","polynomial":"-1/2 t + 1","variable":"t","coefficients":["#,
+        r#"{"numerator":1,"denominator":1,"whole":1,"mixed":"1","decimal":1},"#,
+        r#"{"numerator":-1,"denominator":2,"whole":0,"mixed":"-1/2","decimal":-0.5}]}"#
+    ));
+}
+
+#[test]
+fn time_to_json() {
+    let evaluation = Ok(Value::Time(Fraction::new(9, 2).unwrap()));
+
+    let actual = to_json("0:00:01;15 * 3", &evaluation).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"0:00:01;15 * 3","numerator":9,"denominator":2,"whole":4,"mixed":"4_1/2","decimal":4.5,"#,
+        r#""unit":"s"}"#
+    ));
+}
+
+#[test]
+fn bar_to_json() {
+    let bar = TimeSignature::parse("7/8").unwrap().bar(&[Fraction::new(1, 2).unwrap(), Fraction::new(1, 4).unwrap()]).unwrap();
+
+    let actual = to_json("bar(7/8, 1/2, 1/4)", &Ok(Value::Bar(bar))).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"bar(7/8, 1/2, 1/4)","numerator":3,"denominator":4,"whole":0,"mixed":"3/4","decimal":0.75,"#,
+        r#""signature":"7/8","fill":"underfull"}"#
+    ));
+}
+
+#[test]
+fn quantity_to_json() {
+    let evaluation = Ok(Value::Quantity(Quantity::new(Fraction::new(3, 2).unwrap(), quantity::lookup("in").unwrap())));
+
+    let actual = to_json("3/4 in * 2", &evaluation).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"3/4 in * 2","numerator":3,"denominator":2,"whole":1,"mixed":"1_1/2","decimal":1.5,"#,
+        r#""unit":"in","dimension":"length"}"#
+    ));
+}
+
+#[test]
+fn probability_to_json() {
+    let actual = to_json("P(2d6 = 7)", &Ok(Value::Event(Fraction::new(1, 6).unwrap()))).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"P(2d6 = 7)","numerator":1,"denominator":6,"whole":0,"mixed":"1/6","#,
+        r#""decimal":0.16666666666666666,"odds":"1:5"}"#
+    ));
+}
+
+#[test]
+fn distribution_to_json() {
+    let actual = to_json("1d2", &Ok(Value::Distribution(Distribution::dice(1, 2).unwrap()))).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"1d2","distribution":[{"outcome":1,"numerator":1,"denominator":2,"whole":0,"mixed":"1/2","decimal":0.5},"#,
+        r#"{"outcome":2,"numerator":1,"denominator":2,"whole":0,"mixed":"1/2","decimal":0.5}]}"#
+    ));
+}
+
+#[test]
+fn interval_to_json() {
+    let interval = Interval::new(Fraction::new(1, 2).unwrap(), Fraction::from(1));
+
+    let actual = to_json("3/4 ± 1/4", &Ok(Value::Interval(interval))).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"3/4 ± 1/4","center":{"numerator":3,"denominator":4,"whole":0,"mixed":"3/4","decimal":0.75},"#,
+        r#""tolerance":{"numerator":1,"denominator":4,"whole":0,"mixed":"1/4","decimal":0.25},"#,
+        r#""lower":{"numerator":1,"denominator":2,"whole":0,"mixed":"1/2","decimal":0.5},"#,
+        r#""upper":{"numerator":1,"denominator":1,"whole":1,"mixed":"1","decimal":1}}"#
+    ));
+}
+
+#[test]
+fn complex_to_json() {
+    let complex = Complex::new(Fraction::new(1, 2).unwrap(), Fraction::from(-1));
+
+    let actual = to_json("1/2 - 1i", &Ok(Value::Complex(complex))).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"1/2 - 1i","real":{"numerator":1,"denominator":2,"whole":0,"mixed":"1/2","decimal":0.5},"#,
+        r#""imaginary":{"numerator":-1,"denominator":1,"whole":-1,"mixed":"-1","decimal":-1}}"#
+    ));
+}
+
+#[test]
+fn percent_to_json() {
+    let actual = to_json("3/8 as %", &Ok(Value::Percent(Fraction::new(3, 8).unwrap()))).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"3/8 as %","numerator":3,"denominator":8,"whole":0,"mixed":"3/8","decimal":0.375,"#,
+        r#""percent":"37_1/2"}"#
+    ));
+}
+
+#[test]
+fn money_to_json() {
+    let shares = vec![Fraction::new(17, 50).unwrap(), Fraction::new(33, 100).unwrap()];
+
+    let actual = to_json("$100 / 3", &Ok(Value::Money(Fraction::new(100, 3).unwrap()))).to_string();
+    let split = to_json("split($0.67, 2)", &Ok(Value::Shares(shares))).to_string();
+
+    assert_that!(actual.as_str()).is_equal_to(concat!(
+        r#"{"input":"$100 / 3","numerator":100,"denominator":3,"whole":33,"mixed":"33_1/3","#,
+        r#""decimal":33.333333333333336,"money":"$33.33"}"#
+    ));
+    assert_that!(split.as_str()).is_equal_to(concat!(
+        r#"{"input":"split($0.67, 2)","shares":[{"money":"$0.34","numerator":17,"denominator":50,"whole":0,"mixed":"17/50","decimal":0.34},"#,
+        r#"{"money":"$0.33","numerator":33,"denominator":100,"whole":0,"mixed":"33/100","decimal":0.33}]}"#
+    ));
+}
@@ -0,0 +1,244 @@
+use std::fmt;
+use std::convert::TryFrom;
+use crate::fraction::Fraction;
+use crate::math;
+
+/// Models a polynomial in a single variable with fractions as coefficients, e.g. `1/2 x^2 - 3/4 x + 1`.
+/// Coefficients are kept from the lowest degree up, without zeros on the highest degrees
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    coefficients: Vec<Fraction>,
+    variable: String
+}
+
+impl Polynomial {
+
+    /// Creates a polynomial from its coefficients, from the lowest degree up, e.g. `[1, -3/4, 1/2]` for `1/2 x^2 - 3/4 x + 1`
+    pub fn new(mut coefficients: Vec<Fraction>, variable: &str) -> Polynomial {
+        while coefficients.last().is_some_and(|coefficient| coefficient.numerator == 0) {
+            coefficients.pop();
+        }
+
+        Polynomial { coefficients, variable: variable.to_string() }
+    }
+
+    /// Coefficients from the lowest degree up. The zero polynomial has none
+    pub fn coefficients(&self) -> &[Fraction] {
+        &self.coefficients
+    }
+
+    pub fn variable(&self) -> &str {
+        &self.variable
+    }
+
+    /// The degree of the polynomial, which is undefined for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// The variable both polynomials can share. Constants take the variable of the other polynomial
+    fn common_variable(&self, polynomial: &Polynomial) -> Result<String, &'static str> {
+        match (self.degree(), polynomial.degree()) {
+            (Some(1..), Some(1..)) if self.variable != polynomial.variable => Err("Polynomial variables don't match!"),
+            (Some(1..), _) => Ok(self.variable.clone()),
+            _ => Ok(polynomial.variable.clone())
+        }
+    }
+
+    pub fn add(&self, polynomial: &Polynomial) -> Result<Polynomial, &'static str> {
+        let variable = self.common_variable(polynomial)?;
+        let length = self.coefficients.len().max(polynomial.coefficients.len());
+        let coefficients = (0..length)
+            .map(|degree| self.coefficient(degree).checked_add(&polynomial.coefficient(degree)))
+            .collect::<Result<Vec<Fraction>, &'static str>>()?;
+
+        Ok(Polynomial::new(coefficients, &variable))
+    }
+
+    pub fn substract(&self, polynomial: &Polynomial) -> Result<Polynomial, &'static str> {
+        self.add(&polynomial.scale(&Fraction::from(-1))?)
+    }
+
+    pub fn multiply(&self, polynomial: &Polynomial) -> Result<Polynomial, &'static str> {
+        let variable = self.common_variable(polynomial)?;
+        if self.is_zero() || polynomial.is_zero() {
+            return Ok(Polynomial::new(Vec::new(), &variable));
+        }
+
+        let mut coefficients = vec![Fraction::default(); self.coefficients.len() + polynomial.coefficients.len() - 1];
+        for (i, x) in self.coefficients.iter().enumerate() {
+            for (j, y) in polynomial.coefficients.iter().enumerate() {
+                coefficients[i + j] = coefficients[i + j].checked_add(&x.checked_multiply(y)?)?;
+            }
+        }
+        Ok(Polynomial::new(coefficients, &variable))
+    }
+
+    /// Multiplies every coefficient by the given number
+    pub fn scale(&self, factor: &Fraction) -> Result<Polynomial, &'static str> {
+        let coefficients = self.coefficients.iter()
+            .map(|coefficient| coefficient.checked_multiply(factor))
+            .collect::<Result<Vec<Fraction>, &'static str>>()?;
+
+        Ok(Polynomial::new(coefficients, &self.variable))
+    }
+
+    /// Divides by another polynomial using long division, giving the quotient & the remainder,
+    /// e.g. `x^2 + 1` divided by `x - 1` is `x + 1` with `2` as remainder
+    pub fn divide(&self, divisor: &Polynomial) -> Result<(Polynomial, Polynomial), &'static str> {
+        let variable = self.common_variable(divisor)?;
+        let divisor_degree = divisor.degree().ok_or("Division by zero!")?;
+        let leading = divisor.coefficients[divisor_degree];
+
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![Fraction::default(); remainder.len().saturating_sub(divisor_degree)];
+        while remainder.len() > divisor_degree {
+            let degree = remainder.len() - 1;
+            let factor = remainder[degree].divide(&leading)?;
+            quotient[degree - divisor_degree] = factor;
+            for (index, coefficient) in divisor.coefficients.iter().enumerate() {
+                let term = &mut remainder[degree - divisor_degree + index];
+                *term = term.checked_substract(&coefficient.checked_multiply(&factor)?)?;
+            }
+            remainder.pop();
+        }
+
+        Ok((Polynomial::new(quotient, &variable), Polynomial::new(remainder, &variable)))
+    }
+
+    /// Evaluates the polynomial at the given number using Horner's method
+    pub fn evaluate(&self, x: &Fraction) -> Result<Fraction, &'static str> {
+        self.coefficients.iter().rev()
+            .try_fold(Fraction::default(), |result, coefficient| result.checked_multiply(x)?.checked_add(coefficient))
+    }
+
+    pub fn derivative(&self) -> Result<Polynomial, &'static str> {
+        let coefficients = self.coefficients.iter().enumerate().skip(1)
+            .map(|(degree, coefficient)| coefficient.checked_multiply(&Fraction::from(degree as i32)))
+            .collect::<Result<Vec<Fraction>, &'static str>>()?;
+
+        Ok(Polynomial::new(coefficients, &self.variable))
+    }
+
+    /// Computes the greatest common divisor using the Euclidean algorithm. It's made monic, i.e. its leading
+    /// coefficient is `1`, so it's unique
+    pub fn gcd(&self, polynomial: &Polynomial) -> Result<Polynomial, &'static str> {
+        let (mut a, mut b) = (self.clone(), polynomial.clone());
+        a.variable = self.common_variable(polynomial)?;
+
+        while !b.is_zero() {
+            let (_, remainder) = a.divide(&b)?;
+            a = b;
+            b = remainder;
+        }
+        match a.coefficients.last() {
+            Some(leading) => a.scale(&leading.recip()?),
+            None => Ok(a)
+        }
+    }
+
+    /// Finds the rational roots, from the lowest up, using the rational root theorem: once the coefficients are made whole,
+    /// every rational root `p/q` in lowest terms has `p` dividing the lowest non-zero coefficient & `q` the leading one
+    pub fn rational_roots(&self) -> Result<Vec<Fraction>, &'static str> {
+        if self.is_zero() {
+            return Err("Expected a non-zero polynomial!");
+        }
+
+        let scale = math::lcd(&self.coefficients)?;
+        let whole: Vec<i128> = self.coefficients.iter()
+            .map(|coefficient| i128::from(coefficient.numerator) * i128::from(scale / coefficient.denominator))
+            .collect();
+        let lowest = whole.iter().position(|&coefficient| coefficient != 0).unwrap_or_default();
+        let whole = &whole[lowest..];
+
+        let mut roots = if lowest > 0 { vec![Fraction::default()] } else { Vec::new() };
+        for p in divisors(whole[0])? {
+            for q in divisors(whole[whole.len() - 1])? {
+                for numerator in [p, -p] {
+                    let candidate = Fraction::new(numerator, q)?;
+                    if !roots.contains(&candidate) && is_root(whole, &candidate)? {
+                        roots.push(candidate);
+                    }
+                }
+            }
+        }
+        roots.sort();
+        Ok(roots)
+    }
+
+    fn coefficient(&self, degree: usize) -> Fraction {
+        self.coefficients.get(degree).copied().unwrap_or_default()
+    }
+}
+
+/// The positive divisors of a whole number
+fn divisors(number: i128) -> Result<Vec<i32>, &'static str> {
+    let number = i32::try_from(number.abs()).map_err(|_| "Integer overflow!")?;
+
+    Ok((1..=number).take_while(|&divisor| i64::from(divisor) * i64::from(divisor) <= i64::from(number))
+        .filter(|divisor| number % divisor == 0)
+        .flat_map(|divisor| if divisor * divisor == number { vec![divisor] } else { vec![divisor, number / divisor] })
+        .collect())
+}
+
+/// Checks whether `p/q` is a root of a polynomial with whole coefficients by checking the sum of `a_i * p^i * q^(n-i)`
+/// is zero, which keeps the whole computation in integers
+fn is_root(coefficients: &[i128], candidate: &Fraction) -> Result<bool, &'static str> {
+    let (p, q) = (i128::from(candidate.numerator), i128::from(candidate.denominator));
+    let degree = coefficients.len() - 1;
+    let mut sum: i128 = 0;
+
+    for (i, coefficient) in coefficients.iter().enumerate() {
+        let term = p.checked_pow(i as u32)
+            .and_then(|power| q.checked_pow((degree - i) as u32).and_then(|other| power.checked_mul(other)))
+            .and_then(|term| term.checked_mul(*coefficient))
+            .ok_or("Integer overflow!")?;
+        sum = sum.checked_add(term).ok_or("Integer overflow!")?;
+    }
+    Ok(sum == 0)
+}
+
+/// Implementation to make `Polynomial` displayable from the highest degree down, e.g. `1/2 x^2 - 3/4 x + 1`.
+/// Coefficients are displayed like `Fraction`, so as mixed numbers, leaving out coefficients of `1`
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let mut text = String::new();
+        for (degree, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if coefficient.numerator == 0 {
+                continue;
+            }
+
+            let negative = coefficient.numerator < 0;
+            match (text.is_empty(), negative) {
+                (true, true) => text.push('-'),
+                (true, false) => {},
+                (false, true) => text.push_str(" - "),
+                (false, false) => text.push_str(" + ")
+            }
+
+            let coefficient = coefficient.abs();
+            let power = match degree {
+                0 => String::new(),
+                1 => self.variable.clone(),
+                _ => format!("{}^{}", self.variable, degree)
+            };
+            match (degree, coefficient == Fraction::from(1)) {
+                (0, _) => text.push_str(&coefficient.to_string()),
+                (_, true) => text.push_str(&power),
+                (_, false) => text.push_str(&format!("{} {}", coefficient, power))
+            }
+        }
+        write!(f, "{}", text)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,143 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+// This function will help build the polynomials used in tests below
+fn polynomial(coefficients: &[&str]) -> Polynomial {
+    Polynomial::new(coefficients.iter().map(|coefficient| fraction(coefficient)).collect(), "x")
+}
+
+#[test]
+fn new_trims_highest_zeros() {
+    let p = polynomial(&["1", "2", "0", "0"]);
+
+    assert_that!(&p.degree()).is_equal_to(Some(1));
+    assert_that!(&polynomial(&["0"]).degree()).is_none();
+}
+
+#[test]
+fn display_polynomials() {
+    let display_test_cases = [
+        (polynomial(&["1", "-3/4", "1/2"]), "1/2 x^2 - 3/4 x + 1"),
+        (polynomial(&["0", "1", "0", "-1"]), "-x^3 + x"),
+        (polynomial(&["-5/2", "0", "1_1/2"]), "1_1/2 x^2 - 2_1/2"),
+        (polynomial(&[]), "0")
+    ];
+
+    for (p, expected) in &display_test_cases {
+        assert_that!(p.to_string().as_str())
+            .is_equal_to(expected);
+    }
+}
+
+#[test]
+fn add_and_multiply() {
+    let p = polynomial(&["1", "1"]);
+    let q = polynomial(&["-1", "1"]);
+
+    assert_that!(&p.add(&q)).is_equal_to(Ok(polynomial(&["0", "2"])));
+    assert_that!(&p.substract(&q)).is_equal_to(Ok(polynomial(&["2"])));
+    assert_that!(&p.multiply(&q)).is_equal_to(Ok(polynomial(&["-1", "0", "1"])));
+}
+
+#[test]
+fn combine_polynomials_in_different_variables() {
+    let p = polynomial(&["1", "1"]);
+    let q = Polynomial::new(vec![fraction("1"), fraction("1")], "y");
+
+    assert_that!(&p.add(&q))
+        .is_equal_to(Err("Polynomial variables don't match!"));
+    assert_that!(&q.add(&polynomial(&["2"])))
+        .is_equal_to(Ok(Polynomial::new(vec![fraction("3"), fraction("1")], "y")));
+}
+
+#[test]
+fn divide_with_remainder() {
+    let p = polynomial(&["1", "0", "1"]);
+    let q = polynomial(&["-1", "1"]);
+
+    assert_that!(&p.divide(&q))
+        .is_equal_to(Ok((polynomial(&["1", "1"]), polynomial(&["2"]))));
+    assert_that!(&q.divide(&polynomial(&["1/2"])))
+        .is_equal_to(Ok((polynomial(&["-2", "2"]), polynomial(&[]))));
+}
+
+#[test]
+fn divide_by_zero_polynomial() {
+    assert_that!(&polynomial(&["1"]).divide(&polynomial(&[])))
+        .is_equal_to(Err("Division by zero!"));
+}
+
+#[test]
+fn evaluate_at_fraction() {
+    let p = polynomial(&["1", "-3/4", "1/2"]);
+
+    assert_that!(&p.evaluate(&fraction("1/2")))
+        .is_equal_to(Ok(fraction("3/4")));
+}
+
+#[test]
+fn differentiate() {
+    let p = polynomial(&["1", "-3/4", "1/2", "2"]);
+
+    assert_that!(&p.derivative())
+        .is_equal_to(Ok(polynomial(&["-3/4", "1", "6"])));
+}
+
+#[test]
+fn operations_on_overflow() {
+    let p = polynomial(&["0", "2147483647"]);
+
+    assert_that!(&p.add(&p)).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&p.multiply(&polynomial(&["0", "2"]))).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&p.scale(&fraction("2"))).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&polynomial(&["0", "0", "2147483647"]).derivative()).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&p.divide(&polynomial(&["1/2", "1/2147483647"]))).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn evaluate_on_overflow() {
+    let mut coefficients = vec!["0"; 100];
+    coefficients.push("1");
+
+    assert_that!(&polynomial(&coefficients).evaluate(&fraction("2")))
+        .is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn compute_gcd() {
+    // (x - 1)(x + 2) & 3 (x - 1)(x - 3)
+    let p = polynomial(&["-2", "1", "1"]);
+    let q = polynomial(&["9", "-12", "3"]);
+
+    assert_that!(&p.gcd(&q))
+        .is_equal_to(Ok(polynomial(&["-1", "1"])));
+}
+
+#[test]
+fn find_rational_roots() {
+    let roots_test_cases: [(&[&str], &[&str]); 4] = [
+        // 1/2 x^2 - 3/4 x + 1/4 = 1/2 (x - 1)(x - 1/2)
+        (&["1/4", "-3/4", "1/2"], &["1/2", "1"]),
+        // x^3 - x = x (x - 1)(x + 1)
+        (&["0", "-1", "0", "1"], &["-1", "0", "1"]),
+        // 6 x^2 + x - 2 = (2 x - 1)(3 x + 2)
+        (&["-2", "1", "6"], &["-2/3", "1/2"]),
+        // x^2 + 1 has no rational roots
+        (&["1", "0", "1"], &[])
+    ];
+
+    for (coefficients, roots) in &roots_test_cases {
+        println!("Finding roots of {:?}", coefficients);
+        let expected: Vec<Fraction> = roots.iter().map(|root| fraction(root)).collect();
+
+        assert_that!(&polynomial(coefficients).rational_roots())
+            .is_equal_to(Ok(expected));
+    }
+}
+
+#[test]
+fn find_roots_of_zero_polynomial() {
+    assert_that!(&polynomial(&[]).rational_roots())
+        .is_equal_to(Err("Expected a non-zero polynomial!"));
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+use std::collections::BTreeMap;
+use crate::fraction::Fraction;
+
+/// Largest number of dice, and of sides of a die, a roll can have
+const MAX_DICE: i32 = 100;
+
+/// Models the distribution of a discrete random variable with whole outcomes, e.g. the sum of a roll of `2d6`.
+/// Each outcome is kept along with how many of the equally likely ways it happens, out of a total number of them,
+/// so distributions are combined exactly. The total must fit in a fraction, so every probability does too
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    ways: BTreeMap<i32, i128>,
+    total: i128
+}
+
+impl Distribution {
+
+    /// The distribution of the sum of a roll of several dice, e.g. `3d6`
+    pub fn dice(count: i32, sides: i32) -> Result<Distribution, &'static str> {
+        if !(1..=MAX_DICE).contains(&count) || !(1..=MAX_DICE).contains(&sides) {
+            return Err("Expected 1 to 100 dice with 1 to 100 sides!");
+        }
+
+        let die = Distribution { ways: (1..=sides).map(|side| (side, 1)).collect(), total: i128::from(sides) };
+        let mut roll = die.clone();
+        for _ in 1..count {
+            roll = roll.combine(&die, i32::checked_add)?;
+        }
+        Ok(roll)
+    }
+
+    /// The distribution of a value that's certain
+    pub fn constant(value: i32) -> Distribution {
+        Distribution { ways: BTreeMap::from([(value, 1)]), total: 1 }
+    }
+
+    /// The distribution of the outcomes of both distributions combined with an operation, taking them as independent,
+    /// e.g. adding `1d6` twice gives the distribution of `2d6`
+    pub fn combine(&self, other: &Distribution, operation: fn(i32, i32) -> Option<i32>) -> Result<Distribution, &'static str> {
+        let mut ways = BTreeMap::new();
+        for (&a, &a_ways) in &self.ways {
+            for (&b, &b_ways) in &other.ways {
+                let outcome = operation(a, b).ok_or("Integer overflow!")?;
+                let count = ways.entry(outcome).or_insert(0_i128);
+                *count = a_ways.checked_mul(b_ways)
+                    .and_then(|product| count.checked_add(product))
+                    .ok_or("Integer overflow!")?;
+            }
+        }
+
+        let total = self.total.checked_mul(other.total)
+            .filter(|&total| total <= i128::from(i32::MAX))
+            .ok_or("Integer overflow!")?;
+        Ok(Distribution { ways, total })
+    }
+
+    /// The probability that the outcomes of both distributions, taken as independent, satisfy a relation,
+    /// e.g. that `2d6` is `7`
+    pub fn probability(&self, other: &Distribution, relation: impl Fn(i32, i32) -> bool) -> Result<Fraction, &'static str> {
+        let mut ways = 0_i128;
+        for (&a, &a_ways) in &self.ways {
+            for (_, &b_ways) in other.ways.iter().filter(|(&b, _)| relation(a, b)) {
+                ways = a_ways.checked_mul(b_ways).and_then(|product| ways.checked_add(product)).ok_or("Integer overflow!")?;
+            }
+        }
+
+        Fraction::reduce(ways, self.total.checked_mul(other.total).ok_or("Integer overflow!")?)
+    }
+
+    /// The mean of the outcomes weighted by their probabilities, e.g. `4_1/2` for `1d8`
+    pub fn expected_value(&self) -> Result<Fraction, &'static str> {
+        let sum = self.ways.iter()
+            .try_fold(0_i128, |sum, (&outcome, &ways)| ways.checked_mul(i128::from(outcome)).and_then(|term| sum.checked_add(term)))
+            .ok_or("Integer overflow!")?;
+
+        Fraction::reduce(sum, self.total)
+    }
+
+    /// The outcomes along with their probabilities, from the lowest outcome up
+    pub fn outcomes(&self) -> Vec<(i32, Fraction)> {
+        self.ways.iter()
+            .map(|(&outcome, &ways)| (outcome, Fraction::reduce(ways, self.total).unwrap_or_default()))
+            .collect()
+    }
+}
+
+/// Implementation to make `Distribution` displayable as its outcomes & their probabilities, e.g. `1: 1/2, 2: 1/2`
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let outcomes: Vec<String> = self.outcomes().iter()
+            .map(|(outcome, probability)| format!("{}: {}", outcome, probability))
+            .collect();
+        write!(f, "{}", outcomes.join(", "))
+    }
+}
+
+/// The probability that an event doesn't happen
+pub fn complement(p: &Fraction) -> Result<Fraction, &'static str> {
+    Fraction::reduce(i128::from(p.denominator) - i128::from(p.numerator), i128::from(p.denominator))
+}
+
+/// The probability that two independent events both happen
+pub fn intersection(p: &Fraction, q: &Fraction) -> Result<Fraction, &'static str> {
+    Fraction::reduce(i128::from(p.numerator) * i128::from(q.numerator), i128::from(p.denominator) * i128::from(q.denominator))
+}
+
+/// The probability that at least one of two independent events happens, i.e. that neither fails to
+pub fn union(p: &Fraction, q: &Fraction) -> Result<Fraction, &'static str> {
+    complement(&intersection(&complement(p)?, &complement(q)?)?)
+}
+
+/// The odds in favour of an event of the given probability, i.e. how many ways it happens against how many it doesn't,
+/// e.g. `1 : 5` for a probability of `1/6`
+pub fn odds(p: &Fraction) -> (i32, i32) {
+    (p.numerator, p.denominator - p.numerator)
+}
+
+#[cfg(test)]
+mod tests;
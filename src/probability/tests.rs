@@ -0,0 +1,72 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+#[test]
+fn roll_dice() {
+    let roll = Distribution::dice(2, 6).unwrap();
+
+    assert_that!(&roll.outcomes()[..3].to_vec()).is_equal_to(vec![
+        (2, fraction("1/36")), (3, fraction("1/18")), (4, fraction("1/12"))
+    ]);
+    assert_that!(&roll.outcomes().len()).is_equal_to(11);
+    assert_that!(&Distribution::dice(1, 2).unwrap().to_string()).is_equal_to("1: 1/2, 2: 1/2".to_string());
+}
+
+#[test]
+fn roll_invalid_dice() {
+    assert_that!(&Distribution::dice(0, 6)).is_equal_to(Err("Expected 1 to 100 dice with 1 to 100 sides!"));
+    assert_that!(&Distribution::dice(2, 101)).is_equal_to(Err("Expected 1 to 100 dice with 1 to 100 sides!"));
+    assert_that!(&Distribution::dice(100, 100)).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&Distribution::dice(12, 6)).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&Distribution::dice(11, 6).map(|roll| roll.outcomes().len())).is_equal_to(Ok(56));
+}
+
+#[test]
+fn compute_probabilities() {
+    let exactly: fn(i32, i32) -> bool = |a, b| a == b;
+    let at_least: fn(i32, i32) -> bool = |a, b| a >= b;
+    let probability_test_cases = [
+        (2, 6, exactly, 7, "1/6"),
+        (3, 6, at_least, 15, "5/54"),
+        (1, 20, at_least, 20, "1/20")
+    ];
+
+    for &(count, sides, relation, outcome, expected) in &probability_test_cases {
+        println!("Computing a probability of {}d{} & {}", count, sides, outcome);
+        let roll = Distribution::dice(count, sides).unwrap();
+
+        assert_that!(&roll.probability(&Distribution::constant(outcome), relation))
+            .is_equal_to(Ok(fraction(expected)));
+    }
+}
+
+#[test]
+fn combine_distributions() {
+    let d6 = Distribution::dice(1, 6).unwrap();
+
+    assert_that!(&d6.combine(&d6, i32::checked_add)).is_equal_to(Distribution::dice(2, 6));
+    assert_that!(&d6.combine(&Distribution::constant(2), i32::checked_add).unwrap().expected_value())
+        .is_equal_to(Ok(fraction("5_1/2")));
+    assert_that!(&d6.probability(&d6, |a, b| a > b)).is_equal_to(Ok(fraction("5/12")));
+}
+
+#[test]
+fn compute_expected_values() {
+    assert_that!(&Distribution::dice(1, 8).unwrap().expected_value()).is_equal_to(Ok(fraction("4_1/2")));
+    assert_that!(&Distribution::dice(3, 6).unwrap().expected_value()).is_equal_to(Ok(fraction("10_1/2")));
+}
+
+#[test]
+fn combine_independent_events() {
+    assert_that!(&complement(&fraction("1/6"))).is_equal_to(Ok(fraction("5/6")));
+    assert_that!(&intersection(&fraction("1/6"), &fraction("1/2"))).is_equal_to(Ok(fraction("1/12")));
+    assert_that!(&union(&fraction("1/6"), &fraction("1/2"))).is_equal_to(Ok(fraction("7/12")));
+}
+
+#[test]
+fn compute_odds() {
+    assert_that!(&odds(&fraction("1/6"))).is_equal_to((1, 5));
+    assert_that!(&odds(&fraction("5/54"))).is_equal_to((5, 49));
+    assert_that!(&odds(&fraction("1"))).is_equal_to((1, 0));
+}
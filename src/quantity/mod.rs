@@ -0,0 +1,144 @@
+use std::fmt;
+use std::cmp::Ordering;
+use crate::fraction::Fraction;
+
+/// What a unit measures. Only quantities of the same dimension can be added, compared or converted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Length,
+    Mass,
+    Volume,
+    Time
+}
+
+impl Dimension {
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Dimension::Length => "length",
+            Dimension::Mass => "mass",
+            Dimension::Volume => "volume",
+            Dimension::Time => "time"
+        }
+    }
+}
+
+/// Models a unit with its exact size in the base unit of its dimension, i.e. metres, grams, litres or seconds
+#[derive(Debug, PartialEq, Eq)]
+pub struct Unit {
+    pub name: &'static str,
+    pub dimension: Dimension,
+    numerator: i64,
+    denominator: i64
+}
+
+// US customary volumes are defined from the gallon, which is exactly 3.785411784 litres
+static UNITS: [Unit; 28] = [
+    Unit { name: "mm", dimension: Dimension::Length, numerator: 1, denominator: 1000 },
+    Unit { name: "cm", dimension: Dimension::Length, numerator: 1, denominator: 100 },
+    Unit { name: "m", dimension: Dimension::Length, numerator: 1, denominator: 1 },
+    Unit { name: "km", dimension: Dimension::Length, numerator: 1000, denominator: 1 },
+    Unit { name: "in", dimension: Dimension::Length, numerator: 127, denominator: 5000 },
+    Unit { name: "ft", dimension: Dimension::Length, numerator: 381, denominator: 1250 },
+    Unit { name: "yd", dimension: Dimension::Length, numerator: 1143, denominator: 1250 },
+    Unit { name: "mi", dimension: Dimension::Length, numerator: 201_168, denominator: 125 },
+    Unit { name: "mg", dimension: Dimension::Mass, numerator: 1, denominator: 1000 },
+    Unit { name: "g", dimension: Dimension::Mass, numerator: 1, denominator: 1 },
+    Unit { name: "kg", dimension: Dimension::Mass, numerator: 1000, denominator: 1 },
+    Unit { name: "oz", dimension: Dimension::Mass, numerator: 45_359_237, denominator: 1_600_000 },
+    Unit { name: "lb", dimension: Dimension::Mass, numerator: 45_359_237, denominator: 100_000 },
+    Unit { name: "ml", dimension: Dimension::Volume, numerator: 1, denominator: 1000 },
+    Unit { name: "l", dimension: Dimension::Volume, numerator: 1, denominator: 1 },
+    Unit { name: "tsp", dimension: Dimension::Volume, numerator: 473_176_473, denominator: 96_000_000_000 },
+    Unit { name: "tbsp", dimension: Dimension::Volume, numerator: 473_176_473, denominator: 32_000_000_000 },
+    Unit { name: "floz", dimension: Dimension::Volume, numerator: 473_176_473, denominator: 16_000_000_000 },
+    Unit { name: "cup", dimension: Dimension::Volume, numerator: 473_176_473, denominator: 2_000_000_000 },
+    Unit { name: "pt", dimension: Dimension::Volume, numerator: 473_176_473, denominator: 1_000_000_000 },
+    Unit { name: "qt", dimension: Dimension::Volume, numerator: 473_176_473, denominator: 500_000_000 },
+    Unit { name: "gal", dimension: Dimension::Volume, numerator: 473_176_473, denominator: 125_000_000 },
+    Unit { name: "ms", dimension: Dimension::Time, numerator: 1, denominator: 1000 },
+    Unit { name: "s", dimension: Dimension::Time, numerator: 1, denominator: 1 },
+    Unit { name: "min", dimension: Dimension::Time, numerator: 60, denominator: 1 },
+    Unit { name: "h", dimension: Dimension::Time, numerator: 3600, denominator: 1 },
+    Unit { name: "d", dimension: Dimension::Time, numerator: 86400, denominator: 1 },
+    Unit { name: "wk", dimension: Dimension::Time, numerator: 604_800, denominator: 1 }
+];
+
+/// Looks a unit up by the name it's written with, e.g. `ft`
+pub fn lookup(name: &str) -> Option<&'static Unit> {
+    UNITS.iter().find(|unit| unit.name == name)
+}
+
+/// The exact factor converting amounts in one unit into another, e.g. `12` from `ft` to `in`
+pub fn factor(from: &Unit, to: &Unit) -> Result<Fraction, &'static str> {
+    if from.dimension != to.dimension {
+        return Err("Units don't match!");
+    }
+
+    scale(&Fraction::from(1), from, to)
+}
+
+/// Multiplies an amount by the ratio of the sizes of both units. It's worked out with wide integers
+/// as sizes have large numerators & denominators, only the result must fit in a fraction
+fn scale(amount: &Fraction, from: &Unit, to: &Unit) -> Result<Fraction, &'static str> {
+    let numerator = i128::from(amount.numerator) * i128::from(from.numerator) * i128::from(to.denominator);
+    let denominator = i128::from(amount.denominator) * i128::from(from.denominator) * i128::from(to.numerator);
+
+    Fraction::reduce(numerator, denominator)
+}
+
+/// Models an amount measured in a unit, e.g. `1_1/2 ft`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity {
+    pub amount: Fraction,
+    pub unit: &'static Unit
+}
+
+impl Quantity {
+
+    pub fn new(amount: Fraction, unit: &'static Unit) -> Quantity {
+        Quantity { amount, unit }
+    }
+
+    /// Converts the quantity into another unit of the same dimension, e.g. `1_1/2 ft` is `18 in`
+    pub fn convert(&self, unit: &'static Unit) -> Result<Quantity, &'static str> {
+        if self.unit.dimension != unit.dimension {
+            return Err("Units don't match!");
+        }
+
+        Ok(Quantity { amount: scale(&self.amount, self.unit, unit)?, unit })
+    }
+
+    /// Adds a quantity of the same dimension, giving the result in the unit of this one
+    pub fn add(&self, quantity: &Quantity) -> Result<Quantity, &'static str> {
+        Ok(Quantity { amount: self.amount.checked_add(&quantity.convert(self.unit)?.amount)?, unit: self.unit })
+    }
+
+    pub fn substract(&self, quantity: &Quantity) -> Result<Quantity, &'static str> {
+        Ok(Quantity { amount: self.amount.checked_substract(&quantity.convert(self.unit)?.amount)?, unit: self.unit })
+    }
+
+    pub fn scale(&self, factor: &Fraction) -> Result<Quantity, &'static str> {
+        Ok(Quantity { amount: self.amount.checked_multiply(factor)?, unit: self.unit })
+    }
+
+    /// How many times a quantity of the same dimension fits in this one, e.g. `1 ft / 3 in = 4`
+    pub fn ratio(&self, quantity: &Quantity) -> Result<Fraction, &'static str> {
+        self.amount.divide(&quantity.convert(self.unit)?.amount)
+    }
+
+    /// Compares with a quantity of the same dimension, whatever units both are in
+    pub fn compare(&self, quantity: &Quantity) -> Result<Ordering, &'static str> {
+        Ok(self.amount.cmp(&quantity.convert(self.unit)?.amount))
+    }
+}
+
+/// Implementation to make `Quantity` displayable as the amount followed by the unit, e.g. `1_1/2 in`
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.unit.name)
+    }
+}
+
+#[cfg(test)]
+mod tests;
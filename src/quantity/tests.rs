@@ -0,0 +1,91 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+fn quantity(amount: &str, unit: &str) -> Quantity {
+    Quantity::new(fraction(amount), lookup(unit).unwrap())
+}
+
+#[test]
+fn lookup_units() {
+    assert_that!(&lookup("ft").map(|unit| unit.dimension)).is_equal_to(Some(Dimension::Length));
+    assert_that!(&lookup("tbsp").map(|unit| unit.dimension)).is_equal_to(Some(Dimension::Volume));
+    assert_that!(&lookup("furlong")).is_none();
+}
+
+#[test]
+fn convert_quantities() {
+    let conversion_test_cases = [
+        (("1_1/2", "ft"), "in", "18"),
+        (("1", "mi"), "yd", "1760"),
+        (("1", "in"), "cm", "2_27/50"),
+        (("1", "lb"), "oz", "16"),
+        (("1", "cup"), "tbsp", "16"),
+        (("3", "tsp"), "tbsp", "1"),
+        (("1", "gal"), "l", "3_98176473/125000000"),
+        (("1_1/2", "h"), "min", "90")
+    ];
+
+    for ((amount, unit), target, expected) in &conversion_test_cases {
+        println!("Converting {} {} to {}", amount, unit, target);
+
+        let actual = quantity(amount, unit).convert(lookup(target).unwrap());
+
+        assert_that!(&actual)
+            .is_equal_to(Ok(quantity(expected, target)));
+    }
+}
+
+#[test]
+fn convert_quantity_to_another_dimension() {
+    assert_that!(&quantity("2", "in").convert(lookup("lb").unwrap()))
+        .is_equal_to(Err("Units don't match!"));
+}
+
+#[test]
+fn convert_quantity_overflowing() {
+    assert_that!(&quantity("2000000000", "km").convert(lookup("mm").unwrap()))
+        .is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn compute_quantity_arithmetic() {
+    assert_that!(&quantity("1", "ft").add(&quantity("3", "in")))
+        .is_equal_to(Ok(quantity("1_1/4", "ft")));
+    assert_that!(&quantity("1", "kg").substract(&quantity("250", "g")))
+        .is_equal_to(Ok(quantity("3/4", "kg")));
+    assert_that!(&quantity("3/4", "in").scale(&Fraction::from(2)))
+        .is_equal_to(Ok(quantity("1_1/2", "in")));
+    assert_that!(&quantity("1", "ft").ratio(&quantity("3", "in")))
+        .is_equal_to(Ok(Fraction::from(4)));
+    assert_that!(&quantity("1", "ft").compare(&quantity("11", "in")))
+        .is_equal_to(Ok(Ordering::Greater));
+}
+
+#[test]
+fn compute_quantity_arithmetic_overflowing() {
+    assert_that!(&quantity("2147483647", "in").add(&quantity("1", "in")))
+        .is_equal_to(Err("Integer overflow!"));
+    assert_that!(&quantity("-2147483647", "in").substract(&quantity("1", "in")))
+        .is_equal_to(Err("Integer overflow!"));
+    assert_that!(&quantity("2147483647", "in").scale(&Fraction::from(2)))
+        .is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn add_quantities_of_different_dimensions() {
+    assert_that!(&quantity("2", "in").add(&quantity("3", "lb")))
+        .is_equal_to(Err("Units don't match!"));
+}
+
+#[test]
+fn factor_between_units() {
+    assert_that!(&factor(lookup("ft").unwrap(), lookup("in").unwrap()))
+        .is_equal_to(Ok(Fraction::from(12)));
+}
+
+#[test]
+fn display_quantity() {
+    assert_that!(quantity("3/2", "in").to_string().as_str())
+        .is_equal_to("1_1/2 in");
+}
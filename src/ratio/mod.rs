@@ -0,0 +1,55 @@
+use crate::fraction::Fraction;
+use crate::math;
+
+/// Simplifies a ratio to its smallest whole terms, e.g. `1_1/2 : 2` is `3 : 4`.
+/// Terms are first scaled by the LCM of their denominators, then divided by the GCD of the results
+pub fn simplify(terms: &[Fraction]) -> Result<Vec<i32>, &'static str> {
+    let scale = math::lcd(terms)?;
+    let scaled = terms.iter()
+        .map(|term| term.numerator.checked_mul(scale / term.denominator).ok_or("Integer overflow!"))
+        .collect::<Result<Vec<i32>, &'static str>>()?;
+
+    match math::gcd_of(&scaled)? {
+        0 => Ok(scaled),
+        gcd => Ok(scaled.iter().map(|term| term / gcd).collect())
+    }
+}
+
+/// Returns `true` if the proportion `a : b = c : d` holds, i.e. if `a * d = b * c`
+pub fn holds(a: Fraction, b: Fraction, c: Fraction, d: Fraction) -> Result<bool, &'static str> {
+    Ok(a.checked_multiply(&d)? == b.checked_multiply(&c)?)
+}
+
+/// Solves the proportion `a : b = c : d` for its only unknown term, given as `None`, e.g. `3/4 : 6 = x : 10` gives `x = 1_1/4`.
+/// Proportions where the unknown could take any value, or none at all, are degenerate. So are those with a `0 : 0` ratio
+pub fn solve(terms: [Option<Fraction>; 4]) -> Result<Fraction, &'static str> {
+    // Cross-multiplying, the unknown times its opposite term equals the product of the other two terms
+    let (product, divisor) = match terms {
+        [None, Some(b), Some(c), Some(d)] => (b.checked_multiply(&c)?, d),
+        [Some(a), None, Some(c), Some(d)] => (a.checked_multiply(&d)?, c),
+        [Some(a), Some(b), None, Some(d)] => (a.checked_multiply(&d)?, b),
+        [Some(a), Some(b), Some(c), None] => (b.checked_multiply(&c)?, a),
+        _ => return Err("Expected a single unknown!")
+    };
+    if divisor.is_zero() {
+        return Err("Degenerate proportion!");
+    }
+
+    let unknown = product.divide(&divisor)?;
+    let [a, b, c, d] = terms.map(|term| term.unwrap_or(unknown));
+    if (a.is_zero() && b.is_zero()) || (c.is_zero() && d.is_zero()) {
+        Err("Degenerate proportion!")
+    } else {
+        Ok(unknown)
+    }
+}
+
+/// Formats the terms of a ratio like `3 : 4`
+pub fn format_terms(terms: &[i32]) -> String {
+    let terms: Vec<String> = terms.iter().map(|term| term.to_string()).collect();
+
+    terms.join(" : ")
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,70 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+#[test]
+fn simplify_ratios() {
+    let simplify_test_cases = [
+        (vec!["3", "4"], vec![3, 4]),
+        (vec!["1_1/2", "2"], vec![3, 4]),
+        (vec!["6", "8", "10"], vec![3, 4, 5]),
+        (vec!["-1/2", "1/3"], vec![-3, 2]),
+        (vec!["0", "0"], vec![0, 0])
+    ];
+
+    for (terms, expected) in &simplify_test_cases {
+        println!("Simplifying ratio {:?}", terms);
+        let terms: Vec<Fraction> = terms.iter().map(|term| fraction(term)).collect();
+
+        assert_that!(&simplify(&terms))
+            .is_equal_to(Ok(expected.clone()));
+    }
+}
+
+#[test]
+fn solve_each_term() {
+    let (a, b, c, d) = (fraction("3/4"), fraction("6"), fraction("1_1/4"), fraction("10"));
+
+    assert_that!(&solve([None, Some(b), Some(c), Some(d)])).is_equal_to(Ok(a));
+    assert_that!(&solve([Some(a), None, Some(c), Some(d)])).is_equal_to(Ok(b));
+    assert_that!(&solve([Some(a), Some(b), None, Some(d)])).is_equal_to(Ok(c));
+    assert_that!(&solve([Some(a), Some(b), Some(c), None])).is_equal_to(Ok(d));
+}
+
+#[test]
+fn solve_degenerate_proportions() {
+    assert_that!(&solve([Some(fraction("0")), Some(fraction("6")), Some(fraction("4")), None]))
+        .is_equal_to(Err("Degenerate proportion!"));
+    assert_that!(&solve([Some(fraction("3")), Some(fraction("0")), None, Some(fraction("0"))]))
+        .is_equal_to(Err("Degenerate proportion!"));
+    assert_that!(&solve([Some(fraction("0")), Some(fraction("6")), None, Some(fraction("0"))]))
+        .is_equal_to(Err("Degenerate proportion!"));
+}
+
+#[test]
+fn solve_on_overflow() {
+    assert_that!(&solve([Some(fraction("2147483647")), Some(fraction("2")), None, Some(fraction("2147483647"))]))
+        .is_equal_to(Err("Integer overflow!"));
+    assert_that!(&solve([Some(fraction("2147483647")), Some(fraction("1/2")), None, Some(fraction("2"))]))
+        .is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn solve_without_single_unknown() {
+    assert_that!(&solve([None, None, Some(fraction("1")), Some(fraction("2"))]))
+        .is_equal_to(Err("Expected a single unknown!"));
+}
+
+#[test]
+fn proportion_holds() {
+    assert_that!(&holds(fraction("3/4"), fraction("6"), fraction("1_1/4"), fraction("10"))).is_equal_to(Ok(true));
+    assert_that!(&holds(fraction("3/4"), fraction("6"), fraction("1"), fraction("10"))).is_equal_to(Ok(false));
+    assert_that!(&holds(fraction("2147483647"), fraction("2"), fraction("2147483647"), fraction("2")))
+        .is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn format_ratio() {
+    assert_that!(format_terms(&[3, 4, 5]).as_str())
+        .is_equal_to("3 : 4 : 5");
+}
@@ -0,0 +1,155 @@
+use crate::fraction::Fraction;
+use crate::mixed_number;
+use crate::quantity::{self, Dimension, Unit};
+
+/// Kitchen units of the same dimension, from the smallest up, along with the smallest amount of them they're used for,
+/// e.g. cups are used from `1/4 cup` up. Their sizes are the ones registered in `quantity`
+const VOLUMES: [(&str, i32, i32); 3] = [("tsp", 0, 1), ("tbsp", 1, 1), ("cup", 1, 4)];
+const WEIGHTS: [(&str, i32, i32); 2] = [("oz", 0, 1), ("lb", 1, 1)];
+
+/// The fractions measuring cups & spoons come in. Quantities get rounded to a whole number plus one of these
+const MEASURES: [(i32, i32); 7] = [(0, 1), (1, 4), (1, 3), (1, 2), (2, 3), (3, 4), (1, 1)];
+const QUARTER: Fraction = Fraction { numerator: 1, denominator: 4 };
+
+/// Parses a kitchen unit written abbreviated or in full, e.g. `tbsp` or `tablespoons`, into the unit registered in `quantity`
+pub fn parse_unit(text: &str) -> Option<&'static Unit> {
+    let name = match text.to_lowercase().trim_end_matches('.') {
+        "tsp" | "teaspoon" | "teaspoons" => "tsp",
+        "tbsp" | "tablespoon" | "tablespoons" => "tbsp",
+        "cup" | "cups" => "cup",
+        "oz" | "ounce" | "ounces" => "oz",
+        "lb" | "lbs" | "pound" | "pounds" => "lb",
+        _ => return None
+    };
+
+    quantity::lookup(name)
+}
+
+/// The abbreviation a unit is written with. Only cups are written in plural
+pub fn symbol(unit: &Unit, amount: &Fraction) -> &'static str {
+    if unit.name == "cup" && *amount > Fraction::from(1) {
+        "cups"
+    } else {
+        unit.name
+    }
+}
+
+/// Models an ingredient of a recipe, e.g. `1 1/2 cup flour`. Ingredients counted without a unit, like `2 eggs`, have none
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ingredient {
+    pub amount: Fraction,
+    pub unit: Option<&'static Unit>,
+    pub name: String
+}
+
+impl Ingredient {
+
+    /// Parses a line of a recipe. Amounts can be written like in recipes, e.g. `1 1/2`, or like mixed numbers, e.g. `1_1/2`.
+    /// Lines not starting with an amount, like titles or steps, aren't ingredients
+    pub fn parse(line: &str) -> Option<Result<Ingredient, &'static str>> {
+        let mut words: Vec<&str> = line.split_whitespace().collect();
+        if !words.first()?.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let amount = match words.get(1) {
+            Some(fraction) if mixed_number::is_whole_number(words[0]) && fraction.contains('/') => {
+                let amount = format!("{}_{}", words[0], fraction);
+                words.drain(..2);
+                amount
+            },
+            _ => words.remove(0).to_string()
+        };
+        let amount = match mixed_number::parse_as_fraction(&amount) {
+            Ok(amount) => amount,
+            Err(e) => return Some(Err(e))
+        };
+        let unit = words.first().and_then(|word| parse_unit(word));
+        if unit.is_some() {
+            words.remove(0);
+        }
+
+        Some(Ok(Ingredient { amount, unit, name: words.join(" ") }))
+    }
+
+    /// Multiplies the amount by the given factor, then picks the unit keeping it readable & rounds it to what can be measured
+    pub fn scale(&self, factor: &Fraction) -> Result<Ingredient, &'static str> {
+        let mut ingredient = Ingredient { amount: self.amount.checked_multiply(factor)?, unit: self.unit, name: self.name.clone() };
+
+        // Rounding may give a whole amount of a larger unit, e.g. `2_5/6 tsp` is rounded to `3 tsp`, which is `1 tbsp`
+        for _ in 0..2 {
+            ingredient = ingredient.readable()?;
+            ingredient.amount = round_to_measure(&ingredient.amount)?;
+        }
+        Ok(ingredient)
+    }
+
+    /// Expresses the amount in the largest unit it's large enough for, so `6 tbsp` is `3/8 cup` & `1/2 tbsp` is `1_1/2 tsp`
+    fn readable(&self) -> Result<Ingredient, &'static str> {
+        let unit = match self.unit {
+            Some(unit) => unit,
+            None => return Ok(self.clone())
+        };
+        let family: &[(&str, i32, i32)] = if unit.dimension == Dimension::Volume { &VOLUMES } else { &WEIGHTS };
+
+        for &(name, numerator, denominator) in family.iter().rev() {
+            let larger = quantity::lookup(name).ok_or("Unknown unit!")?;
+            let amount = self.amount.checked_multiply(&quantity::factor(unit, larger)?)?;
+            if amount >= Fraction::new(numerator, denominator)? {
+                return Ok(Ingredient { amount, unit: Some(larger), name: self.name.clone() });
+            }
+        }
+        Ok(self.clone())
+    }
+}
+
+/// Rounds a positive amount to the nearest whole number plus one of the fractions measuring cups come in.
+/// Amounts never get rounded down to zero, the smallest measure is used instead. The fraction part is compared
+/// to the measures with wide integers, as their differences may have denominators beyond integers
+pub fn round_to_measure(amount: &Fraction) -> Result<Fraction, &'static str> {
+    let whole = amount.floor();
+    let part = Fraction { numerator: amount.numerator.rem_euclid(amount.denominator), denominator: amount.denominator };
+    // The distance to a measure as a numerator over the denominator of the measure, the one of the part being common
+    let distance = |&(numerator, denominator): &(i32, i32)| (
+        (i128::from(numerator) * i128::from(part.denominator) - i128::from(part.numerator) * i128::from(denominator)).abs(),
+        i128::from(denominator)
+    );
+
+    let (numerator, denominator) = MEASURES.iter()
+        .min_by(|a, b| {
+            let ((x, m), (y, n)) = (distance(a), distance(b));
+            (x * n).cmp(&(y * m))
+        })
+        .copied()
+        .unwrap_or((0, 1));
+    let rounded = whole.checked_add(&Fraction { numerator, denominator })?;
+    match rounded.numerator {
+        0 if part.numerator != 0 => Ok(QUARTER),
+        _ => Ok(rounded)
+    }
+}
+
+/// Writes an amount the way recipes do, e.g. `1 1/2` rather than `1_1/2`
+pub fn format_amount(amount: &Fraction) -> String {
+    amount.to_string().replace('_', " ")
+}
+
+/// Scales a line of a recipe by the given factor. Lines that aren't ingredients are kept as they are
+pub fn scale_line(line: &str, factor: &Fraction) -> Result<String, &'static str> {
+    let ingredient = match Ingredient::parse(line) {
+        Some(ingredient) => ingredient?.scale(factor)?,
+        None => return Ok(line.to_string())
+    };
+
+    let mut words = vec![format_amount(&ingredient.amount)];
+    if let Some(unit) = ingredient.unit {
+        words.push(symbol(unit, &ingredient.amount).to_string());
+    }
+    if !ingredient.name.is_empty() {
+        words.push(ingredient.name);
+    }
+    Ok(words.join(" "))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,85 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+#[test]
+fn parse_ingredient() {
+    let ingredient_test_cases = [
+        ("1 1/2 cup flour", "1_1/2", quantity::lookup("cup"), "flour"),
+        ("3/4 tsp salt", "3/4", quantity::lookup("tsp"), "salt"),
+        ("2_1/4 Tablespoons melted butter", "2_1/4", quantity::lookup("tbsp"), "melted butter"),
+        ("2 eggs", "2", None, "eggs"),
+        ("1 lb. ground beef", "1", quantity::lookup("lb"), "ground beef")
+    ];
+
+    for (line, amount, unit, name) in &ingredient_test_cases {
+        println!("Parsing line {}", line);
+
+        let ingredient = Ingredient::parse(line).unwrap().unwrap();
+
+        assert_that!(&ingredient)
+            .is_equal_to(&Ingredient { amount: fraction(amount), unit: *unit, name: name.to_string() });
+    }
+}
+
+#[test]
+fn parse_line_without_amount() {
+    assert_that!(&Ingredient::parse("Mix everything in a bowl")).is_none();
+    assert_that!(&Ingredient::parse("")).is_none();
+}
+
+#[test]
+fn parse_ingredient_with_zero_denominator() {
+    assert_that!(&Ingredient::parse("1/0 cup milk"))
+        .is_equal_to(Some(Err("Fraction with zero denominator!")));
+}
+
+#[test]
+fn round_to_measures() {
+    let rounding_test_cases = [
+        ("1/5", "1/4"),
+        ("3/10", "1/3"),
+        ("5/8", "2/3"),
+        ("7/8", "3/4"),
+        ("15/16", "1"),
+        ("2_1/16", "2"),
+        ("1/100", "1/4"),
+        ("0", "0")
+    ];
+
+    for (amount, expected) in &rounding_test_cases {
+        println!("Rounding {}", amount);
+
+        assert_that!(&round_to_measure(&fraction(amount)))
+            .is_equal_to(Ok(fraction(expected)));
+    }
+}
+
+#[test]
+fn round_to_measures_with_large_parts() {
+    assert_that!(&round_to_measure(&fraction("1/2147483647"))).is_equal_to(Ok(fraction("1/4")));
+    assert_that!(&round_to_measure(&fraction("536870910_3/4"))).is_equal_to(Ok(fraction("536870910_3/4")));
+    assert_that!(&round_to_measure(&fraction("715827882_1/3"))).is_equal_to(Ok(fraction("715827882_1/3")));
+}
+
+#[test]
+fn scale_lines() {
+    let scaling_test_cases = [
+        ("1 1/2 cup flour", "1/3", "1/2 cup flour"),
+        ("1 1/2 cup flour", "2", "3 cups flour"),
+        ("3/4 tsp salt", "4", "1 tbsp salt"),
+        ("1 tbsp sugar", "1/2", "1 1/2 tsp sugar"),
+        ("2 tbsp butter", "3", "1/3 cup butter"),
+        ("1 cup milk", "1/8", "2 tbsp milk"),
+        ("12 oz chocolate", "2", "1 1/2 lb chocolate"),
+        ("3 eggs", "1/2", "1 1/2 eggs"),
+        ("Pancakes", "3", "Pancakes")
+    ];
+
+    for (line, factor, expected) in &scaling_test_cases {
+        println!("Scaling {} by {}", line, factor);
+
+        assert_that!(&scale_line(line, &fraction(factor)))
+            .is_equal_to(Ok(expected.to_string()));
+    }
+}
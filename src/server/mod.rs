@@ -0,0 +1,114 @@
+use std::io;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::net::{TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use crate::json::Json;
+use crate::output;
+use crate::session::Session;
+
+// JSON-RPC 2.0 error codes for malformed requests
+const PARSE_ERROR: f64 = -32700.0;
+const INVALID_REQUEST: f64 = -32600.0;
+const METHOD_NOT_FOUND: f64 = -32601.0;
+const INVALID_PARAMS: f64 = -32602.0;
+const INTERNAL_ERROR: f64 = -32603.0;
+
+/// Listens on the given address & serves JSON-RPC 2.0 requests, one per line, on every connection.
+/// Each connection runs in its own thread with its own `Session`, so variables aren't shared between clients.
+pub fn serve(address: &str) -> io::Result<()> {
+    let listener = TcpListener::bind(address)?;
+    eprintln!("Listening on {}", listener.local_addr()?);
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                thread::spawn(move || handle_connection(stream).ok());
+            },
+            Err(e) => eprintln!("Error: {}", e)
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_connection(stream: TcpStream) -> io::Result<()> {
+    let mut session = Session::new();
+    let reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_request(&line, &mut session) {
+            writeln!(writer, "{}", response)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles a single JSON-RPC 2.0 request & returns the response to write back, if any.
+/// The `evaluate` method takes the expression either by name (`{"expression": "1/2 + x"}`) or by position.
+/// Its result is the same object `--output json` writes, so evaluation errors are part of the result while
+/// JSON-RPC errors are only used for malformed requests. Notifications, i.e. requests without id, get no response
+pub fn handle_request(request: &str, session: &mut Session) -> Option<Json> {
+    let request = match Json::parse(request) {
+        Ok(request) => request,
+        Err(_) => return Some(error_response(Json::Null, PARSE_ERROR, "Parse error"))
+    };
+    let id = request.get("id").cloned();
+
+    let response = match request.get("method").and_then(Json::as_str) {
+        Some("evaluate") => match expression_param(&request) {
+            // A panicking evaluation is answered with an internal error so the connection stays open
+            Some(expression) => match guarded(|| output::to_json(expression, &session.evaluate(expression).map(|(_, result)| result))) {
+                Some(result) => result_response(id.clone().unwrap_or(Json::Null), result),
+                None => error_response(id.clone().unwrap_or(Json::Null), INTERNAL_ERROR, "Internal error")
+            },
+            None => error_response(id.clone().unwrap_or(Json::Null), INVALID_PARAMS, "Invalid params")
+        },
+        Some(_) => error_response(id.clone().unwrap_or(Json::Null), METHOD_NOT_FOUND, "Method not found"),
+        None => return Some(error_response(id.unwrap_or(Json::Null), INVALID_REQUEST, "Invalid Request"))
+    };
+
+    id.map(|_| response)
+}
+
+/// Runs both the evaluation & the formatting of its result, so a panic in either doesn't take the connection down
+fn guarded(evaluate: impl FnOnce() -> Json) -> Option<Json> {
+    panic::catch_unwind(AssertUnwindSafe(evaluate)).ok()
+}
+
+fn expression_param(request: &Json) -> Option<&str> {
+    match request.get("params") {
+        Some(Json::Array(params)) => params.first().and_then(Json::as_str),
+        Some(params) => params.get("expression").and_then(Json::as_str),
+        None => None
+    }
+}
+
+fn result_response(id: Json, result: Json) -> Json {
+    Json::object(vec![
+        ("jsonrpc", Json::String("2.0".to_string())),
+        ("id", id),
+        ("result", result)
+    ])
+}
+
+fn error_response(id: Json, code: f64, message: &str) -> Json {
+    Json::object(vec![
+        ("jsonrpc", Json::String("2.0".to_string())),
+        ("id", id),
+        ("error", Json::object(vec![
+            ("code", Json::Number(code)),
+            ("message", Json::String(message.to_string()))
+        ]))
+    ])
+}
+
+#[cfg(test)]
+mod tests;
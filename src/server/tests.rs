@@ -0,0 +1,96 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::Fraction;
+use crate::value::Value;
+
+// This function will help test request handling in tests below
+fn test_handle_requests(session: &mut Session, request_test_cases: &[(&str, &str)]) {
+    for (request, expected_response) in request_test_cases {
+        println!("Handling request {}", request);
+
+        let actual_response = handle_request(request, session).unwrap().to_string();
+
+        assert_that!(actual_response.as_str())
+            .is_equal_to(expected_response);
+    }
+}
+
+#[test]
+fn handle_evaluate_request() {
+    test_handle_requests(&mut Session::new(), &[
+        (r#"{"jsonrpc": "2.0", "id": 1, "method": "evaluate", "params": {"expression": "1/2 * 3_3/4"}}"#,
+         r#"{"jsonrpc":"2.0","id":1,"result":{"input":"1/2 * 3_3/4","numerator":15,"denominator":8,"whole":1,"mixed":"1_7/8","decimal":1.875}}"#),
+        (r#"{"jsonrpc": "2.0", "id": "b", "method": "evaluate", "params": ["25/13 / 0/11"]}"#,
+         r#"{"jsonrpc":"2.0","id":"b","result":{"input":"25/13 / 0/11","error":{"kind":"division_by_zero","message":"Division by zero!","span":{"start":8,"end":12}}}}"#)
+    ]);
+}
+
+#[test]
+fn handle_evaluate_requests_sharing_variables() {
+    test_handle_requests(&mut Session::new(), &[
+        (r#"{"jsonrpc": "2.0", "id": 1, "method": "evaluate", "params": ["x = 2_3/8 + 9/8"]}"#,
+         r#"{"jsonrpc":"2.0","id":1,"result":{"input":"x = 2_3/8 + 9/8","numerator":7,"denominator":2,"whole":3,"mixed":"3_1/2","decimal":3.5}}"#),
+        (r#"{"jsonrpc": "2.0", "id": 2, "method": "evaluate", "params": ["x - 1/2"]}"#,
+         r#"{"jsonrpc":"2.0","id":2,"result":{"input":"x - 1/2","numerator":3,"denominator":1,"whole":3,"mixed":"3","decimal":3}}"#)
+    ]);
+}
+
+#[test]
+fn handle_evaluate_request_overflowing() {
+    let mut session = Session::new();
+
+    test_handle_requests(&mut session, &[
+        (r#"{"jsonrpc": "2.0", "id": 1, "method": "evaluate", "params": ["100000/3 * 100000/7"]}"#,
+         r#"{"jsonrpc":"2.0","id":1,"result":{"input":"100000/3 * 100000/7","error":{"kind":"overflow","message":"Integer overflow!","span":{"start":0,"end":19}}}}"#),
+        (r#"{"jsonrpc": "2.0", "id": 2, "method": "evaluate", "params": ["sum([2147483647, 1])"]}"#,
+         r#"{"jsonrpc":"2.0","id":2,"result":{"input":"sum([2147483647, 1])","error":{"kind":"overflow","message":"Integer overflow!","span":{"start":0,"end":20}}}}"#),
+        (r#"{"jsonrpc": "2.0", "id": 3, "method": "evaluate", "params": ["1/2 + 1/4"]}"#,
+         r#"{"jsonrpc":"2.0","id":3,"result":{"input":"1/2 + 1/4","numerator":3,"denominator":4,"whole":0,"mixed":"3/4","decimal":0.75}}"#)
+    ]);
+}
+
+#[test]
+fn handle_malformed_requests() {
+    test_handle_requests(&mut Session::new(), &[
+        (r#"{"jsonrpc": "2.0", "id": 1, "method""#,
+         r#"{"jsonrpc":"2.0","id":null,"error":{"code":-32700,"message":"Parse error"}}"#),
+        (r#"{"jsonrpc": "2.0", "id": 2}"#,
+         r#"{"jsonrpc":"2.0","id":2,"error":{"code":-32600,"message":"Invalid Request"}}"#),
+        (r#"{"jsonrpc": "2.0", "id": 3, "method": "solve", "params": ["x"]}"#,
+         r#"{"jsonrpc":"2.0","id":3,"error":{"code":-32601,"message":"Method not found"}}"#),
+        (r#"{"jsonrpc": "2.0", "id": 4, "method": "evaluate", "params": {"expr": "1 + 1"}}"#,
+         r#"{"jsonrpc":"2.0","id":4,"error":{"code":-32602,"message":"Invalid params"}}"#)
+    ]);
+}
+
+#[test]
+fn handle_notification_without_response() {
+    let mut session = Session::new();
+
+    let response = handle_request(r#"{"jsonrpc": "2.0", "method": "evaluate", "params": ["y = 1 + 1"]}"#, &mut session);
+
+    assert_that!(&response)
+        .is_equal_to(&None);
+    assert_that!(&session.evaluate("y * 1").unwrap().1)
+        .is_equal_to(Value::Number(Fraction::new(2, 1).unwrap()));
+}
+
+#[test]
+fn handle_deeply_nested_requests() {
+    let nested_request = format!(r#"{{"jsonrpc": "2.0", "id": 1, "method": "evaluate", "params": {}"1"{}}}"#, "[".repeat(300), "]".repeat(300));
+    let nested_expression = format!("{}1 + 1{}", "(".repeat(100), ")".repeat(100));
+    let expression_request = format!(r#"{{"jsonrpc": "2.0", "id": 2, "method": "evaluate", "params": ["{}"]}}"#, nested_expression);
+
+    test_handle_requests(&mut Session::new(), &[
+        (&nested_request,
+         r#"{"jsonrpc":"2.0","id":null,"error":{"code":-32700,"message":"Parse error"}}"#),
+        (&expression_request,
+         &format!(r#"{{"jsonrpc":"2.0","id":2,"result":{{"input":"{}","error":{{"kind":"syntax","message":"Too deeply nested!","span":{{"start":64,"end":65}}}}}}}}"#, nested_expression))
+    ]);
+}
+
+#[test]
+fn guarded_evaluation_catches_panics() {
+    assert_that!(&guarded(|| Json::Null)).is_equal_to(Some(Json::Null));
+    assert_that!(&guarded(|| panic!("Formatting failed!"))).is_none();
+}
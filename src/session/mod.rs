@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use crate::expression::{self, Statement};
+use crate::evaluator;
+use crate::explanation;
+use crate::function;
+use crate::value::Value;
+use crate::error::Error;
+
+/// Keeps the variables assigned along a sequence of evaluations, e.g. a REPL run or a server connection
+#[derive(Debug, Default)]
+pub struct Session {
+    variables: HashMap<String, Value>
+}
+
+impl Session {
+
+    pub fn new() -> Session {
+        Session::default()
+    }
+
+    /// Parses & runs an input like `x + 1/2`, or an assignment like `x = 1/2 * 3_3/4` that
+    /// stores the result in the session for later evaluations.
+    pub fn evaluate(&mut self, input: &str) -> Result<(Statement, Value), Error> {
+        let statement = expression::parse(input)?;
+        let value = self.run(&statement)?;
+
+        Ok((statement, value))
+    }
+
+    /// Runs an already parsed statement, storing the result if it's an assignment.
+    /// Solving a proportion like `3/4 : 6 = x : 10` or equations like `solve 2x = 1` assigns the unknowns too
+    pub fn run(&mut self, statement: &Statement) -> Result<Value, Error> {
+        let value = evaluator::evaluate(&statement.expression, &self.variables)?;
+
+        if let Some(variable) = &statement.variable {
+            self.variables.insert(variable.clone(), value.clone());
+        }
+        if let Value::Solution(solution) = &value {
+            for (variable, value) in solution {
+                self.variables.insert(variable.clone(), Value::Number(*value));
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Lists the names starting with the given prefix, for completion. Functions come with their opening
+    /// parenthesis, e.g. `abs(`, so they can't be mistaken for variables
+    pub fn complete(&self, prefix: &str) -> Vec<String> {
+        let functions = function::names().map(|name| format!("{}(", name));
+        let mut names: Vec<String> = functions.chain(self.variables.keys().cloned())
+            .filter(|name| name.starts_with(prefix))
+            .collect();
+        names.sort();
+
+        names
+    }
+
+    /// Explains how a statement gets computed with the current variables, i.e. before running it
+    pub fn explain(&self, statement: &Statement) -> Result<Vec<String>, Error> {
+        explanation::explain(&statement.expression, &self.variables)
+    }
+}
+
+#[cfg(test)]
+mod tests;
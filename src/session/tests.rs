@@ -0,0 +1,116 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::Fraction;
+
+#[test]
+fn evaluate_operation() {
+    let mut session = Session::new();
+
+    let (_, value) = session.evaluate("1/2 * 3_3/4").unwrap();
+
+    assert_that!(&value)
+        .is_equal_to(Value::Number(Fraction::new(15, 8).unwrap()));
+}
+
+#[test]
+fn evaluate_assignment_stores_variable() {
+    let mut session = Session::new();
+
+    session.evaluate("x = 2_3/8 + 9/8").unwrap();
+    let (_, value) = session.evaluate("x * 2").unwrap();
+
+    assert_that!(&session.variables.get("x"))
+        .is_equal_to(Some(&Value::Number(Fraction::new(7, 2).unwrap())));
+    assert_that!(&value)
+        .is_equal_to(Value::Number(Fraction::new(7, 1).unwrap()));
+}
+
+#[test]
+fn evaluate_literal_assignment() {
+    let mut session = Session::new();
+
+    session.evaluate("x = 1/2").unwrap();
+    session.evaluate("fps = 30000/1001").unwrap();
+    session.evaluate("y = x").unwrap();
+
+    assert_that!(&session.variables.get("y"))
+        .is_equal_to(Some(&Value::Number(Fraction::new(1, 2).unwrap())));
+    assert_that!(&session.variables.get("fps"))
+        .is_equal_to(Some(&Value::Number(Fraction::new(30000, 1001).unwrap())));
+}
+
+#[test]
+fn evaluate_boolean_assignment() {
+    let mut session = Session::new();
+
+    session.evaluate("bigger = 5_3/8 > 43/8").unwrap();
+    let (_, value) = session.evaluate("bigger == (1 > 2)").unwrap();
+
+    assert_that!(&value)
+        .is_equal_to(Value::Boolean(true));
+}
+
+#[test]
+fn evaluate_assignment_error_points_to_expression() {
+    let mut session = Session::new();
+
+    let error = session.evaluate("ratio = 25/13 / 0/11").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Division by zero!", 16, 20));
+    assert_that!(&session.variables.get("ratio"))
+        .is_equal_to(None);
+}
+
+#[test]
+#[should_panic(expected = "Unknown variable!")]
+fn evaluate_with_unknown_variable() {
+    let mut session = Session::new();
+
+    session.evaluate("y + 1").unwrap();
+}
+
+#[test]
+fn solving_proportion_assigns_unknown() {
+    let mut session = Session::new();
+    session.evaluate("3/4 : 6 = cups : 10").unwrap();
+
+    let (_, value) = session.evaluate("cups * 2").unwrap();
+
+    assert_that!(&value)
+        .is_equal_to(Value::Number(Fraction::new(5, 2).unwrap()));
+}
+
+#[test]
+fn solving_system_assigns_unknowns() {
+    let mut session = Session::new();
+    session.evaluate("solve { x + y = 3/2; x - y = 1/4 }").unwrap();
+
+    let (_, value) = session.evaluate("x * y").unwrap();
+
+    assert_that!(&value)
+        .is_equal_to(Value::Number(Fraction::new(35, 64).unwrap()));
+}
+
+#[test]
+fn explain_uses_variables_before_assignment() {
+    let mut session = Session::new();
+    session.evaluate("x = 1/2 + 1/2").unwrap();
+    let statement = expression::parse("x = x + 1/2").unwrap();
+
+    let steps = session.explain(&statement).unwrap();
+
+    assert_that!(&steps[0].as_str())
+        .is_equal_to("Write x as a fraction: 1/1");
+}
+
+#[test]
+fn complete_functions_and_variables() {
+    let mut session = Session::new();
+    session.evaluate("rate = 3/4").unwrap();
+
+    let actual = session.complete("r");
+
+    assert_that!(&actual)
+        .is_equal_to(vec!["range(".to_string(), "rate".to_string(), "recip(".to_string(), "roots(".to_string(), "round(".to_string(), "rref(".to_string()]);
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use crate::fraction::Fraction;
+
+/// Adds up the numbers, the sum of no numbers being `0`
+pub fn sum(numbers: &[Fraction]) -> Result<Fraction, &'static str> {
+    numbers.iter().try_fold(Fraction::from(0), |sum, number| sum.checked_add(number))
+}
+
+/// Multiplies the numbers, the product of no numbers being `1`
+pub fn product(numbers: &[Fraction]) -> Result<Fraction, &'static str> {
+    numbers.iter().try_fold(Fraction::from(1), |product, number| product.checked_multiply(number))
+}
+
+/// Computes the arithmetic mean, e.g. the mean of `1/2`, `3_1/4` & `7/8` is `1_13/24`
+pub fn mean(numbers: &[Fraction]) -> Result<Fraction, &'static str> {
+    non_empty(numbers)?;
+
+    sum(numbers)?.divide(&count(numbers.len())?)
+}
+
+/// Finds the middle number once sorted, or the mean of both middle numbers if there's an even amount of them
+pub fn median(numbers: &[Fraction]) -> Result<Fraction, &'static str> {
+    non_empty(numbers)?;
+
+    let mut sorted = numbers.to_vec();
+    sorted.sort();
+    let middle = sorted.len() / 2;
+
+    if sorted.len() % 2 == 1 {
+        Ok(sorted[middle])
+    } else {
+        mean(&sorted[middle - 1..=middle])
+    }
+}
+
+/// Finds the most frequent numbers, in ascending order. There are several of them on a tie
+pub fn mode(numbers: &[Fraction]) -> Result<Vec<Fraction>, &'static str> {
+    non_empty(numbers)?;
+
+    let mut counts: HashMap<Fraction, usize> = HashMap::new();
+    for number in numbers {
+        *counts.entry(*number).or_insert(0) += 1;
+    }
+    let highest = counts.values().max().copied().unwrap_or(0);
+    let mut modes: Vec<Fraction> = counts.into_iter()
+        .filter(|&(_, count)| count == highest)
+        .map(|(number, _)| number)
+        .collect();
+    modes.sort();
+
+    Ok(modes)
+}
+
+/// Computes the difference between the largest & the smallest number
+pub fn range(numbers: &[Fraction]) -> Result<Fraction, &'static str> {
+    non_empty(numbers)?;
+
+    let smallest = numbers.iter().min().unwrap();
+    let largest = numbers.iter().max().unwrap();
+    largest.checked_substract(smallest)
+}
+
+/// Computes the population variance, i.e. the mean of the squared deviations from the mean
+pub fn variance(numbers: &[Fraction]) -> Result<Fraction, &'static str> {
+    let deviations = squared_deviations(numbers)?;
+
+    deviations.divide(&count(numbers.len())?)
+}
+
+/// Computes the sample variance, which divides the squared deviations by one less than the amount of numbers
+pub fn sample_variance(numbers: &[Fraction]) -> Result<Fraction, &'static str> {
+    if numbers.len() < 2 {
+        return Err("Expected at least two numbers!");
+    }
+    let deviations = squared_deviations(numbers)?;
+
+    deviations.divide(&count(numbers.len() - 1)?)
+}
+
+fn squared_deviations(numbers: &[Fraction]) -> Result<Fraction, &'static str> {
+    let mean = mean(numbers)?;
+
+    let deviations = numbers.iter()
+        .map(|number| number.checked_substract(&mean).and_then(|deviation| deviation.checked_multiply(&deviation)))
+        .collect::<Result<Vec<Fraction>, &'static str>>()?;
+
+    sum(&deviations)
+}
+
+fn count(amount: usize) -> Result<Fraction, &'static str> {
+    i32::try_from(amount).map(Fraction::from).map_err(|_| "Integer overflow!")
+}
+
+fn non_empty(numbers: &[Fraction]) -> Result<(), &'static str> {
+    if numbers.is_empty() {
+        Err("Expected at least one number!")
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
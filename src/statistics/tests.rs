@@ -0,0 +1,69 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+// This function will help parse the numbers used in tests below
+fn fractions(fraction_exps: &[&str]) -> Vec<Fraction> {
+    fraction_exps.iter().map(|exp| fraction(exp)).collect()
+}
+
+#[test]
+fn sum_and_product() {
+    let numbers = fractions(&["1/2", "3_1/4", "7/8"]);
+
+    assert_that!(&sum(&numbers)).is_equal_to(Ok(fraction("4_5/8")));
+    assert_that!(&product(&numbers)).is_equal_to(Ok(fraction("1_27/64")));
+    assert_that!(&sum(&[])).is_equal_to(Ok(Fraction::from(0)));
+    assert_that!(&product(&[])).is_equal_to(Ok(Fraction::from(1)));
+}
+
+#[test]
+fn statistics_on_overflow() {
+    let numbers = fractions(&["2147483647", "-2147483647"]);
+
+    assert_that!(&sum(&fractions(&["2147483647", "1"]))).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&product(&fractions(&["2147483647", "2"]))).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&mean(&fractions(&["2147483647", "1"]))).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&range(&numbers)).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&variance(&numbers)).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn mean_of_numbers() {
+    let numbers = fractions(&["1/2", "3_1/4", "7/8"]);
+
+    assert_that!(&mean(&numbers)).is_equal_to(Ok(fraction("1_13/24")));
+}
+
+#[test]
+fn median_of_odd_and_even_amount_of_numbers() {
+    assert_that!(&median(&fractions(&["7/8", "1/2", "3_1/4"]))).is_equal_to(Ok(fraction("7/8")));
+    assert_that!(&median(&fractions(&["7/8", "1/2", "3_1/4", "1"]))).is_equal_to(Ok(fraction("15/16")));
+}
+
+#[test]
+fn mode_of_numbers() {
+    assert_that!(&mode(&fractions(&["1/2", "2/4", "3/4"]))).is_equal_to(Ok(fractions(&["1/2"])));
+    assert_that!(&mode(&fractions(&["3/4", "1/2", "3/4", "1/2", "1"]))).is_equal_to(Ok(fractions(&["1/2", "3/4"])));
+}
+
+#[test]
+fn range_of_numbers() {
+    assert_that!(&range(&fractions(&["1/2", "3_1/4", "7/8"]))).is_equal_to(Ok(fraction("2_3/4")));
+}
+
+#[test]
+fn population_and_sample_variance() {
+    let numbers = fractions(&["1", "2", "3", "4"]);
+
+    assert_that!(&variance(&numbers)).is_equal_to(Ok(fraction("5/4")));
+    assert_that!(&sample_variance(&numbers)).is_equal_to(Ok(fraction("5/3")));
+    assert_that!(&variance(&fractions(&["1/2", "1/3"]))).is_equal_to(Ok(fraction("1/144")));
+}
+
+#[test]
+fn statistics_of_no_numbers() {
+    assert_that!(&mean(&[])).is_equal_to(Err("Expected at least one number!"));
+    assert_that!(&median(&[])).is_equal_to(Err("Expected at least one number!"));
+    assert_that!(&sample_variance(&fractions(&["1/2"]))).is_equal_to(Err("Expected at least two numbers!"));
+}
@@ -0,0 +1,130 @@
+use crate::fraction::Fraction;
+
+/// Frame rate frames of time literals are counted at unless the `fps` variable gives another one
+pub const DEFAULT_FRAME_RATE: Fraction = Fraction { numerator: 30, denominator: 1 };
+
+/// Models a time literal like `1:23:45`, `0:00:10.5` or `0:01:00;12`. Frames are kept apart from the seconds
+/// as the frame rate they're counted at is only known when evaluating
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timecode {
+    pub seconds: Fraction,
+    pub frames: Option<i32>
+}
+
+impl Timecode {
+
+    /// The exact number of seconds of the literal, counting frames at the given frame rate, e.g. `0:00:01;15` is
+    /// `1_1/2` seconds at 30 frames per second. There must be fewer frames than a second holds
+    pub fn in_seconds(&self, frame_rate: &Fraction) -> Result<Fraction, &'static str> {
+        let frames = match self.frames {
+            Some(frames) => Fraction::from(frames),
+            None => return Ok(self.seconds)
+        };
+
+        if frames.abs() >= *frame_rate {
+            return Err("Too many frames for the frame rate!");
+        }
+        add(&self.seconds, &frames.divide(frame_rate)?)
+    }
+}
+
+/// Parses a time literal written as `h:mm:ss`, with minutes & seconds below 60. Seconds may have decimals,
+/// e.g. `1:23:45.5`, and be followed by frames, e.g. `1:23:45;12`. Negative times are written with a sign, e.g. `-0:01:30`
+pub fn parse(text: &str) -> Result<Timecode, &'static str> {
+    if let Some(text) = text.strip_prefix('-') {
+        let timecode = parse(text)?;
+        return Ok(Timecode { seconds: -timecode.seconds, frames: timecode.frames.map(|frames| -frames) });
+    }
+
+    let (time, frames) = match text.split_once(';') {
+        Some((time, frames)) => (time, Some(whole(frames)?)),
+        None => (text, None)
+    };
+    let (seconds, decimals) = match time.split_once('.') {
+        Some((seconds, decimals)) => (seconds, Some(decimals)),
+        None => (time, None)
+    };
+
+    let parts: Vec<&str> = seconds.split(':').collect();
+    let (hours, minutes, seconds) = match parts[..] {
+        [hours, minutes, seconds] if minutes.len() == 2 && seconds.len() == 2 =>
+            (whole(hours)?, whole(minutes)?, whole(seconds)?),
+        _ => return Err("Unparseable time!")
+    };
+    if minutes >= 60 || seconds >= 60 {
+        return Err("Unparseable time!");
+    }
+
+    let whole_seconds = hours.checked_mul(3600)
+        .and_then(|total| total.checked_add(minutes * 60 + seconds))
+        .ok_or("Integer overflow!")?;
+    let fraction = match decimals {
+        Some(decimals) if decimals.len() <= 9 =>
+            Fraction::new(whole(decimals)?, 10_i32.pow(decimals.len() as u32))?,
+        Some(_) => return Err("Integer overflow!"),
+        None => Fraction::from(0)
+    };
+
+    Ok(Timecode { seconds: Fraction::from(whole_seconds).checked_add(&fraction)?, frames })
+}
+
+/// Adds two numbers of seconds. It's worked out with wide integers as frame rates like `30000/1001` give large
+/// denominators, only the result must fit in a fraction
+pub fn add(a: &Fraction, b: &Fraction) -> Result<Fraction, &'static str> {
+    let numerator = i128::from(a.numerator) * i128::from(b.denominator) + i128::from(b.numerator) * i128::from(a.denominator);
+
+    Fraction::reduce(numerator, i128::from(a.denominator) * i128::from(b.denominator))
+}
+
+pub fn multiply(a: &Fraction, b: &Fraction) -> Result<Fraction, &'static str> {
+    Fraction::reduce(i128::from(a.numerator) * i128::from(b.numerator), i128::from(a.denominator) * i128::from(b.denominator))
+}
+
+/// Parses a whole number written with digits only
+fn whole(text: &str) -> Result<i32, &'static str> {
+    if text.is_empty() || !text.chars().all(|c| c.is_ascii_digit()) {
+        return Err("Unparseable time!");
+    }
+
+    text.parse().map_err(|_| "Integer overflow!")
+}
+
+/// Writes a number of seconds as `h:mm:ss`. Parts of a second are written as decimals when they have a finite
+/// number of them, e.g. `0:00:01.5`, or as a fraction otherwise, e.g. `0:00:00_1001/30000`
+pub fn format(seconds: &Fraction) -> String {
+    let sign = if seconds.numerator < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    let whole = seconds.trunc().numerator;
+    let part = seconds.fract();
+
+    let time = format!("{}{}:{:02}:{:02}", sign, whole / 3600, whole % 3600 / 60, whole % 60);
+    match (part.numerator, decimals(&part)) {
+        (0, _) => time,
+        (_, Some(decimals)) => format!("{}.{}", time, decimals),
+        (_, None) => format!("{}_{}", time, part)
+    }
+}
+
+/// The decimals of a part of a second, if its denominator only has 2 & 5 as prime factors & they fit in a number
+fn decimals(part: &Fraction) -> Option<String> {
+    let mut denominator = part.denominator;
+    let (mut twos, mut fives) = (0, 0);
+    while denominator % 2 == 0 {
+        denominator /= 2;
+        twos += 1;
+    }
+    while denominator % 5 == 0 {
+        denominator /= 5;
+        fives += 1;
+    }
+    if denominator != 1 {
+        return None;
+    }
+
+    let digits: u32 = twos.max(fives);
+    let decimals = 10_i128.checked_pow(digits)?.checked_mul(i128::from(part.numerator))? / i128::from(part.denominator);
+    Some(format!("{:0width$}", decimals, width = digits as usize))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,74 @@
+use spectral::prelude::*;
+use super::*;
+use crate::fraction::fraction;
+
+#[test]
+fn parse_timecode() {
+    let parse_test_cases = [
+        ("1:23:45", "5025", None),
+        ("0:00:10.5", "10_1/2", None),
+        ("12:00:00.125", "43200_1/8", None),
+        ("0:01:00;12", "60", Some(12)),
+        ("-0:01:30;6", "-90", Some(-6))
+    ];
+
+    for (text, seconds, frames) in &parse_test_cases {
+        println!("Parsing {}", text);
+
+        assert_that!(&parse(text)).is_equal_to(Ok(Timecode { seconds: fraction(seconds), frames: *frames }));
+    }
+}
+
+#[test]
+fn parse_invalid_timecode() {
+    let invalid_test_cases = ["1:60:00", "1:00:75", "1:5:00", "1:00", "1:00:00.", "1:00:00;"];
+
+    for text in &invalid_test_cases {
+        println!("Parsing {}", text);
+
+        assert_that!(&parse(text)).is_equal_to(Err("Unparseable time!"));
+    }
+}
+
+#[test]
+fn parse_overflowing_timecode() {
+    assert_that!(&parse("596523:14:07.5")).is_equal_to(Err("Integer overflow!"));
+    assert_that!(&parse("596524:00:00")).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn count_frames_at_frame_rate() {
+    let timecode = parse("0:00:01;15").unwrap();
+
+    assert_that!(&timecode.in_seconds(&DEFAULT_FRAME_RATE)).is_equal_to(Ok(fraction("1_1/2")));
+    assert_that!(&timecode.in_seconds(&fraction("30000/1001"))).is_equal_to(Ok(fraction("1_1001/2000")));
+    assert_that!(&timecode.in_seconds(&fraction("12"))).is_equal_to(Err("Too many frames for the frame rate!"));
+}
+
+#[test]
+fn add_seconds_with_large_denominators() {
+    let frame = fraction("1001/30000");
+
+    assert_that!(&add(&fraction("3600_29029/30000"), &frame)).is_equal_to(Ok(fraction("3601_1/1000")));
+    assert_that!(&multiply(&fraction("3600_29029/30000"), &fraction("2"))).is_equal_to(Ok(fraction("7201_14029/15000")));
+    assert_that!(&add(&fraction("1/2147483647"), &fraction("1/2147483646"))).is_equal_to(Err("Integer overflow!"));
+}
+
+#[test]
+fn format_seconds() {
+    let format_test_cases = [
+        ("5025", "1:23:45"),
+        ("15675", "4:21:15"),
+        ("10_1/2", "0:00:10.5"),
+        ("1/8", "0:00:00.125"),
+        ("1001/30000", "0:00:00_1001/30000"),
+        ("-90", "-0:01:30"),
+        ("360000", "100:00:00")
+    ];
+
+    for (seconds, expected) in &format_test_cases {
+        println!("Formatting {}", seconds);
+
+        assert_that!(&format(&fraction(seconds))).is_equal_to(expected.to_string());
+    }
+}
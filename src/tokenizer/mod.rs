@@ -0,0 +1,202 @@
+use crate::error::{Error, Span};
+use crate::function;
+
+/// Symbols made of several characters. They're matched before single character symbols, longest first
+const LONG_SYMBOLS: [&str; 5] = ["+/-", "<=", ">=", "==", "!="];
+const SYMBOLS: &str = "+-*/^<>=(),[]:{};±%";
+
+/// Kinds of tokens an expression is made of
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TokenKind {
+    /// A whole number, fraction, mixed number, dotted note or continued fraction like `2`, `-3/4`, `2_3/8`, `1/4.` or `[4; 2, 6, 7]`
+    Number,
+    /// A time like `1:23:45`, `0:00:10.5` or `0:01:00;12`
+    Time,
+    /// A roll of dice like `2d6`
+    Dice,
+    /// An imaginary number, i.e. a number followed by `i` like `3/4i`
+    Imaginary,
+    /// A percentage, i.e. a number followed by `%` like `15%`
+    Percent,
+    /// An amount of money like `$129.99`
+    Money,
+    /// A variable name like `x`
+    Identifier,
+    /// The name of a built-in function like `max`
+    Function,
+    /// An operator or punctuation like `+`, `<=`, `:` or `(`
+    Symbol
+}
+
+/// Models a token with the text it was typed with & its location in the expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    pub span: Span
+}
+
+/// Splits an expression into tokens.
+/// A `-` followed by a digit is the sign of a number unless it comes right after an operand, so `1 - -2` has 3 tokens.
+/// Numbers are kept as typed, parsing them is up to `mixed_number` & `continued_fraction`.
+/// A `%` right after a number makes it a percentage, a `%` on its own is only a symbol as in `as %`.
+/// Times are told apart from ratios by their two digit minutes & seconds written without spaces, e.g. `1:23:45`.
+pub fn tokenize(expression: &str) -> Result<Vec<Token>, Error> {
+    let chars: Vec<(usize, char)> = expression.char_indices().collect();
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let (start, c) = chars[index];
+        let next = chars.get(index + 1).map(|&(_, next)| next);
+        let follows_operand = tokens.last().is_some_and(|token| {
+            token.kind != TokenKind::Symbol || token.text == ")" || token.text == "]"
+        });
+
+        let (kind, length) = if c.is_whitespace() {
+            index += 1;
+            continue;
+        } else if let Some(length) = time_length(&chars[index..]).filter(|_| c != '-' || !follows_operand) {
+            (TokenKind::Time, length)
+        } else if let Some(length) = dice_length(&chars[index..]) {
+            (TokenKind::Dice, length)
+        } else if let Some(length) = money_length(&chars[index..]).filter(|_| c != '-' || !follows_operand) {
+            (TokenKind::Money, length)
+        } else if c.is_ascii_digit() || (c == '-' && next.is_some_and(|n| n.is_ascii_digit()) && !follows_operand) {
+            let length = number_length(&chars[index..]);
+            if imaginary_suffix(&chars[index + length..]) {
+                (TokenKind::Imaginary, length + 1)
+            } else if chars.get(index + length).is_some_and(|&(_, c)| c == '%') {
+                (TokenKind::Percent, length + 1)
+            } else {
+                (TokenKind::Number, length)
+            }
+        } else if let Some(length) = continued_fraction_length(&chars[index..]) {
+            (TokenKind::Number, length)
+        } else if c.is_alphabetic() || c == '_' {
+            let length = chars[index..].iter()
+                .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+                .count();
+            let name: String = chars[index..index + length].iter().map(|&(_, c)| c).collect();
+            if function::lookup(&name).is_some() {
+                (TokenKind::Function, length)
+            } else {
+                (TokenKind::Identifier, length)
+            }
+        } else if let Some(symbol) = LONG_SYMBOLS.iter().find(|symbol| symbol.chars().eq(chars[index..].iter().take(symbol.len()).map(|&(_, c)| c))) {
+            (TokenKind::Symbol, symbol.len())
+        } else if SYMBOLS.contains(c) {
+            (TokenKind::Symbol, 1)
+        } else {
+            return Err(Error::new("Unparseable operation!", start, start + c.len_utf8()));
+        };
+
+        let end = chars.get(index + length).map_or(expression.len(), |&(end, _)| end);
+        tokens.push(Token { kind, text: expression[start..end].to_string(), span: Span { start, end } });
+        index += length;
+    }
+
+    Ok(tokens)
+}
+
+/// Numbers span digits, `_` & `/`, allowing a sign right after the slash like in `1/-2`.
+/// Fractions may end with the dots of dotted notes, like `1/4.`
+fn number_length(chars: &[(usize, char)]) -> usize {
+    let mut length = 1;
+    while let Some(&(_, c)) = chars.get(length) {
+        let after_slash = chars[length - 1].1 == '/';
+        if c.is_ascii_digit() || c == '_' || c == '/' || (c == '-' && after_slash) {
+            length += 1;
+        } else {
+            break;
+        }
+    }
+
+    if chars[..length].iter().any(|&(_, c)| c == '/') {
+        length += chars[length..].iter().take_while(|&&(_, c)| c == '.').count();
+    }
+    length
+}
+
+/// Imaginary numbers end with an `i` right after the number, which mustn't start a name like `in`
+fn imaginary_suffix(chars: &[(usize, char)]) -> bool {
+    match chars {
+        [(_, 'i'), (_, c), ..] => !c.is_alphanumeric() && *c != '_',
+        [(_, 'i')] => true,
+        _ => false
+    }
+}
+
+/// Times span hours, two digit minutes & seconds, then decimals of the seconds & frames if any, e.g. `1:23:45.5;12`.
+/// Like numbers, they may have a sign
+fn time_length(chars: &[(usize, char)]) -> Option<usize> {
+    let digits = |from: usize| chars[from..].iter().take_while(|(_, c)| c.is_ascii_digit()).count();
+    let sign = usize::from(chars[0].1 == '-');
+    let hours = digits(sign);
+    if hours == 0 {
+        return None;
+    }
+
+    let mut length = sign + hours;
+    for _ in 0..2 {
+        if chars.get(length).map(|&(_, c)| c) != Some(':') || digits(length + 1) != 2 {
+            return None;
+        }
+        length += 3;
+    }
+    for separator in ['.', ';'] {
+        if chars.get(length).map(|&(_, c)| c) == Some(separator) {
+            length += 1 + digits(length + 1);
+        }
+    }
+    Some(length)
+}
+
+/// Rolls of dice span the number of dice, a `d` & the number of sides, e.g. `3d6`, so `2 d` is still two days
+fn dice_length(chars: &[(usize, char)]) -> Option<usize> {
+    let digits = |from: usize| chars[from..].iter().take_while(|(_, c)| c.is_ascii_digit()).count();
+    let count = digits(0);
+    if count == 0 || chars.get(count).map(|&(_, c)| c) != Some('d') {
+        return None;
+    }
+
+    let length = count + 1 + digits(count + 1);
+    match chars.get(length) {
+        _ if length == count + 1 => None,
+        Some(&(_, c)) if c.is_alphanumeric() || c == '_' => None,
+        _ => Some(length)
+    }
+}
+
+/// Amounts of money span a `$` followed by digits & their decimals if any, e.g. `$129.99`. Like numbers, they may have a sign
+fn money_length(chars: &[(usize, char)]) -> Option<usize> {
+    let digits = |from: usize| chars.get(from..).map_or(0, |rest| rest.iter().take_while(|(_, c)| c.is_ascii_digit()).count());
+    let sign = usize::from(chars[0].1 == '-');
+    if chars.get(sign).map(|&(_, c)| c) != Some('$') || digits(sign + 1) == 0 {
+        return None;
+    }
+
+    let length = sign + 1 + digits(sign + 1);
+    match chars.get(length) {
+        Some(&(_, '.')) if digits(length + 1) > 0 => Some(length + 1 + digits(length + 1)),
+        _ => Some(length)
+    }
+}
+
+/// Continued fractions like `[4; 2, 6, 7]` are numbers too, spanning up to the closing bracket.
+/// Brackets without a `;` are lists instead, which may contain continued fractions
+fn continued_fraction_length(chars: &[(usize, char)]) -> Option<usize> {
+    if chars[0].1 != '[' {
+        return None;
+    }
+    let length = chars[1..].iter().position(|&(_, c)| c == ']' || c == '[')? + 2;
+
+    if chars[length - 1].1 == ']' && chars[..length].iter().any(|&(_, c)| c == ';') {
+        Some(length)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests;
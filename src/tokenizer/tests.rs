@@ -0,0 +1,128 @@
+use spectral::prelude::*;
+use super::*;
+
+// This function will help test tokenization in tests below
+fn token_texts(expression: &str) -> Vec<&str> {
+    tokenize(expression).unwrap().into_iter()
+        .map(|token| &expression[token.span.start..token.span.end])
+        .collect()
+}
+
+#[test]
+fn tokenize_operation() {
+    let tokens = tokenize("2_3/8 + x").unwrap();
+
+    assert_that!(&tokens).is_equal_to(vec![
+        Token { kind: TokenKind::Number, text: "2_3/8".to_string(), span: Span { start: 0, end: 5 } },
+        Token { kind: TokenKind::Symbol, text: "+".to_string(), span: Span { start: 6, end: 7 } },
+        Token { kind: TokenKind::Identifier, text: "x".to_string(), span: Span { start: 8, end: 9 } }
+    ]);
+}
+
+#[test]
+fn tokenize_negative_numbers() {
+    assert_that!(&token_texts("-1/2 - -3")).is_equal_to(vec!["-1/2", "-", "-3"]);
+    assert_that!(&token_texts("x -3")).is_equal_to(vec!["x", "-", "3"]);
+    assert_that!(&token_texts("1/-2")).is_equal_to(vec!["1/-2"]);
+}
+
+#[test]
+fn tokenize_long_symbols_and_calls() {
+    let texts = token_texts("clamp(x,0,1) <= (1/2*3)");
+
+    assert_that!(&texts)
+        .is_equal_to(vec!["clamp", "(", "x", ",", "0", ",", "1", ")", "<=", "(", "1/2", "*", "3", ")"]);
+}
+
+#[test]
+fn tokenize_tolerances() {
+    assert_that!(&token_texts("2_1/2 ± 1/64")).is_equal_to(vec!["2_1/2", "±", "1/64"]);
+    assert_that!(&token_texts("3/4+/-1/32")).is_equal_to(vec!["3/4", "+/-", "1/32"]);
+}
+
+#[test]
+fn tokenize_imaginary_numbers() {
+    let kinds: Vec<TokenKind> = tokenize("3/4i + 2 in").unwrap().into_iter().map(|token| token.kind).collect();
+
+    assert_that!(&token_texts("1/2+-3/4i")).is_equal_to(vec!["1/2", "+", "-3/4i"]);
+    assert_that!(&kinds).is_equal_to(vec![TokenKind::Imaginary, TokenKind::Symbol, TokenKind::Number, TokenKind::Identifier]);
+}
+
+#[test]
+fn tokenize_percentages() {
+    let kinds: Vec<TokenKind> = tokenize("15% of 2_1/2 as %").unwrap().into_iter().map(|token| token.kind).collect();
+
+    assert_that!(&token_texts("40+-10%")).is_equal_to(vec!["40", "+", "-10%"]);
+    assert_that!(&kinds).is_equal_to(vec![
+        TokenKind::Percent, TokenKind::Identifier, TokenKind::Number, TokenKind::Identifier, TokenKind::Symbol
+    ]);
+}
+
+#[test]
+fn tokenize_money() {
+    let kinds: Vec<TokenKind> = tokenize("$129.99 * 2/3").unwrap().into_iter().map(|token| token.kind).collect();
+
+    assert_that!(&token_texts("$5-$1.5+-$2")).is_equal_to(vec!["$5", "-", "$1.5", "+", "-$2"]);
+    assert_that!(&kinds).is_equal_to(vec![TokenKind::Money, TokenKind::Symbol, TokenKind::Number]);
+}
+
+#[test]
+fn tokenize_keeps_malformed_numbers_for_parsing() {
+    assert_that!(&token_texts("2__1/2 * 3")).is_equal_to(vec!["2__1/2", "*", "3"]);
+}
+
+#[test]
+fn tokenize_with_unexpected_character() {
+    let error = tokenize("1/2 # 3").err().unwrap();
+
+    assert_that!(&error)
+        .is_equal_to(Error::new("Unparseable operation!", 4, 5));
+}
+
+#[test]
+fn tokenize_function_names() {
+    let kinds: Vec<TokenKind> = tokenize("abs(x)").unwrap().into_iter().map(|token| token.kind).collect();
+
+    assert_that!(&kinds)
+        .is_equal_to(vec![TokenKind::Function, TokenKind::Symbol, TokenKind::Identifier, TokenKind::Symbol]);
+}
+
+#[test]
+fn tokenize_continued_fractions() {
+    assert_that!(&token_texts("[4; 2, 6, 7] * 2")).is_equal_to(vec!["[4; 2, 6, 7]", "*", "2"]);
+}
+
+#[test]
+fn tokenize_lists() {
+    assert_that!(&token_texts("[1/2, [4; 2]] - -1"))
+        .is_equal_to(vec!["[", "1/2", ",", "[4; 2]", "]", "-", "-1"]);
+}
+
+#[test]
+fn tokenize_powers() {
+    assert_that!(&token_texts("2x^3 - x")).is_equal_to(vec!["2", "x", "^", "3", "-", "x"]);
+}
+
+#[test]
+fn tokenize_times() {
+    let tokens = tokenize("1:23:45.5;12 + 1:2:3").unwrap();
+    let kinds: Vec<TokenKind> = tokens.iter().map(|token| token.kind).collect();
+
+    assert_that!(&token_texts("1:23:45.5;12 + 1:2:3")).is_equal_to(vec!["1:23:45.5;12", "+", "1", ":", "2", ":", "3"]);
+    assert_that!(&kinds[..2].to_vec()).is_equal_to(vec![TokenKind::Time, TokenKind::Symbol]);
+    assert_that!(&token_texts("-0:01:30 - 0:00:30")).is_equal_to(vec!["-0:01:30", "-", "0:00:30"]);
+}
+
+#[test]
+fn tokenize_dotted_notes() {
+    assert_that!(&token_texts("1/4. + 1/2..")).is_equal_to(vec!["1/4.", "+", "1/2.."]);
+    assert_that!(tokenize("1.5").is_err()).is_true();
+}
+
+#[test]
+fn tokenize_dice() {
+    let kinds: Vec<TokenKind> = tokenize("3d6 + 2 d").unwrap().into_iter().map(|token| token.kind).collect();
+
+    assert_that!(&token_texts("1d8+2")).is_equal_to(vec!["1d8", "+", "2"]);
+    assert_that!(&kinds).is_equal_to(vec![TokenKind::Dice, TokenKind::Symbol, TokenKind::Number, TokenKind::Identifier]);
+}
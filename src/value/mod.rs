@@ -0,0 +1,174 @@
+use std::fmt;
+use crate::fraction::Fraction;
+use crate::continued_fraction;
+use crate::egyptian;
+use crate::ratio;
+use crate::matrix::Matrix;
+use crate::polynomial::Polynomial;
+use crate::quantity::Quantity;
+use crate::timecode;
+use crate::music::Bar;
+use crate::probability::{self, Distribution};
+use crate::interval::Interval;
+use crate::complex::Complex;
+use crate::money::{self, Rounding};
+
+/// Models the values expressions evaluate to
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(Fraction),
+    Boolean(bool),
+    /// A whole number shown as the product of its prime factors, e.g. `360` is shown as `2^3 * 3^2 * 5`
+    Factors { number: i32, factors: Vec<(i32, u32)> },
+    /// The least common denominator of some fractions, shown along with the fractions rewritten over it
+    CommonDenominator { denominator: i32, fractions: Vec<Fraction> },
+    /// A number shown as the terms of its continued fraction, e.g. `415/93` is shown as `[4; 2, 6, 7]`
+    ContinuedFraction { value: Fraction, terms: Vec<i32> },
+    /// Several numbers, e.g. the convergents of a continued fraction
+    List(Vec<Fraction>),
+    /// A fraction shown as a sum of distinct unit fractions, e.g. `4/13` as `1/4 + 1/26 + 1/52`
+    UnitFractions { value: Fraction, denominators: Vec<i128> },
+    /// A ratio simplified to its smallest whole terms, e.g. `1_1/2 : 2` is `3 : 4`
+    Ratio(Vec<i32>),
+    /// The unknowns of a proportion or a linear system along with the numbers solving them, e.g. `x = 7/8, y = 5/8`
+    Solution(Vec<(String, Fraction)>),
+    /// A matrix written as a list of rows, e.g. `[[1/2, 1/3], [1/4, 1/5]]`
+    Matrix(Matrix),
+    /// A polynomial with fractions as coefficients, e.g. `1/2 x^2 - 3/4 x + 1`
+    Polynomial(Polynomial),
+    /// The quotient & the remainder of the long division of two polynomials, e.g. `x + 1 remainder 2`
+    PolynomialDivision { quotient: Polynomial, remainder: Polynomial },
+    /// A number measured in a unit, e.g. `1_1/2 in`
+    Quantity(Quantity),
+    /// A number of seconds shown as hours, minutes & seconds, e.g. `1:23:45.5`
+    Time(Fraction),
+    /// A bar filled with notes, shown as how full it is, e.g. `7/8 bar underfull by 1/8`
+    Bar(Bar),
+    /// The outcomes of a random variable along with their probabilities, e.g. of a roll of `1d2` as `1: 1/2, 2: 1/2`
+    Distribution(Distribution),
+    /// The probability of an event, shown along with the odds in favour of it, e.g. `1/6 (odds 1:5)`
+    Event(Fraction),
+    /// The values within a tolerance, shown both as it & as bounds, e.g. `3_1/4 ± 3/64 [3_13/64, 3_19/64]`
+    Interval(Interval),
+    /// A complex number with a non-zero imaginary part, e.g. `1/2 + 3/4i`
+    Complex(Complex),
+    /// A number shown as a percentage, e.g. `3/8` as `37_1/2%`. Built with `Value::percent` so the percentage fits
+    Percent(Fraction),
+    /// An exact amount of money, shown rounded to whole cents with banker's rounding, e.g. `$86.66`
+    Money(Fraction),
+    /// An amount of money split into parts of whole cents adding up to it, e.g. `[$33.34, $33.33, $33.33]`
+    Shares(Vec<Fraction>)
+}
+
+impl Value {
+
+    /// Builds a percentage, failing if the number of percent it's shown as doesn't fit in a fraction
+    pub fn percent(fraction: Fraction) -> Result<Value, &'static str> {
+        percentage(&fraction).map(|_| Value::Percent(fraction))
+    }
+
+    /// Returns the fraction if the value is a number. Factorisations & common denominators are numbers too,
+    /// so they can be used in arithmetic like any other number. So are solutions of a single unknown, probabilities & percentages
+    pub fn as_number(&self) -> Option<Fraction> {
+        match self {
+            Value::Number(number) => Some(*number),
+            Value::Factors { number, .. } => Some(Fraction::from(*number)),
+            Value::CommonDenominator { denominator, .. } => Some(Fraction::from(*denominator)),
+            Value::ContinuedFraction { value, .. } | Value::UnitFractions { value, .. } => Some(*value),
+            Value::Solution(solution) if solution.len() == 1 => Some(solution[0].1),
+            Value::Event(fraction) | Value::Percent(fraction) => Some(*fraction),
+            Value::Boolean(_) | Value::List(_) | Value::Ratio(_) | Value::Solution(_) | Value::Matrix(_)
+                | Value::Polynomial(_) | Value::PolynomialDivision { .. } | Value::Quantity(_) | Value::Time(_) | Value::Bar(_)
+                | Value::Distribution(_) | Value::Interval(_) | Value::Complex(_) | Value::Money(_) | Value::Shares(_) => None
+        }
+    }
+}
+
+/// The number of percent a fraction is shown as, e.g. `37_1/2` for `3/8`
+pub(crate) fn percentage(fraction: &Fraction) -> Result<Fraction, &'static str> {
+    fraction.checked_multiply(&Fraction::from(100))
+}
+
+/// Complex numbers without imaginary part are just numbers, e.g. `2i * 2i` is `-4`
+impl From<Complex> for Value {
+    fn from(complex: Complex) -> Value {
+        if complex.imaginary.numerator == 0 {
+            Value::Number(complex.real)
+        } else {
+            Value::Complex(complex)
+        }
+    }
+}
+
+/// Implementation to make `Value` displayable.
+impl fmt::Display for Value {
+
+    /// Numbers are displayed like `Fraction`, booleans as `true` or `false`, factorisations like `-1 * 2^2 * 3`
+    /// common denominators like `24 (1/6 = 4/24, 3/8 = 9/24)`, continued fractions like `[4; 2, 6, 7]`
+    /// lists like `[4, 9/2, 58/13]`, unit fractions like `1/4 + 1/26 + 1/52`, ratios like `3 : 4`
+    /// solutions like `x = 7/8, y = 5/8`, matrices like `[[1/2, 1/3], [1/4, 1/5]]`, polynomials like `1/2 x^2 - 3/4 x + 1`
+    /// polynomial divisions like `x + 1 remainder 2`, quantities like `1_1/2 in`, times like `1:23:45.5`
+    /// bars like `full 4/4 bar`, distributions like `1: 1/2, 2: 1/2` probabilities like `1/6 (odds 1:5)`
+    /// intervals like `3_1/4 ± 3/64 [3_13/64, 3_19/64]`, complex numbers like `1/2 - 3/4i`, percentages like `37_1/2%`
+    /// money like `$1,234.57` and shares like `[$33.34, $33.33, $33.33]`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{}", number),
+            Value::Boolean(boolean) => write!(f, "{}", boolean),
+            Value::Factors { factors, .. } if factors.is_empty() => write!(f, "1"),
+            Value::Factors { factors, .. } => {
+                let factors: Vec<String> = factors.iter()
+                    .map(|&(prime, exponent)| match exponent {
+                        1 => prime.to_string(),
+                        _ => format!("{}^{}", prime, exponent)
+                    })
+                    .collect();
+                write!(f, "{}", factors.join(" * "))
+            },
+            Value::CommonDenominator { denominator, fractions } => {
+                let fractions: Vec<String> = fractions.iter()
+                    .map(|fraction| format!(
+                        "{} = {}/{}",
+                        fraction, fraction.numerator * (denominator / fraction.denominator), denominator
+                    ))
+                    .collect();
+                write!(f, "{} ({})", denominator, fractions.join(", "))
+            },
+            Value::ContinuedFraction { terms, .. } => write!(f, "{}", continued_fraction::format_terms(terms)),
+            Value::List(numbers) => {
+                let numbers: Vec<String> = numbers.iter().map(|number| number.to_string()).collect();
+                write!(f, "[{}]", numbers.join(", "))
+            },
+            Value::UnitFractions { denominators, .. } => write!(f, "{}", egyptian::format_denominators(denominators)),
+            Value::Ratio(terms) => write!(f, "{}", ratio::format_terms(terms)),
+            Value::Solution(solution) => {
+                let solution: Vec<String> = solution.iter()
+                    .map(|(variable, value)| format!("{} = {}", variable, value))
+                    .collect();
+                write!(f, "{}", solution.join(", "))
+            },
+            Value::Matrix(matrix) => write!(f, "{}", matrix),
+            Value::Polynomial(polynomial) => write!(f, "{}", polynomial),
+            Value::PolynomialDivision { quotient, remainder } => write!(f, "{} remainder {}", quotient, remainder),
+            Value::Quantity(quantity) => write!(f, "{}", quantity),
+            Value::Time(seconds) => write!(f, "{}", timecode::format(seconds)),
+            Value::Bar(bar) => write!(f, "{}", bar),
+            Value::Distribution(distribution) => write!(f, "{}", distribution),
+            Value::Event(probability) => {
+                let (favourable, unfavourable) = probability::odds(probability);
+                write!(f, "{} (odds {}:{})", probability, favourable, unfavourable)
+            },
+            Value::Interval(interval) => write!(f, "{}", interval),
+            Value::Complex(complex) => write!(f, "{}", complex),
+            Value::Percent(fraction) => match percentage(fraction) {
+                Ok(percentage) => write!(f, "{}%", percentage),
+                Err(message) => write!(f, "{}", message)
+            },
+            Value::Money(amount) => write!(f, "{}", money::format(amount, Rounding::default())),
+            Value::Shares(shares) => {
+                let shares: Vec<String> = shares.iter().map(|share| money::format(share, Rounding::default())).collect();
+                write!(f, "[{}]", shares.join(", "))
+            }
+        }
+    }
+}
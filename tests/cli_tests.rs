@@ -3,7 +3,7 @@ use assert_cmd::prelude::*; // Add methods on commands
 use predicates::prelude::*; // Used for writing assertions
 
 #[test]
-fn command_help_shows_usage_and_options() -> Result<(), Box<std::error::Error>> {
+fn command_help_shows_usage_and_options() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = Command::main_binary()?;
 
     cmd.arg("-h")
@@ -16,7 +16,7 @@ fn command_help_shows_usage_and_options() -> Result<(), Box<std::error::Error>>
 }
 
 #[test]
-fn run_with_short_eval_arg() -> Result<(), Box<std::error::Error>> {
+fn run_with_short_eval_arg() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = Command::main_binary()?;
     cmd.arg("-e")
         .arg("1/2 * 3_3/4");
@@ -28,7 +28,7 @@ fn run_with_short_eval_arg() -> Result<(), Box<std::error::Error>> {
 }
 
 #[test]
-fn run_with_long_eval_arg() -> Result<(), Box<std::error::Error>> {
+fn run_with_long_eval_arg() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = Command::main_binary()?;
     cmd.arg("--eval")
         .arg("2_3/8 + 9/8");
@@ -40,10 +40,10 @@ fn run_with_long_eval_arg() -> Result<(), Box<std::error::Error>> {
 }
 
 #[test]
-fn run_with_unparseable_expression_prints_error() -> Result<(), Box<std::error::Error>> {
+fn run_with_unparseable_expression_prints_error() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = Command::main_binary()?;
     cmd.arg("-e")
-        .arg("1 / 2 - 3_3/4");
+        .arg("1 / 2 3_3/4");
     cmd.assert()
         .failure()
         .stderr(predicate::str::contains("Error: Unparseable operation!"));
@@ -52,7 +52,7 @@ fn run_with_unparseable_expression_prints_error() -> Result<(), Box<std::error::
 }
 
 #[test]
-fn run_with_fraction_with_zero_denominator_prints_error() -> Result<(), Box<std::error::Error>> {
+fn run_with_fraction_with_zero_denominator_prints_error() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = Command::main_binary()?;
     cmd.arg("-e")
         .arg("1/0 / 3_3/4");
@@ -64,7 +64,7 @@ fn run_with_fraction_with_zero_denominator_prints_error() -> Result<(), Box<std:
 }
 
 #[test]
-fn run_with_no_args_start_repl_mode() -> Result<(), Box<std::error::Error>> {
+fn run_with_no_args_start_repl_mode() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = Command::main_binary()?;
 
     cmd.with_stdin()
@@ -79,7 +79,7 @@ fn run_with_no_args_start_repl_mode() -> Result<(), Box<std::error::Error>> {
 }
 
 #[test]
-fn run_repl_mode_with_errors() -> Result<(), Box<std::error::Error>> {
+fn run_repl_mode_with_errors() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = Command::main_binary()?;
 
     cmd.with_stdin()
@@ -94,3 +94,557 @@ fn run_repl_mode_with_errors() -> Result<(), Box<std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn run_with_explain_arg_shows_steps() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+    cmd.arg("--explain")
+        .arg("-e")
+        .arg("2_3/8 + 9/8");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1. Convert 2_3/8 to an improper fraction: (2 * 8 + 3)/8 = 19/8"))
+        .stdout(predicate::str::contains("5. Convert back to a mixed number: 7 / 2 = 3 remainder 1, so 7/2 = 3_1/2"))
+        .stdout(predicate::str::contains("= 3_1/2"));
+
+    Ok(())
+}
+
+#[test]
+fn run_repl_mode_with_explain_command() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer(":explain\n1/2 * 3_3/4\n:explain\n2_3/8 + 9/8\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Explain mode on"))
+        .stdout(predicate::str::contains("2. Multiply the numerators and the denominators: (1 * 15)/(2 * 4) = 15/8"))
+        .stdout(predicate::str::contains("Explain mode off"))
+        .stdout(predicate::str::contains("Cross-multiply").not());
+
+    Ok(())
+}
+
+#[test]
+fn run_with_latex_format_arg() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+    cmd.arg("--format")
+        .arg("latex")
+        .arg("-e")
+        .arg("2_3/8 + 9/8");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2\\tfrac{3}{8} + \\frac{9}{8} = 3\\tfrac{1}{2}"));
+
+    Ok(())
+}
+
+#[test]
+fn run_repl_mode_with_format_command() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer(":format mathml\n1/2 * 3_3/4\n:format html\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Format set to MathML"))
+        .stdout(predicate::str::contains("<mo>&#xD7;</mo>"))
+        .stderr(predicate::str::contains("Error: Unsupported format!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_pretty_format_arg() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+    cmd.arg("--format")
+        .arg("pretty")
+        .arg("-e")
+        .arg("2_3/8 + 9/8");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("    1\n= 3 ─\n    2"));
+
+    Ok(())
+}
+
+#[test]
+fn run_repl_mode_with_ascii_format() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer(":format ascii\n1/2 * 3_3/4\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("    7\n= 1 -\n    8"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_chained_operations() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+    cmd.arg("-e")
+        .arg("1/4 + 1/8 + 1/8 + 1/2");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("= 1"));
+
+    let mut cmd = Command::main_binary()?;
+    cmd.arg("-e")
+        .arg("1/2 + 1/3 * 2");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("= 1_1/6"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_comparison() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+    cmd.arg("-e")
+        .arg("5_3/8 > 43/8");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("= false"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_functions() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+    cmd.arg("-e")
+        .arg("(1/2 + 1/4) >= max(2/3, clamp(3/5, 0, 1))");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("= true"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_number_theory_functions() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+    cmd.arg("-e")
+        .arg("lcd(1/6, 3/8, 5/12)");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("= 24 (1/6 = 4/24, 3/8 = 9/24, 5/12 = 10/24)"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_continued_fractions() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("cf(415/93)\nconvergents(415/93)\n[4; 2, 6, 7] - 4\n[4; 2, 6, 7]\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= [4; 2, 6, 7]"))
+        .stdout(predicate::str::contains("= [4, 4_1/2, 4_6/13, 4_43/93]"))
+        .stdout(predicate::str::contains("= 43/93"))
+        .stdout(predicate::str::contains("= 4_43/93"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_egyptian_fractions() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("egyptian(5/121)\negyptian_short(5/121)\negyptian(5/4)\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= 1/25 + 1/757 + 1/763309 + 1/873960180913 + 1/1527612795642093418846225"))
+        .stdout(predicate::str::contains("= 1/33 + 1/121 + 1/363"))
+        .stderr(predicate::str::contains("Error: Expected a positive proper fraction!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_statistics() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("data = [1/2, 3_1/4, 7/8]\nmean(data)\nsample_variance(data)\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= [1/2, 3_1/4, 7/8]"))
+        .stdout(predicate::str::contains("= 1_13/24"))
+        .stdout(predicate::str::contains("= 2_43/192"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_proportion() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("1_1/2 : 2\n3/4 : 6 = cups : 10\ncups * 2\n0 : 6 = 4 : x\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= 3 : 4"))
+        .stdout(predicate::str::contains("cups = 1_1/4"))
+        .stdout(predicate::str::contains("= 2_1/2"))
+        .stderr(predicate::str::contains("Error: Degenerate proportion!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_linear_equations() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("solve 2/3 x + 1_1/4 = 5/6\nsolve { x + y = 3/2; x - y = 1/4 }\nx * y\nsolve { x + y = 1; 2x + 2y = 2 }\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("x = -5/8"))
+        .stdout(predicate::str::contains("x = 7/8, y = 5/8"))
+        .stdout(predicate::str::contains("= 35/64"))
+        .stderr(predicate::str::contains("Error: Infinitely many solutions!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_matrices() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("m = [[1/2, 1/3], [1_1/4, 1/5]]\ndet(m)\nm * [6, 3]\ninverse([[1, 2], [2, 4]])\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= [   1/2  1/3 ]\n  [ 1_1/4  1/5 ]"))
+        .stdout(predicate::str::contains("= -19/60"))
+        .stdout(predicate::str::contains("= [4, 8_1/10]"))
+        .stderr(predicate::str::contains("Error: Singular matrix!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_polynomials() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("p = poly(6x^2 - x - 2)\nroots(p)\np / poly(2x + 1)\neval(p, 1_1/2)\nroots(poly(0))\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= 6 x^2 - x - 2"))
+        .stdout(predicate::str::contains("= [-1/2, 2/3]"))
+        .stdout(predicate::str::contains("= 3 x - 2 remainder 0"))
+        .stdout(predicate::str::contains("= 10"))
+        .stderr(predicate::str::contains("Error: Expected a non-zero polynomial!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_quantities() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("3/4 in * 2\n1_1/2 ft to in\n2 in + 3 lb\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= 1_1/2 in"))
+        .stdout(predicate::str::contains("= 18 in"))
+        .stderr(predicate::str::contains("Error: Units don't match!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_json_output_arg() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+    cmd.arg("--output")
+        .arg("json")
+        .arg("-e")
+        .arg("2_3/8 + 9/8");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(r#"{"input":"2_3/8 + 9/8","numerator":7,"denominator":2,"whole":3,"mixed":"3_1/2","decimal":3.5}"#));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_json_output_arg_reports_error() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+    cmd.arg("--output")
+        .arg("json")
+        .arg("-e")
+        .arg("3/0 + 2_2/5");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(r#""error":{"kind":"zero_denominator","message":"Fraction with zero denominator!","span":{"start":0,"end":3}}"#));
+
+    let mut cmd = Command::main_binary()?;
+    cmd.arg("--output")
+        .arg("json")
+        .arg("-e")
+        .arg("[4; 0, 6] + 0");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(r#""error":{"kind":"invalid_argument","message":"Invalid continued fraction!","span":{"start":0,"end":9}}"#));
+
+    Ok(())
+}
+
+#[test]
+fn run_batch_mode() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.arg("--batch")
+        .with_stdin()
+        .buffer("1/2 * 3_3/4\n\n2_3/8 + 9/8\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Starting repl mode").not())
+        .stdout(predicate::str::contains("= 1_7/8\n= 3_1/2\n"));
+
+    Ok(())
+}
+
+#[test]
+fn run_batch_mode_with_json_lines_output() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.arg("--batch")
+        .arg("--output")
+        .arg("jsonl")
+        .with_stdin()
+        .buffer("1/2 * 3_3/4\n1/2\n")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(r#""mixed":"1_7/8""#))
+        .stdout(predicate::str::contains("}\n{"))
+        .stdout(predicate::str::contains(r#"{"input":"1/2","error":{"kind":"syntax","message":"Unparseable operation!","span":{"start":0,"end":3}}}"#));
+
+    Ok(())
+}
+
+#[test]
+fn run_batch_mode_with_json_output() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.arg("--batch")
+        .arg("--output")
+        .arg("json")
+        .with_stdin()
+        .buffer("1/2 * 3_3/4\n2_3/8 + 9/8\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(r#"[{"input":"1/2 * 3_3/4""#))
+        .stdout(predicate::str::contains(r#""mixed":"3_1/2","decimal":3.5}]"#));
+
+    Ok(())
+}
+
+#[test]
+fn run_repl_mode_with_variables() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("x = 2_3/8 + 9/8\nx * 2\ny + 1\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= 3_1/2"))
+        .stdout(predicate::str::contains("= 7"))
+        .stderr(predicate::str::contains("Error: Unknown variable!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_repl_mode_with_completion() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("rate = 3/4 * 1\n:complete r\nround(rate) + recip(rate)\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("range(\nrate\nrecip(\nroots(\nround(\n"))
+        .stdout(predicate::str::contains("= 2_1/3"));
+
+    Ok(())
+}
+
+#[test]
+fn run_scale_subcommand() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.args(["scale", "--by", "1_1/2"])
+        .with_stdin()
+        .buffer("Pancakes\n1 1/2 cup flour\n3/4 tsp salt\n2 tbsp sugar\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Pancakes\n2 1/4 cups flour\n1 tsp salt\n3 tbsp sugar\n"));
+
+    Ok(())
+}
+
+#[test]
+fn run_scale_subcommand_with_malformed_quantity() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.args(["scale", "--by", "2"])
+        .with_stdin()
+        .buffer("1/0 cup milk\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Error: Fraction with zero denominator! (1/0 cup milk)"));
+
+    Ok(())
+}
+
+#[test]
+fn run_serve_subcommand() -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpStream;
+    use std::process::Stdio;
+
+    let mut server = Command::main_binary()?
+        .arg("serve")
+        .arg("--listen")
+        .arg("127.0.0.1:0")
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let mut banner = String::new();
+    BufReader::new(server.stderr.take().unwrap()).read_line(&mut banner)?;
+    let address = banner.trim().trim_start_matches("Listening on ").to_string();
+
+    let mut stream = TcpStream::connect(address)?;
+    stream.write_all(b"{\"jsonrpc\": \"2.0\", \"id\": 1, \"method\": \"evaluate\", \"params\": [\"x = 1/2 * 3_3/4\"]}\n")?;
+    stream.write_all(b"{\"jsonrpc\": \"2.0\", \"id\": 2, \"method\": \"evaluate\", \"params\": [\"x + 1/8\"]}\n")?;
+    let mut responses = BufReader::new(stream).lines();
+    let first_response = responses.next().unwrap()?;
+    let second_response = responses.next().unwrap()?;
+    server.kill()?;
+
+    assert!(first_response.contains(r#""id":1,"result":{"input":"x = 1/2 * 3_3/4","numerator":15,"denominator":8"#));
+    assert!(second_response.contains(r#""id":2,"result":{"input":"x + 1/8","numerator":2,"denominator":1"#));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_times() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("1:23:45 + 2:50:30\nfps = 30000 / 1001\n1:00:00;29 + 0:00:00;1\n1:00:00 + 2\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= 4:14:15"))
+        .stdout(predicate::str::contains("= 1:00:01.001"))
+        .stderr(predicate::str::contains("Error: Expected a time!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_note_durations() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("bar(4/4, 1/4, 1/8, 1/8, 1/2)\nbar(7/8, 1/4., 1/4)\ntuplet(3:2, 1/8) * 3\nbar(4/3, 1)\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= full 4/4 bar"))
+        .stdout(predicate::str::contains("= 7/8 bar underfull by 1/4"))
+        .stdout(predicate::str::contains("= 1/4"))
+        .stderr(predicate::str::contains("Error: Expected a time signature!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_dice_probabilities() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("P(2d6 = 7)\nP(3d6 >= 15)\nE(1d8 + 2)\nP(0d6 = 1)\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= 1/6 (odds 1:5)"))
+        .stdout(predicate::str::contains("= 5/54 (odds 5:49)"))
+        .stdout(predicate::str::contains("= 6_1/2"))
+        .stderr(predicate::str::contains("Error: Expected 1 to 100 dice with 1 to 100 sides!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_tolerances() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("2_1/2 ± 1/64 + 3/4 ± 1/32\n(2 +/- 1/2) * 3\n1 / (1 ± 1)\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= 3_1/4 ± 3/64 [3_13/64, 3_19/64]"))
+        .stdout(predicate::str::contains("= 6 ± 1_1/2 [4_1/2, 7_1/2]"))
+        .stderr(predicate::str::contains("Error: Division by an interval containing zero!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_complex_numbers() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("(1/2 + 3/4i) * (2 - 1i)\n1 / (1 + 1i)\nnorm(1/2 + 3/4i)\n1i < 2\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= 1_3/4 + 1i"))
+        .stdout(predicate::str::contains("= 1/2 - 1/2i"))
+        .stdout(predicate::str::contains("= 13/16"))
+        .stderr(predicate::str::contains("Error: Expected a number!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_percentages() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("15% of 2_1/2\n40 + 10%\n3/8 as %\npct_change(3/4, 7/8)\n7 % 3\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= 3/8"))
+        .stdout(predicate::str::contains("= 44"))
+        .stdout(predicate::str::contains("= 37_1/2%"))
+        .stdout(predicate::str::contains("= 16_2/3%"))
+        .stderr(predicate::str::contains("Error: Unparseable operation!"));
+
+    Ok(())
+}
+
+#[test]
+fn run_with_money() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::main_binary()?;
+
+    cmd.with_stdin()
+        .buffer("$129.99 * 2/3\n$0.125 rounded half_up\nsplit($100, 3)\n$1200 + 7_1/2%\n$5 + 1\nq")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("= $86.66"))
+        .stdout(predicate::str::contains("= $0.13"))
+        .stdout(predicate::str::contains("= [$33.34, $33.33, $33.33]"))
+        .stdout(predicate::str::contains("= $1,290.00"))
+        .stderr(predicate::str::contains("Error: Expected an amount of money!"));
+
+    Ok(())
+}